@@ -1,6 +1,7 @@
 #[cfg(windows)]
 use embed_manifest::{embed_manifest, new_manifest};
 
+#[cfg_attr(not(windows), allow(clippy::needless_return))]
 fn main() {
     // wasm 타겟으로 빌드할 때는 build.rs를 건너뜀
     let target = std::env::var("TARGET").unwrap_or_default();
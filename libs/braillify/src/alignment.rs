@@ -0,0 +1,100 @@
+use std::{borrow::Cow, ops::Range};
+
+use crate::{
+    EncodeOptions, hanja, normalize,
+    tokenize::{self, Token},
+};
+
+/// 입력의 한 단어와 그 점역 결과의 위치
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordAlignment {
+    /// 입력에서 단어가 차지하는 바이트 범위
+    pub source: Range<usize>,
+    /// 점역 결과에서 단어가 차지하는 칸 범위. 단어 뒤의 빈칸은 들지 않는다.
+    pub cells: Range<usize>,
+}
+
+/// 점역하기 전에 바꾼 입력에서 원래 입력으로 단어 위치를 찾는 표
+#[derive(Debug, Default)]
+pub(crate) struct SourceMap {
+    /// 단어마다 (원래 입력의 범위, 바꾼 입력의 범위)
+    words: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl SourceMap {
+    /// 바꾼 입력의 범위를 원래 입력의 범위로 바꾼다. 단어 안의 범위이면 그 단어 전체로 본다.
+    pub(crate) fn original(&self, range: &Range<usize>) -> Range<usize> {
+        let first = self
+            .words
+            .iter()
+            .find(|(_, prepared)| prepared.end > range.start);
+        let last = self
+            .words
+            .iter()
+            .rev()
+            .find(|(_, prepared)| prepared.start < range.end);
+        match (first, last) {
+            (Some((first, _)), Some((last, _))) => first.start..last.end,
+            _ => range.clone(),
+        }
+    }
+}
+
+/// 한 단어의 조합형 자모와 반각 자모는 음절이나 호환 자모로 바꾸고, 한자는 설정에 따라
+/// 지우거나 한글 음으로 바꾼다.
+pub(crate) fn prepare_word<'a>(
+    word: &'a str,
+    options: &EncodeOptions,
+) -> Result<Cow<'a, str>, String> {
+    match normalize::normalize(word) {
+        Cow::Borrowed(word) => hanja::apply_policy(word, options.hanja),
+        Cow::Owned(word) => Ok(Cow::Owned(
+            hanja::apply_policy(&word, options.hanja)?.into_owned(),
+        )),
+    }
+}
+
+/// 입력의 단어마다 [`prepare_word`]로 바꾸고, 원래 단어의 위치를 함께 기억한다.
+pub(crate) fn prepare(text: &str, options: &EncodeOptions) -> Result<(String, SourceMap), String> {
+    let mut prepared = String::with_capacity(text.len());
+    let mut map = SourceMap::default();
+    let mut pos = 0;
+    for token in tokenize::tokenize(text) {
+        let Token::Word(word) = token else {
+            continue;
+        };
+        let start = word.as_ptr() as usize - text.as_ptr() as usize;
+        // 단어 앞의 빈칸은 그대로 둔다.
+        prepared.push_str(&text[pos..start]);
+        let converted = prepare_word(word, options)?;
+        let prepared_start = prepared.len();
+        prepared.push_str(&converted);
+        map.words
+            .push((start..start + word.len(), prepared_start..prepared.len()));
+        pos = start + word.len();
+    }
+    prepared.push_str(&text[pos..]);
+    Ok((prepared, map))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::HanjaPolicy;
+
+    #[test]
+    fn test_prepare() {
+        let options = EncodeOptions {
+            hanja: HanjaPolicy::Reading,
+            ..Default::default()
+        };
+        let (prepared, map) = prepare(" 國民 \u{1100}\u{1161}\n나", &options).unwrap();
+        assert_eq!(prepared, " 국민 가\n나");
+        // 國民 → 국민, ᄀ+ᅡ → 가
+        assert_eq!(map.original(&(1..7)), 1..7);
+        assert_eq!(map.original(&(8..11)), 8..14);
+        assert_eq!(map.original(&(12..15)), 15..18);
+        assert_eq!(map.original(&(8..15)), 8..18);
+        assert!(prepare("國民", &EncodeOptions::default()).is_err());
+    }
+}
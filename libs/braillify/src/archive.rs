@@ -0,0 +1,77 @@
+use std::io::{Cursor, Read};
+
+use zip::ZipArchive;
+
+/// EPUB와 DOCX처럼 zip으로 묶은 문서
+pub(crate) struct Archive<'a> {
+    zip: ZipArchive<Cursor<&'a [u8]>>,
+}
+
+impl<'a> Archive<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Result<Self, String> {
+        ZipArchive::new(Cursor::new(bytes))
+            .map(|zip| Self { zip })
+            .map_err(|e| format!("Invalid archive: {e}"))
+    }
+
+    /// 묶음 안의 파일을 UTF-8 글로 읽는다. 파일이 없으면 `None`이다.
+    pub(crate) fn read(&mut self, name: &str) -> Result<Option<String>, String> {
+        let mut file = match self.zip.by_name(name) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(e) => return Err(format!("{name}: {e}")),
+        };
+        let mut text = String::new();
+        file.read_to_string(&mut text)
+            .map_err(|e| format!("{name}: {e}"))?;
+        Ok(Some(text))
+    }
+
+    /// 묶음 안의 파일을 읽는다. 파일이 없으면 오류이다.
+    pub(crate) fn read_required(&mut self, name: &str) -> Result<String, String> {
+        self.read(name)?
+            .ok_or_else(|| format!("Missing file in archive: {name}"))
+    }
+}
+
+/// XML 문서를 읽는다.
+pub(crate) fn parse_xml(text: &str) -> Result<roxmltree::Document<'_>, String> {
+    roxmltree::Document::parse_with_options(
+        text,
+        roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        },
+    )
+    .map_err(|e| format!("Invalid XML: {e}"))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::io::Write;
+
+    use zip::{ZipWriter, write::SimpleFileOptions};
+
+    /// 테스트에 쓸 zip 묶음을 만든다.
+    pub(crate) fn zip(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, text) in files {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(text.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_read() {
+        let bytes = zip(&[("a/b.txt", "점자")]);
+        let mut archive = super::Archive::new(&bytes).unwrap();
+        assert_eq!(archive.read("a/b.txt").unwrap(), Some("점자".to_string()));
+        assert_eq!(archive.read("c.txt").unwrap(), None);
+        assert!(archive.read_required("c.txt").is_err());
+        assert!(super::Archive::new(b"not a zip").is_err());
+        assert!(super::parse_xml("<a>").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    document::{Block, DocumentOptions, page_break_line, render_blocks},
+    encode_with_encoder,
+};
+
+/// 점자책 편집 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookOptions {
+    /// 한 면의 줄 수. 첫 줄과 마지막 줄은 쪽 번호를 적는 데 쓴다.
+    pub lines: usize,
+    /// 본문의 편집 설정
+    pub document: DocumentOptions,
+}
+
+impl Default for BookOptions {
+    fn default() -> Self {
+        Self {
+            lines: 25,
+            document: DocumentOptions::default(),
+        }
+    }
+}
+
+/// 점자책의 한 면
+#[derive(Default)]
+struct Page {
+    /// 이 면이 시작할 때의 묵자 쪽 번호
+    print_page: Option<String>,
+    body: Vec<Vec<u8>>,
+}
+
+/// 줄 끝에 맞추어 적는다.
+fn right_aligned(cells: Vec<u8>, width: usize) -> Vec<u8> {
+    let mut line = vec![0; width.saturating_sub(cells.len())];
+    line.extend(cells);
+    line
+}
+
+/// 블록을 점역해 면마다 나눈다.
+///
+/// 면의 첫 줄 끝에는 그 면이 시작할 때의 묵자 쪽 번호를, 마지막 줄 끝에는 점자 쪽 번호를 적고,
+/// 그 사이에 본문을 적는다. 묵자 쪽이 바뀌는 곳에는 바뀜 줄을 두되, 면의 첫머리에서 바뀌면
+/// 첫 줄의 쪽 번호로 대신한다. 제목은 다음 줄과 함께 적을 수 없으면 다음 면으로 넘기고,
+/// 면 첫머리의 빈 줄은 적지 않는다.
+pub fn render_book(blocks: &[Block], options: &BookOptions) -> Result<Vec<Vec<Vec<u8>>>, String> {
+    let document = &options.document;
+    let body_lines = options.lines.saturating_sub(2).max(1);
+    let mut pages: Vec<Page> = Vec::new();
+    let mut page = Page::default();
+    let mut print_page = None;
+    for block in blocks {
+        let lines = match block {
+            Block::PageBreak(number) => {
+                print_page = Some(number.clone());
+                if page.body.is_empty() {
+                    page.print_page = print_page.clone();
+                    continue;
+                }
+                vec![page_break_line(number, document)?]
+            }
+            Block::BlankLine if page.body.is_empty() => continue,
+            _ => render_blocks(std::slice::from_ref(block), document)?,
+        };
+        let keep = match block {
+            Block::Heading(_) => lines.len() + 1,
+            _ => 1,
+        };
+        if !page.body.is_empty() && page.body.len() + keep.min(body_lines) > body_lines {
+            pages.push(std::mem::take(&mut page));
+            page.print_page = print_page.clone();
+        }
+        for line in lines {
+            if page.body.len() == body_lines {
+                pages.push(std::mem::take(&mut page));
+                page.print_page = print_page.clone();
+            }
+            page.body.push(line);
+        }
+    }
+    if !page.body.is_empty() || pages.is_empty() {
+        pages.push(page);
+    }
+
+    pages
+        .into_iter()
+        .enumerate()
+        .map(|(idx, page)| {
+            let mut lines = vec![match &page.print_page {
+                Some(number) => right_aligned(
+                    encode_with_encoder(number, &document.encode)?.0,
+                    document.width,
+                ),
+                None => Vec::new(),
+            }];
+            lines.extend(page.body);
+            lines.resize(options.lines.saturating_sub(1).max(lines.len()), Vec::new());
+            let number = encode_with_encoder(&(idx + 1).to_string(), &document.encode)?.0;
+            lines.push(right_aligned(number, document.width));
+            Ok(lines)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn to_unicode(pages: &[Vec<Vec<u8>>]) -> Vec<Vec<String>> {
+        pages
+            .iter()
+            .map(|page| {
+                page.iter()
+                    .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_render_book() {
+        let options = BookOptions {
+            lines: 5,
+            document: DocumentOptions {
+                width: 8,
+                ..Default::default()
+            },
+        };
+        let blocks = [
+            Block::PageBreak("1".to_string()),
+            Block::Paragraph("상 상".to_string()),
+            Block::PageBreak("2".to_string()),
+            Block::Heading("상".to_string()),
+            Block::BlankLine,
+            Block::Paragraph("상".to_string()),
+        ];
+        assert_eq!(
+            to_unicode(&render_book(&blocks, &options).unwrap()),
+            vec![
+                vec!["⠀⠀⠀⠀⠀⠀⠼⠁", "⠀⠀⠇⠶⠀⠇⠶", "⠤⠤⠤⠤⠤⠤⠼⠃", "", "⠀⠀⠀⠀⠀⠀⠼⠁",],
+                vec!["⠀⠀⠀⠀⠀⠀⠼⠃", "⠀⠀⠀⠇⠶", "", "⠀⠀⠇⠶", "⠀⠀⠀⠀⠀⠀⠼⠃",],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_book_empty() {
+        let pages = render_book(&[], &BookOptions::default()).unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].len(), 25);
+    }
+}
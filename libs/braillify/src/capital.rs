@@ -0,0 +1,134 @@
+use crate::utils::is_english_letter;
+
+/// 대문자 기호표
+pub const CAPITAL_LETTER: [u8; 1] = [32];
+/// 대문자 단어표
+pub const CAPITAL_WORD: [u8; 2] = [32, 32];
+/// 대문자 구절표
+pub const CAPITAL_PASSAGE: [u8; 3] = [32, 32, 32];
+/// 대문자 종료표
+pub const CAPITAL_TERMINATOR: [u8; 2] = [32, 4];
+
+/// 단어 안의 글자 앞에 적는 대문자 표
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapitalMark {
+    /// 한 글자만 대문자일 때 그 앞에 적는 대문자 기호표 ⠠
+    Letter,
+    /// 두 글자 이상 이어진 대문자 앞에 적는 대문자 단어표 ⠠⠠
+    Word,
+    /// 대문자 단어표 뒤에 같은 로마자 묶음 안에서 소문자가 이어질 때 소문자 앞에 적는 대문자 종료표 ⠠⠄
+    Terminator,
+}
+
+impl CapitalMark {
+    pub fn cells(self) -> &'static [u8] {
+        match self {
+            CapitalMark::Letter => &CAPITAL_LETTER,
+            CapitalMark::Word => &CAPITAL_WORD,
+            CapitalMark::Terminator => &CAPITAL_TERMINATOR,
+        }
+    }
+}
+
+/// 단어의 로마자가 두 글자 이상이고 모두 대문자인지 확인한다. (타 언어인 경우 반드시 false)
+pub fn is_all_uppercase(chars: &[char]) -> bool {
+    let (letters, uppers) = chars
+        .iter()
+        .filter(|c| is_english_letter(**c))
+        .fold((0, 0), |(letters, uppers), c| {
+            (letters + 1, uppers + usize::from(c.is_uppercase()))
+        });
+    letters >= 2 && letters == uppers
+}
+
+/// 28항 [붙임] 대소문자가 섞인 단어에서 글자마다 앞에 적을 대문자 표를 구한다.
+///
+/// 숫자나 기호로 끊기지 않고 이어진 로마자 묶음 안에서, 한 글자만 대문자이면 대문자 기호표를,
+/// 두 글자 이상 이어진 대문자에는 대문자 단어표를 적는다. 대문자 단어표 뒤에 같은 묶음의
+/// 소문자가 이어지면 그 앞에 대문자 종료표를 적는다. (`iPhone`, `YouTube`, `PDFs`)
+pub fn capital_marks(chars: &[char]) -> Vec<Option<CapitalMark>> {
+    let mut marks = vec![None; chars.len()];
+    let mut idx = 0;
+    while idx < chars.len() {
+        if !(is_english_letter(chars[idx]) && chars[idx].is_uppercase()) {
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        while idx < chars.len() && is_english_letter(chars[idx]) && chars[idx].is_uppercase() {
+            idx += 1;
+        }
+        if idx - start == 1 {
+            marks[start] = Some(CapitalMark::Letter);
+        } else {
+            marks[start] = Some(CapitalMark::Word);
+            if idx < chars.len() && is_english_letter(chars[idx]) {
+                marks[idx] = Some(CapitalMark::Terminator);
+            }
+        }
+    }
+    marks
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    /// 대문자 표를 ⠠, ⠠⠠, ⠠⠄로 표시하고 나머지 글자는 그대로 둔 문자열
+    fn render(word: &str) -> String {
+        let chars = word.chars().collect::<Vec<_>>();
+        let mut result = String::new();
+        for (c, mark) in chars.iter().zip(capital_marks(&chars)) {
+            if let Some(mark) = mark {
+                result.extend(mark.cells().iter().map(|cell| encode_unicode(*cell)));
+            }
+            result.extend(c.to_lowercase());
+        }
+        result
+    }
+
+    #[test]
+    fn test_capital_marks_single_letters() {
+        assert_eq!(render("iPhone"), "i⠠phone");
+        assert_eq!(render("YouTube"), "⠠you⠠tube");
+        assert_eq!(render("McDonald"), "⠠mc⠠donald");
+        assert_eq!(render("eBay"), "e⠠bay");
+        assert_eq!(render("A"), "⠠a");
+        assert_eq!(render("abc"), "abc");
+    }
+
+    #[test]
+    fn test_capital_marks_words_and_terminator() {
+        assert_eq!(render("PDFs"), "⠠⠠pdf⠠⠄s");
+        assert_eq!(render("MyPDFs"), "⠠my⠠⠠pdf⠠⠄s");
+        assert_eq!(render("ABCdEF"), "⠠⠠abc⠠⠄d⠠⠠ef");
+        assert_eq!(render("GHz"), "⠠⠠gh⠠⠄z");
+    }
+
+    #[test]
+    fn test_capital_marks_around_digits_and_symbols() {
+        // 숫자나 기호는 로마자 묶음을 끊으므로 대문자 종료표가 필요 없다.
+        assert_eq!(render("COVID19"), "⠠⠠covid19");
+        assert_eq!(render("MP3s"), "⠠⠠mp3s");
+        assert_eq!(render("A-b"), "⠠a-b");
+        assert_eq!(render("x86X"), "x86⠠x");
+        assert_eq!(render("1A"), "1⠠a");
+    }
+
+    #[test]
+    fn test_capital_marks_accented_letters() {
+        assert_eq!(render("École"), "⠠école");
+        assert_eq!(render("ÉÉs"), "⠠⠠éé⠠⠄s");
+    }
+
+    #[test]
+    fn test_is_all_uppercase() {
+        assert!(is_all_uppercase(&['O', 'K']));
+        assert!(is_all_uppercase(&"COVID19".chars().collect::<Vec<_>>()));
+        assert!(is_all_uppercase(&"U.S.A.".chars().collect::<Vec<_>>()));
+        assert!(!is_all_uppercase(&['A']));
+        assert!(!is_all_uppercase(&"PDFs".chars().collect::<Vec<_>>()));
+        assert!(!is_all_uppercase(&"한글".chars().collect::<Vec<_>>()));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{math_symbol_shortcut::is_math_symbol_char, symbol_shortcut::is_symbol_char, fraction::is_unicode_fraction};
+use crate::{utils::is_english_letter, emphasis::is_emphasis_mark, kana::is_kana, old_hangul::is_conjoining_jamo, math_symbol_shortcut::is_math_symbol_char, symbol_shortcut::is_symbol_char, fraction::is_unicode_fraction, script::is_script_char, unit::is_unit_char};
 
 /// Character in Korean
 #[derive(Debug)]
@@ -54,17 +54,25 @@ impl KoreanChar {
 pub enum CharType {
     Korean(KoreanChar),
     KoreanPart(char),
+    /// 옛한글 음절을 이루는 조합형 자모
+    OldKorean(char),
+    /// 일본어 가나 (히라가나, 가타카나)
+    Kana(char),
     English(char),
     Number(char),
     Symbol(char),
     MathSymbol(char),
     Fraction(char), 
+    Unit(char),
+    Script(char),
     Space(char),
+    /// 제56항 강조 구간 표시 문자
+    Emphasis(char),
 }
 
 impl CharType {
     pub fn new(c: char) -> Result<Self, String> {
-        if c.is_ascii_alphabetic() {
+        if is_english_letter(c) {
             return Ok(Self::English(c));
         }
         if c.is_ascii_digit() {
@@ -79,10 +87,23 @@ impl CharType {
         if is_unicode_fraction(c) { 
             return Ok(Self::Fraction(c));
         }
+        if is_unit_char(c) {
+            return Ok(Self::Unit(c));
+        }
+        if is_script_char(c) {
+            return Ok(Self::Script(c));
+        }
         let code = c as u32;
-        if (0x3131..=0x3163).contains(&code) {
+        // 현대 호환 자모와 옛 호환 자모 (U+3164 채움 문자 제외)
+        if (0x3131..=0x3163).contains(&code) || (0x3165..=0x318E).contains(&code) {
             return Ok(Self::KoreanPart(c));
         }
+        if is_conjoining_jamo(c) {
+            return Ok(Self::OldKorean(c));
+        }
+        if is_kana(c) {
+            return Ok(Self::Kana(c));
+        }
         // if !(0xAC00 <= code && code <= 0xD7A3) {
         //     return Ok(Self::Char(c));
         // }
@@ -92,6 +113,9 @@ impl CharType {
         if c.is_whitespace() {
             return Ok(Self::Space(c));
         }
+        if is_emphasis_mark(c) {
+            return Ok(Self::Emphasis(c));
+        }
         Err("Invalid character".to_string())
     }
 }
@@ -115,6 +139,8 @@ mod test {
             CharType::KoreanPart('ㄱ')
         ));
         assert!(matches!(CharType::new(' ').unwrap(), CharType::Space(' ')));
+        assert!(matches!(CharType::new('%').unwrap(), CharType::Unit('%')));
+        assert!(matches!(CharType::new('²').unwrap(), CharType::Script('²')));
         assert!(matches!(
             CharType::new('½').unwrap(), 
             CharType::Fraction('½')
@@ -135,10 +161,16 @@ mod test {
                 }
                 CharType::KoreanPart(ch) => {
                     let code = ch as u32;
-                    assert!(0x3131 <= code && code <= 0x3163);
+                    assert!((0x3131..=0x318E).contains(&code) && code != 0x3164);
+                }
+                CharType::Kana(ch) => {
+                    assert!(is_kana(ch));
+                }
+                CharType::OldKorean(ch) => {
+                    assert!(is_conjoining_jamo(ch));
                 }
                 CharType::English(ch) => {
-                    assert!(ch.is_ascii_alphabetic());
+                    assert!(is_english_letter(ch));
                 }
                 CharType::Number(ch) => {
                     assert!(ch.is_ascii_digit());
@@ -155,6 +187,15 @@ mod test {
                 CharType::Fraction(ch) => {
                     assert!(is_unicode_fraction(ch));
                 }
+                CharType::Unit(ch) => {
+                    assert!(is_unit_char(ch));
+                }
+                CharType::Script(ch) => {
+                    assert!(is_script_char(ch));
+                }
+                CharType::Emphasis(ch) => {
+                    assert!(is_emphasis_mark(ch));
+                }
             }
         }
     }
@@ -0,0 +1,299 @@
+use phf::{phf_map, phf_set};
+
+use crate::{
+    english, math::SUPERSCRIPT_INDICATOR, number, script, symbol_shortcut, unicode::decode_unicode,
+};
+
+/// 화학식의 아래 첨자는 수표 없이 아래 칸 숫자로 적는다.
+const LOWER_DIGITS: [u8; 10] = [
+    decode_unicode('⠴'),
+    decode_unicode('⠂'),
+    decode_unicode('⠆'),
+    decode_unicode('⠒'),
+    decode_unicode('⠲'),
+    decode_unicode('⠢'),
+    decode_unicode('⠖'),
+    decode_unicode('⠶'),
+    decode_unicode('⠦'),
+    decode_unicode('⠔'),
+];
+
+/// 반응 화살표. `->`, `<-`, `<=>`처럼 글자로 쓴 화살표도 같은 점형으로 적는다.
+static ARROW_MAP: phf::Map<&'static str, &'static [u8]> = phf_map! {
+    "→" => &[decode_unicode('⠒'), decode_unicode('⠒'), decode_unicode('⠕')],
+    "←" => &[decode_unicode('⠪'), decode_unicode('⠒'), decode_unicode('⠒')],
+    "⇌" => &[decode_unicode('⠪'), decode_unicode('⠒'), decode_unicode('⠒'), decode_unicode('⠕')],
+    "⇄" => &[decode_unicode('⠪'), decode_unicode('⠒'), decode_unicode('⠒'), decode_unicode('⠕')],
+    "->" => &[decode_unicode('⠒'), decode_unicode('⠒'), decode_unicode('⠕')],
+    "<-" => &[decode_unicode('⠪'), decode_unicode('⠒'), decode_unicode('⠒')],
+    "<=>" => &[decode_unicode('⠪'), decode_unicode('⠒'), decode_unicode('⠒'), decode_unicode('⠕')],
+};
+
+/// 원소 기호
+static ELEMENTS: phf::Set<&'static str> = phf_set! {
+    "H", "He", "Li", "Be", "B", "C", "N", "O", "F", "Ne", "Na", "Mg", "Al", "Si", "P", "S", "Cl",
+    "Ar", "K", "Ca", "Sc", "Ti", "V", "Cr", "Mn", "Fe", "Co", "Ni", "Cu", "Zn", "Ga", "Ge", "As",
+    "Se", "Br", "Kr", "Rb", "Sr", "Y", "Zr", "Nb", "Mo", "Tc", "Ru", "Rh", "Pd", "Ag", "Cd", "In",
+    "Sn", "Sb", "Te", "I", "Xe", "Cs", "Ba", "La", "Ce", "Pr", "Nd", "Pm", "Sm", "Eu", "Gd", "Tb",
+    "Dy", "Ho", "Er", "Tm", "Yb", "Lu", "Hf", "Ta", "W", "Re", "Os", "Ir", "Pt", "Au", "Hg", "Tl",
+    "Pb", "Bi", "Po", "At", "Rn", "Fr", "Ra", "Ac", "Th", "Pa", "U", "Np", "Pu", "Am", "Cm", "Bk",
+    "Cf", "Es", "Fm", "Md", "No", "Lr", "Rf", "Db", "Sg", "Bh", "Hs", "Mt", "Ds", "Rg", "Cn", "Nh",
+    "Fl", "Mc", "Lv", "Ts", "Og",
+};
+
+#[derive(Debug, PartialEq)]
+pub enum ChemToken {
+    /// 화학식 앞의 계수 (`2H₂O`의 2)
+    Coefficient(String),
+    /// 원소 기호
+    Element(String),
+    /// 원자 수를 나타내는 아래 첨자
+    Count(String),
+    /// 이온의 전하. 숫자와 부호(+, −)
+    Charge(String, char),
+    Open,
+    Close,
+    /// 화학식 사이에 붙어 나오는 +
+    Plus,
+    Arrow(&'static str),
+}
+
+fn subscript_digit(c: char) -> Option<char> {
+    if c.is_ascii_digit() {
+        return Some(c);
+    }
+    match script::script_char(c)? {
+        (script::ScriptKind::Sub, base) if base.is_ascii_digit() => Some(base),
+        _ => None,
+    }
+}
+
+fn superscript_char(c: char) -> Option<char> {
+    match script::script_char(c)? {
+        (script::ScriptKind::Super, base) => Some(base),
+        _ => None,
+    }
+}
+
+/// 단어 앞부분의 화학식을 분석한다.
+///
+/// 분석한 토큰과 화학식이 차지하는 바이트 수를 돌려준다. 화학식 모드에서만 쓰이므로 `CO`,
+/// `HI`처럼 원소 기호만 이어진 단어도 화학식으로 보고, 원소가 아닌 기호가 섞인 단어(`USA`)만
+/// 화학식으로 다루지 않는다.
+pub fn tokenize_formula(word: &str) -> Option<(Vec<ChemToken>, usize)> {
+    let chars = word.char_indices().collect::<Vec<_>>();
+    let char_at = |idx: usize| chars.get(idx).map(|(_, c)| *c);
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+    let mut depth = 0usize;
+    let mut species_start = true;
+
+    while let Some((pos, c)) = chars.get(idx).copied() {
+        if let Some((arrow, _)) = ARROW_MAP
+            .entries()
+            .find(|(arrow, _)| word[pos..].starts_with(**arrow))
+        {
+            tokens.push(ChemToken::Arrow(arrow));
+            species_start = true;
+            idx += arrow.chars().count();
+            continue;
+        }
+        if species_start && c.is_ascii_digit() {
+            let start = idx;
+            while char_at(idx).is_some_and(|c| c.is_ascii_digit()) {
+                idx += 1;
+            }
+            if !char_at(idx).is_some_and(|c| c.is_ascii_uppercase() || c == '(') {
+                return None;
+            }
+            tokens.push(ChemToken::Coefficient(
+                chars[start..idx].iter().map(|(_, c)| c).collect(),
+            ));
+            species_start = false;
+            continue;
+        }
+        let after_atom = matches!(
+            tokens.last(),
+            Some(ChemToken::Element(_) | ChemToken::Count(_) | ChemToken::Close)
+        );
+        if c.is_ascii_uppercase() {
+            let mut symbol = c.to_string();
+            idx += 1;
+            if let Some(lower) = char_at(idx).filter(|c| c.is_ascii_lowercase()) {
+                symbol.push(lower);
+                idx += 1;
+            }
+            tokens.push(ChemToken::Element(symbol));
+        } else if c == '(' {
+            tokens.push(ChemToken::Open);
+            depth += 1;
+            idx += 1;
+        } else if c == ')' && depth > 0 {
+            tokens.push(ChemToken::Close);
+            depth -= 1;
+            idx += 1;
+        } else if after_atom
+            && !matches!(tokens.last(), Some(ChemToken::Count(_)))
+            && subscript_digit(c).is_some()
+        {
+            let mut digits = String::new();
+            while let Some(digit) = char_at(idx).and_then(subscript_digit) {
+                digits.push(digit);
+                idx += 1;
+            }
+            tokens.push(ChemToken::Count(digits));
+        } else if after_atom && superscript_char(c).is_some() {
+            let mut digits = String::new();
+            let mut sign = None;
+            while let Some(base) = char_at(idx).and_then(superscript_char) {
+                match base {
+                    '0'..='9' if sign.is_none() => digits.push(base),
+                    '+' | '−' if sign.is_none() => sign = Some(base),
+                    _ => return None,
+                }
+                idx += 1;
+            }
+            tokens.push(ChemToken::Charge(digits, sign?));
+        } else if after_atom && matches!(c, '+' | '-') {
+            idx += 1;
+            let next = char_at(idx);
+            if next.is_some_and(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '(') {
+                tokens.push(ChemToken::Plus);
+                species_start = true;
+                continue;
+            }
+            let sign = if c == '+' { '+' } else { '−' };
+            tokens.push(ChemToken::Charge(String::new(), sign));
+        } else {
+            break;
+        }
+        species_start = false;
+    }
+
+    let len = chars.get(idx).map_or(word.len(), |(pos, _)| *pos);
+    let has_element = tokens.iter().any(|t| matches!(t, ChemToken::Element(_)));
+    let is_element = |t: &ChemToken| match t {
+        ChemToken::Element(symbol) => ELEMENTS.contains(symbol.as_str()),
+        _ => true,
+    };
+    if depth != 0
+        || tokens.is_empty()
+        || !tokens.iter().all(is_element)
+        || (!has_element && tokens.iter().any(|t| !matches!(t, ChemToken::Arrow(_))))
+        || matches!(tokens.last(), Some(ChemToken::Plus))
+        || char_at(idx).is_some_and(|c| c.is_ascii_alphanumeric())
+    {
+        return None;
+    }
+    Some((tokens, len))
+}
+
+/// 화학식을 점자로 적는다. 원소 기호는 로마자로 적고 대문자 앞에는 대문자 기호표를 적는다.
+pub fn encode_formula(tokens: &[ChemToken], result: &mut Vec<u8>) -> Result<(), String> {
+    for token in tokens {
+        match token {
+            ChemToken::Coefficient(digits) => {
+                result.push(decode_unicode('⠼'));
+                for c in digits.chars() {
+                    result.push(number::encode_number(c)?);
+                }
+            }
+            ChemToken::Element(symbol) => {
+                for c in symbol.chars() {
+                    if c.is_ascii_uppercase() {
+                        result.push(decode_unicode('⠠'));
+                    }
+                    result.push(english::encode_english(c)?);
+                }
+            }
+            ChemToken::Count(digits) => {
+                for c in digits.chars() {
+                    result.push(LOWER_DIGITS[c as usize - '0' as usize]);
+                }
+            }
+            ChemToken::Charge(digits, sign) => {
+                result.push(SUPERSCRIPT_INDICATOR);
+                if !digits.is_empty() {
+                    result.push(decode_unicode('⠼'));
+                    for c in digits.chars() {
+                        result.push(number::encode_number(c)?);
+                    }
+                }
+                result
+                    .extend(crate::math_symbol_shortcut::encode_char_math_symbol_shortcut(*sign)?);
+            }
+            ChemToken::Open => {
+                result.extend(symbol_shortcut::encode_english_char_symbol_shortcut('(').unwrap())
+            }
+            ChemToken::Close => {
+                result.extend(symbol_shortcut::encode_english_char_symbol_shortcut(')').unwrap())
+            }
+            ChemToken::Plus => {
+                result.extend(crate::math_symbol_shortcut::encode_char_math_symbol_shortcut('+')?)
+            }
+            ChemToken::Arrow(arrow) => result.extend(ARROW_MAP[*arrow].iter()),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(word: &str) -> Option<(String, usize)> {
+        let (tokens, len) = tokenize_formula(word)?;
+        let mut result = Vec::new();
+        encode_formula(&tokens, &mut result).unwrap();
+        Some((result.iter().map(|c| encode_unicode(*c)).collect(), len))
+    }
+
+    #[test]
+    fn test_tokenize_formula() {
+        assert_eq!(
+            tokenize_formula("2H₂O"),
+            Some((
+                vec![
+                    ChemToken::Coefficient("2".to_string()),
+                    ChemToken::Element("H".to_string()),
+                    ChemToken::Count("2".to_string()),
+                    ChemToken::Element("O".to_string()),
+                ],
+                "2H₂O".len()
+            ))
+        );
+        assert_eq!(tokenize_formula("CO2는").map(|(_, len)| len), Some(3));
+        assert_eq!(tokenize_formula("USA"), None);
+        assert_eq!(tokenize_formula("Xy"), None);
+        assert_eq!(tokenize_formula("The"), None);
+        assert_eq!(tokenize_formula("Ca(OH"), None);
+    }
+
+    #[test]
+    fn test_encode_formula() {
+        assert_eq!(encode_str("H2O").unwrap().0, "⠠⠓⠆⠠⠕");
+        assert_eq!(encode_str("CO").unwrap().0, "⠠⠉⠠⠕");
+        assert_eq!(encode_str("CO2").unwrap().0, "⠠⠉⠠⠕⠆");
+        assert_eq!(encode_str("NO").unwrap().0, "⠠⠝⠠⠕");
+        assert_eq!(encode_str("HI").unwrap().0, "⠠⠓⠠⠊");
+        assert_eq!(encode_str("HF").unwrap().0, "⠠⠓⠠⠋");
+        assert_eq!(encode_str("H₂O").unwrap().0, "⠠⠓⠆⠠⠕");
+        assert_eq!(encode_str("NaCl").unwrap().0, "⠠⠝⠁⠠⠉⠇");
+        assert_eq!(encode_str("2H₂O").unwrap().0, "⠼⠃⠠⠓⠆⠠⠕");
+        assert_eq!(encode_str("Ca(OH)2").unwrap().0, "⠠⠉⠁⠐⠣⠠⠕⠠⠓⠐⠜⠆");
+    }
+
+    #[test]
+    fn test_encode_charges_and_arrows() {
+        assert_eq!(encode_str("Na⁺").unwrap().0, "⠠⠝⠁⠘⠢");
+        assert_eq!(encode_str("Cl-").unwrap().0, "⠠⠉⠇⠘⠔");
+        assert_eq!(encode_str("SO₄²⁻").unwrap().0, "⠠⠎⠠⠕⠲⠘⠼⠃⠔");
+        assert_eq!(encode_str("2H2+O2").unwrap().0, "⠼⠃⠠⠓⠆⠢⠠⠕⠆");
+        assert_eq!(encode_str("→").unwrap().0, "⠒⠒⠕");
+        assert_eq!(encode_str("->").unwrap().0, "⠒⠒⠕");
+        assert_eq!(encode_str("<-").unwrap().0, "⠪⠒⠒");
+        assert_eq!(encode_str("<=>").unwrap().0, "⠪⠒⠒⠕");
+        assert_eq!(encode_str("H2->H").unwrap().0, "⠠⠓⠆⠒⠒⠕⠠⠓");
+    }
+}
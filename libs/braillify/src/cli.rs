@@ -1,41 +1,316 @@
 use std::io::{self, IsTerminal, Read, Write};
 
 use anyhow::{Result, bail};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use rustyline::{DefaultEditor, error::ReadlineError};
 
-use crate::encode_to_unicode;
+use crate::{
+    BookOptions, DocumentOptions, EmbosserOptions, EmbosserProfile, EncodeOptions, HanjaPolicy,
+    NewlinePolicy, PdfOptions, SpacePolicy, StlOptions, SvgOptions, emboss, encode_pdf, encode_stl,
+    encode_with_options, interlinear_lines, parse_blocks, parse_docx, parse_epub, parse_html,
+    parse_markdown, render_blocks, render_book, render_interlinear_html, render_interlinear_text,
+    render_svg, render_svg_with_print,
+    unicode::{encode_ascii, encode_unicode},
+};
+
+/// 입력 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    /// 일반 텍스트
+    #[default]
+    Text,
+    /// 마크다운. 제목, 목록, 인용문, 표와 강조, 코드 표시를 점자 편집 규칙에 맞게 적습니다
+    Markdown,
+    /// HTML. 스크립트와 스타일은 지우고 문단, 제목, 목록, 표와 강조를 점자 편집 규칙에 맞게 적습니다
+    Html,
+    /// EPUB 파일. 입력 문자열을 파일 경로로 읽고 묵자 쪽 번호를 적은 점자책으로 나눕니다
+    Epub,
+    /// DOCX 파일. 입력 문자열을 파일 경로로 읽고 묵자 쪽 번호를 적은 점자책으로 나눕니다
+    Docx,
+}
+
+/// 출력 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// 유니코드 점자
+    #[default]
+    Unicode,
+    /// BRF 파일에 쓰는 점자 ASCII
+    Brf,
+    /// 단어마다 묵자 아래에 유니코드 점자를 맞추어 적는 대역 텍스트
+    Interlinear,
+    /// 단어마다 묵자 아래에 유니코드 점자를 맞추어 적는 대역 HTML
+    InterlinearHtml,
+    /// 실제 치수대로 점을 그린 SVG. 면마다 SVG 문서를 적고 면 사이에는 쪽 넘김 문자를 둡니다
+    Svg,
+    /// 단어마다 점자 아래에 묵자를 흐리게 함께 적은 SVG
+    SvgPrint,
+    /// 점이 솟은 3D 인쇄용 판의 이진 STL. 일반 텍스트 입력의 줄바꿈에서 판의 줄을 바꿉니다
+    Stl,
+    /// 단어마다 칸의 점과 점 번호, 묵자를 크게 그린 교정용 PDF
+    Pdf,
+    /// 점자 프린터로 보내는 바이트. `--embosser`로 고른 명령 체계의 설정 명령 뒤에 점자책의 면을 적습니다
+    Embosser,
+}
+
+/// 입력을 읽는 방법과 결과를 적는 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Format {
+    from: InputFormat,
+    width: Option<usize>,
+    lines: Option<usize>,
+    output: OutputFormat,
+    embosser: EmbosserProfile,
+    interpoint: bool,
+}
+
+impl Format {
+    /// 문서 편집 규칙에 맞추어 줄을 나누는지 확인한다.
+    fn is_document(&self) -> bool {
+        self.from != InputFormat::Text
+            || self.width.is_some()
+            || self.lines.is_some()
+            || matches!(self.output, OutputFormat::Svg | OutputFormat::Embosser)
+    }
+
+    /// 묵자와 점자를 위아래로 맞추어 적는지 확인한다.
+    fn is_interlinear(&self) -> bool {
+        matches!(
+            self.output,
+            OutputFormat::Interlinear | OutputFormat::InterlinearHtml | OutputFormat::SvgPrint
+        )
+    }
+
+    /// 점자책의 면으로 나누는지 확인한다.
+    fn is_book(&self) -> bool {
+        matches!(self.from, InputFormat::Epub | InputFormat::Docx)
+            || self.lines.is_some()
+            || self.output == OutputFormat::Embosser
+    }
+}
 
 #[derive(Parser, Debug)]
 #[command(name = "braillify", about = "한국어 점자 변환 CLI", version)]
 struct Cli {
     /// 입력 문자열. 없으면 REPL 모드로 진입합니다
     input: Option<String>,
+    /// 화학식 모드. 원소 기호, 아래 첨자, 전하, 반응 화살표를 과학 점자로 변환합니다
+    #[arg(long)]
+    chemistry: bool,
+    /// 한자 처리 방법
+    #[arg(long, value_enum, default_value_t = HanjaPolicy::Error)]
+    hanja: HanjaPolicy,
+    /// 8점 점자로 출력합니다. 대문자와 숫자를 8점 컴퓨터 점자로 나타냅니다
+    #[arg(long)]
+    eight_dot: bool,
+    /// 연속된 공백과 탭 처리 방법
+    #[arg(long, value_enum, default_value_t = SpacePolicy::Collapse)]
+    spaces: SpacePolicy,
+    /// 문단 안의 줄바꿈 처리 방법
+    #[arg(long, value_enum, default_value_t = NewlinePolicy::Keep)]
+    newlines: NewlinePolicy,
+    /// 한 줄의 칸 수. 지정하면 문단, 목록, 제목을 찾아 들여쓰기와 줄 나눔을 점자 편집 규칙에 맞춥니다
+    #[arg(long)]
+    width: Option<usize>,
+    /// 한 면의 줄 수. 지정하거나 EPUB, DOCX를 읽으면 쪽 번호를 적은 점자책의 면으로 나눕니다
+    #[arg(long)]
+    lines: Option<usize>,
+    /// 입력 형식
+    #[arg(long, value_enum, default_value_t = InputFormat::Text)]
+    from: InputFormat,
+    /// 출력 형식
+    #[arg(long, value_enum, default_value_t = OutputFormat::Unicode)]
+    output: OutputFormat,
+    /// 점자 프린터의 명령 체계
+    #[arg(long, value_enum, default_value_t = EmbosserProfile::Generic)]
+    embosser: EmbosserProfile,
+    /// 점자 프린터에서 종이의 앞뒤에 찍습니다
+    #[arg(long)]
+    interpoint: bool,
 }
 
-pub fn run_cli(mut args: Vec<String>) -> Result<()> {
-    if args.len() == 1 && !std::io::stdin().is_terminal() {
+impl Cli {
+    fn options(&self) -> EncodeOptions {
+        EncodeOptions {
+            chemistry: self.chemistry,
+            hanja: self.hanja,
+            eight_dot: self.eight_dot,
+            spaces: self.spaces,
+            newlines: self.newlines,
+        }
+    }
+
+    fn format(&self) -> Format {
+        Format {
+            from: self.from,
+            width: self.width,
+            lines: self.lines,
+            output: self.output,
+            embosser: self.embosser,
+            interpoint: self.interpoint,
+        }
+    }
+}
+
+/// 입력을 문서 편집 규칙에 맞추어 점역해 면마다 줄로 나눈다. 점자책이 아니면 한 면에 모두 적는다.
+/// EPUB과 DOCX는 입력을 파일 경로로 읽는다.
+fn encode_input(
+    text: &str,
+    options: &EncodeOptions,
+    format: &Format,
+) -> Result<Vec<Vec<Vec<u8>>>, String> {
+    let read = |path: &str| std::fs::read(path).map_err(|e| format!("{path}: {e}"));
+    let blocks = match format.from {
+        InputFormat::Text => parse_blocks(text),
+        InputFormat::Markdown => parse_markdown(text),
+        InputFormat::Html => parse_html(text),
+        InputFormat::Epub => parse_epub(&read(text.trim())?)?,
+        InputFormat::Docx => parse_docx(&read(text.trim())?)?,
+    };
+    let document = DocumentOptions {
+        width: format.width.unwrap_or(DocumentOptions::default().width),
+        encode: options.clone(),
+        ..Default::default()
+    };
+    if !format.is_book() {
+        return Ok(vec![render_blocks(&blocks, &document)?]);
+    }
+    let book = BookOptions {
+        lines: format.lines.unwrap_or(BookOptions::default().lines),
+        document,
+    };
+    render_book(&blocks, &book)
+}
+
+/// 일반 텍스트 입력을 점역해 단어마다 묵자 아래에 점자를 맞추어 적는다.
+fn encode_interlinear(
+    text: &str,
+    options: &EncodeOptions,
+    format: &Format,
+) -> Result<String, String> {
+    if format.from != InputFormat::Text {
+        return Err("Interlinear output supports only text input".to_string());
+    }
+    let width = format.width.unwrap_or(DocumentOptions::default().width);
+    let lines = interlinear_lines(text, options, width)?;
+    Ok(match format.output {
+        OutputFormat::InterlinearHtml => render_interlinear_html(&lines),
+        OutputFormat::SvgPrint => render_svg_with_print(&lines, &SvgOptions::default()),
+        _ => render_interlinear_text(&lines),
+    })
+}
+
+/// 면마다 줄 끝에 줄바꿈을 적고, 면 사이에는 BRF처럼 쪽 넘김 문자(`\x0C`)를 둔다.
+fn format_pages(pages: &[Vec<Vec<u8>>], output: OutputFormat) -> Result<String, String> {
+    if output == OutputFormat::Svg {
+        let options = SvgOptions::default();
+        let pages: Vec<_> = pages
+            .iter()
+            .map(|page| render_svg(page, &options))
+            .collect();
+        return Ok(pages.join("\x0C"));
+    }
+    let pages = pages
+        .iter()
+        .map(|page| {
+            page.iter()
+                .map(|line| format_cells(line, output).map(|line| line + "\n"))
+                .collect::<Result<String, String>>()
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    Ok(pages.join("\x0C"))
+}
+
+fn format_cells(cells: &[u8], output: OutputFormat) -> Result<String, String> {
+    match output {
+        OutputFormat::Brf => cells.iter().map(|c| encode_ascii(*c)).collect(),
+        _ => Ok(cells.iter().map(|c| encode_unicode(*c)).collect()),
+    }
+}
+
+fn encode_to_unicode(text: &str, options: &EncodeOptions) -> Result<String, String> {
+    Ok(encode_with_options(text, options)?
+        .iter()
+        .map(|c| encode_unicode(*c))
+        .collect())
+}
+
+pub fn run_cli(args: Vec<String>) -> Result<()> {
+    let mut cli = Cli::parse_from(args);
+    if cli.input.is_none() && !std::io::stdin().is_terminal() {
         let mut buffer = vec![];
-        io::stdin().read(&mut buffer)?;
+        io::stdin().read_to_end(&mut buffer)?;
         if !buffer.is_empty() {
-            args.push(String::from_utf8(buffer)?);
+            cli.input = Some(String::from_utf8(buffer)?);
         }
     }
-    match Cli::parse_from(args).input {
-        Some(text) => run_one_shot(&text),
-        None => run_repl(),
+    let options = cli.options();
+    match &cli.input {
+        Some(text) if cli.format() == Format::default() => run_one_shot(text, &options),
+        Some(text) => run_formatted(text, &options, &cli.format()),
+        None => run_repl(&options),
+    }
+}
+
+fn run_one_shot(text: &str, options: &EncodeOptions) -> Result<()> {
+    run_formatted(text, options, &Format::default())
+}
+
+/// 일반 텍스트 입력을 점역해 STL이나 PDF 같은 이진 파일로 만든다.
+fn encode_binary(text: &str, options: &EncodeOptions, format: &Format) -> Result<Vec<u8>, String> {
+    if format.from != InputFormat::Text {
+        return Err(format!(
+            "{:?} output supports only text input",
+            format.output
+        ));
+    }
+    match format.output {
+        OutputFormat::Pdf => encode_pdf(text, options, &PdfOptions::default()),
+        _ => encode_stl(text, options, &StlOptions::default()),
     }
 }
 
-fn run_one_shot(text: &str) -> Result<()> {
-    let out = encode_to_unicode(text).map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
+/// 입력을 점자책의 면으로 나누어 점자 프린터로 보낼 바이트로 만든다.
+fn encode_embosser(
+    text: &str,
+    options: &EncodeOptions,
+    format: &Format,
+) -> Result<Vec<u8>, String> {
+    let pages = encode_input(text, options, format)?;
+    let embosser = EmbosserOptions {
+        profile: format.embosser,
+        cells_per_line: format.width.unwrap_or(DocumentOptions::default().width),
+        lines_per_page: format.lines.unwrap_or(BookOptions::default().lines),
+        interpoint: format.interpoint,
+    };
+    emboss(&pages, &embosser)
+}
+
+/// 입력을 점역해 글로 적는 출력 형식에 맞추어 적는다.
+fn format_text(text: &str, options: &EncodeOptions, format: &Format) -> Result<String, String> {
+    if format.is_interlinear() {
+        encode_interlinear(text, options, format)
+    } else if format.is_document() {
+        encode_input(text, options, format).and_then(|pages| format_pages(&pages, format.output))
+    } else {
+        encode_with_options(text, options).and_then(|cells| format_cells(&cells, format.output))
+    }
+}
+
+fn run_formatted(text: &str, options: &EncodeOptions, format: &Format) -> Result<()> {
+    let out = match format.output {
+        OutputFormat::Stl | OutputFormat::Pdf => encode_binary(text, options, format),
+        OutputFormat::Embosser => encode_embosser(text, options, format),
+        _ => format_text(text, options, format).map(String::into_bytes),
+    }
+    .map_err(|e| anyhow::anyhow!("점자 변환 실패: {}", e))?;
     let mut stdout = io::stdout();
-    stdout.write_all(out.as_bytes())?;
+    stdout.write_all(&out)?;
     stdout.flush()?;
     Ok(())
 }
 
-fn run_repl() -> Result<()> {
+fn run_repl(options: &EncodeOptions) -> Result<()> {
     let mut rl = DefaultEditor::new()?;
     let mut stdout = io::stdout();
     writeln!(
@@ -48,7 +323,7 @@ fn run_repl() -> Result<()> {
         match rl.readline("> ") {
             Ok(line) => {
                 rl.add_history_entry(&line).ok();
-                match encode_to_unicode(&line) {
+                match encode_to_unicode(&line, options) {
                     Ok(out) => writeln!(stdout, "{}", out)?,
                     Err(e) => writeln!(stdout, "오류: {}", e)?,
                 }
@@ -82,17 +357,245 @@ mod tests {
         assert_eq!(cli.input, None);
     }
 
+    #[test]
+    fn test_cli_parsing_chemistry() {
+        let args = vec!["braillify", "--chemistry", "H2O"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.options().chemistry);
+        assert_eq!(encode_to_unicode("H2O", &cli.options()).unwrap(), "⠠⠓⠆⠠⠕");
+    }
+
+    #[test]
+    fn test_cli_parsing_hanja() {
+        let args = vec!["braillify", "--hanja", "reading", "國民"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.options().hanja, HanjaPolicy::Reading);
+        assert_eq!(
+            Cli::try_parse_from(vec!["braillify", "國民"])
+                .unwrap()
+                .hanja,
+            HanjaPolicy::Error
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_eight_dot() {
+        let args = vec!["braillify", "--eight-dot", "ABC"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.options().eight_dot);
+        assert_eq!(encode_to_unicode("ABC", &cli.options()).unwrap(), "⡁⡃⡉");
+    }
+
+    #[test]
+    fn test_cli_parsing_interlinear() {
+        let args = vec![
+            "braillify",
+            "--output",
+            "interlinear",
+            "--width",
+            "7",
+            "상 상 상",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_interlinear());
+        assert_eq!(
+            encode_interlinear("상 상 상", &cli.options(), &cli.format()).unwrap(),
+            "상 상\n⠇⠶⠀⠇⠶\n\n상\n⠇⠶\n"
+        );
+        let args = vec!["braillify", "--output", "interlinear-html", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(
+            encode_interlinear("상", &cli.options(), &cli.format())
+                .unwrap()
+                .contains("<span class=\"braille\">⠇⠶</span>")
+        );
+        let args = vec![
+            "braillify",
+            "--from",
+            "html",
+            "--output",
+            "interlinear",
+            "상",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(encode_interlinear("상", &cli.options(), &cli.format()).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_svg() {
+        let args = vec!["braillify", "--output", "svg", "--lines", "3", "상 상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_document());
+        let pages = encode_input("상 상", &cli.options(), &cli.format()).unwrap();
+        let svg = format_pages(&pages, cli.output).unwrap();
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("</svg>").count(), pages.len());
+        assert!(!svg.contains("<text"));
+        let args = vec!["braillify", "--output", "svg-print", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_interlinear());
+        assert!(
+            encode_interlinear("상", &cli.options(), &cli.format())
+                .unwrap()
+                .contains(">상</text>")
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_stl() {
+        let args = vec!["braillify", "--output", "stl", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.output, OutputFormat::Stl);
+        let stl = encode_binary("상", &cli.options(), &cli.format()).unwrap();
+        assert_eq!(&stl[..9], b"braillify");
+        let args = vec!["braillify", "--from", "markdown", "--output", "stl", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(encode_binary("상", &cli.options(), &cli.format()).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_embosser() {
+        let args = vec![
+            "braillify",
+            "--output",
+            "embosser",
+            "--embosser",
+            "index",
+            "--interpoint",
+            "--width",
+            "12",
+            "--lines",
+            "5",
+            "상",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_book());
+        let bytes = encode_embosser("상", &cli.options(), &cli.format()).unwrap();
+        assert!(bytes.starts_with(b"\x1bDBT0,CH12,LP5,DP2,PN0;\r\n  L7\r\n"));
+        assert!(bytes.ends_with(b"\x0C"));
+        let args = vec!["braillify", "--output", "embosser", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.embosser, EmbosserProfile::Generic);
+        let bytes = encode_embosser("상", &cli.options(), &cli.format()).unwrap();
+        assert_eq!(bytes.iter().filter(|b| **b == b'\n').count(), 25);
+    }
+
+    #[test]
+    fn test_cli_parsing_pdf() {
+        let args = vec!["braillify", "--output", "pdf", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        let pdf = encode_binary("상", &cli.options(), &cli.format()).unwrap();
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        let args = vec!["braillify", "--from", "html", "--output", "pdf", "상"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(
+            encode_binary("상", &cli.options(), &cli.format()),
+            Err("Pdf output supports only text input".to_string())
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_width() {
+        let args = vec!["braillify", "--width", "10", "상 상 상 상."];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.width, Some(10));
+        let pages = encode_input("상 상 상 상.", &cli.options(), &cli.format()).unwrap();
+        assert_eq!(
+            format_pages(&pages, OutputFormat::Unicode).unwrap(),
+            "⠀⠀⠇⠶⠀⠇⠶⠀⠇⠶\n⠇⠶⠲\n"
+        );
+        assert!(run_formatted("상", &cli.options(), &cli.format()).is_ok());
+    }
+
+    #[test]
+    fn test_cli_parsing_markdown_and_brf() {
+        let args = vec![
+            "braillify",
+            "--from",
+            "markdown",
+            "--output",
+            "brf",
+            "**가**",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.format().from, InputFormat::Markdown);
+        let pages = encode_input("**가**", &cli.options(), &cli.format()).unwrap();
+        assert_eq!(format_pages(&pages, cli.output).unwrap(), "  ;-$-2\n");
+        assert!(format_cells(&[0x40], OutputFormat::Brf).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_html() {
+        let args = vec!["braillify", "--from", "html", "--width", "10"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.format().from, InputFormat::Html);
+        let pages = encode_input(
+            "<script>x</script><h1>상</h1><p>상 상<br>상</p>",
+            &cli.options(),
+            &cli.format(),
+        )
+        .unwrap();
+        assert_eq!(
+            format_pages(&pages, cli.output).unwrap(),
+            "⠀⠀⠀⠀⠇⠶\n⠀⠀⠇⠶⠀⠇⠶\n⠇⠶\n"
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_book() {
+        let args = vec![
+            "braillify",
+            "--lines",
+            "4",
+            "--width",
+            "6",
+            "상\n\n상 상\n\n상 상",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_book());
+        let pages =
+            encode_input(cli.input.as_deref().unwrap(), &cli.options(), &cli.format()).unwrap();
+        assert_eq!(
+            format_pages(&pages, OutputFormat::Unicode).unwrap(),
+            "\n⠀⠀⠇⠶\n\n⠀⠀⠀⠀⠼⠁\n\x0C\n⠇⠶⠀⠇⠶\n\n⠀⠀⠀⠀⠼⠃\n\x0C\n⠀⠀⠇⠶\n⠇⠶\n⠀⠀⠀⠀⠼⠉\n"
+        );
+
+        let args = vec!["braillify", "--from", "epub", "없는 파일.epub"];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert!(cli.format().is_book());
+        assert!(encode_input("없는 파일.epub", &cli.options(), &cli.format()).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_whitespace() {
+        let args = vec![
+            "braillify",
+            "--spaces",
+            "preserve",
+            "--newlines",
+            "join",
+            "가",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+        assert_eq!(cli.options().spaces, SpacePolicy::Preserve);
+        assert_eq!(cli.options().newlines, NewlinePolicy::Join);
+        assert_eq!(
+            encode_to_unicode("가  나\n다", &cli.options()).unwrap(),
+            "⠫⠀⠀⠉⠀⠊"
+        );
+    }
+
     // 유닛 테스트들
     #[test]
     fn test_run_one_shot_success() {
-        let result = run_one_shot("안녕");
+        let result = run_one_shot("안녕", &EncodeOptions::default());
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_run_one_shot_invalid_input() {
         // 빈 문자열이나 특수한 경우 테스트
-        let result = run_one_shot("");
+        let result = run_one_shot("", &EncodeOptions::default());
         assert!(result.is_ok()); // 빈 문자열도 유효한 입력
     }
 
@@ -102,7 +605,7 @@ mod tests {
         let long_text = "안녕하세요 ".repeat(100);
         let start = std::time::Instant::now();
 
-        let result = run_one_shot(&long_text);
+        let result = run_one_shot(&long_text, &EncodeOptions::default());
         let duration = start.elapsed();
 
         assert!(result.is_ok());
@@ -115,7 +618,7 @@ mod tests {
     fn test_braillify_error_handling() {
         // 특수 문자나 매우 긴 입력에 대한 에러 처리 테스트
         let very_long_text = "a".repeat(10000);
-        let result = run_one_shot(&very_long_text);
+        let result = run_one_shot(&very_long_text, &EncodeOptions::default());
         // 에러가 발생하더라도 프로그램이 크래시되지 않아야 함
         // (실제로는 성공할 수도 있지만, 에러 처리가 제대로 되는지 확인)
         let _ = result;
@@ -123,7 +626,7 @@ mod tests {
 
     #[test]
     fn test_braillify_invalid_input() {
-        let result = run_one_shot("§");
+        let result = run_one_shot("§", &EncodeOptions::default());
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,287 @@
+use phf::phf_map;
+
+use crate::{
+    capital::CAPITAL_LETTER,
+    english,
+    unicode::{DOT_7, decode_unicode},
+};
+
+/// 컴퓨터 점자 시작 표. 웹 주소, 전자 우편 주소, 프로그램 코드는 컴퓨터 점자 구간으로 적는다.
+pub const COMPUTER_START: [u8; 2] = [decode_unicode('⠸'), decode_unicode('⠫')];
+/// 컴퓨터 점자 종료 표
+pub const COMPUTER_END: [u8; 2] = [decode_unicode('⠸'), decode_unicode('⠱')];
+
+/// 웹 주소의 시작
+const URL_PREFIXES: [&str; 4] = ["https://", "http://", "ftp://", "www."];
+
+/// 프로그램 코드에서만 쓰이는 글자 묶음. `a_b`처럼 밑줄만 있는 단어는 일반 로마자로 적는다.
+const CODE_PATTERNS: [&str; 8] = ["->", "=>", "::", "()", "==", "!=", "&&", "||"];
+
+/// 중괄호는 `f(){`, `x={`처럼 식별자나 연산자 바로 뒤에 올 때만 코드로 본다.
+fn has_code_brace(span: &str) -> bool {
+    span.match_indices('{').any(|(idx, _)| {
+        span[..idx]
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, ')' | '=' | '_'))
+    })
+}
+
+/// 컴퓨터 점자의 숫자와 기호 (북미 컴퓨터 점자 코드). 로마자는 약자 없이 한 글자씩 적는다.
+static COMPUTER_SYMBOL_MAP: phf::Map<char, u8> = phf_map! {
+    ' ' => decode_unicode('⠀'),
+    '!' => decode_unicode('⠮'),
+    '"' => decode_unicode('⠐'),
+    '#' => decode_unicode('⠼'),
+    '$' => decode_unicode('⠫'),
+    '%' => decode_unicode('⠩'),
+    '&' => decode_unicode('⠯'),
+    '\'' => decode_unicode('⠄'),
+    '(' => decode_unicode('⠷'),
+    ')' => decode_unicode('⠾'),
+    '*' => decode_unicode('⠡'),
+    '+' => decode_unicode('⠬'),
+    ',' => decode_unicode('⠠'),
+    '-' => decode_unicode('⠤'),
+    '.' => decode_unicode('⠨'),
+    '/' => decode_unicode('⠌'),
+    '0' => decode_unicode('⠴'),
+    '1' => decode_unicode('⠂'),
+    '2' => decode_unicode('⠆'),
+    '3' => decode_unicode('⠒'),
+    '4' => decode_unicode('⠲'),
+    '5' => decode_unicode('⠢'),
+    '6' => decode_unicode('⠖'),
+    '7' => decode_unicode('⠶'),
+    '8' => decode_unicode('⠦'),
+    '9' => decode_unicode('⠔'),
+    ':' => decode_unicode('⠱'),
+    ';' => decode_unicode('⠰'),
+    '<' => decode_unicode('⠣'),
+    '=' => decode_unicode('⠿'),
+    '>' => decode_unicode('⠜'),
+    '?' => decode_unicode('⠹'),
+    '@' => decode_unicode('⠈'),
+    '[' => decode_unicode('⠪'),
+    '\\' => decode_unicode('⠳'),
+    ']' => decode_unicode('⠻'),
+    '^' => decode_unicode('⠘'),
+    '_' => decode_unicode('⠸'),
+    '`' => decode_unicode('⠈'),
+    '{' => decode_unicode('⠪'),
+    '|' => decode_unicode('⠳'),
+    '}' => decode_unicode('⠻'),
+    '~' => decode_unicode('⠘'),
+};
+
+/// 컴퓨터 점자 한 글자. 6점 점자의 대문자 기호는 [`encode_computer`]가 앞에 붙인다.
+pub fn encode_computer_char(c: char) -> Result<u8, String> {
+    if c.is_ascii_alphabetic() {
+        return english::encode_english(c);
+    }
+    COMPUTER_SYMBOL_MAP
+        .get(&c)
+        .copied()
+        .ok_or_else(|| "Invalid computer braille character".to_string())
+}
+
+/// 8점 컴퓨터 점자 한 글자. 대문자와 `@[\]^_`에는 7점을 더한다.
+pub fn encode_computer_char_eight_dot(c: char) -> Result<u8, String> {
+    let cell = encode_computer_char(c)?;
+    if c.is_ascii_uppercase() || matches!(c, '@' | '[' | '\\' | ']' | '^' | '_') {
+        return Ok(cell | DOT_7);
+    }
+    Ok(cell)
+}
+
+/// 컴퓨터 점자 구간을 적는다. `` `…` ``로 표시한 코드는 역따옴표를 빼고 적는다.
+pub fn encode_computer(span: &str, eight_dot: bool) -> Result<Vec<u8>, String> {
+    let body = span
+        .strip_prefix('`')
+        .and_then(|body| body.strip_suffix('`'))
+        .unwrap_or(span);
+    if eight_dot {
+        return body.chars().map(encode_computer_char_eight_dot).collect();
+    }
+    let mut result = Vec::with_capacity(body.len());
+    for c in body.chars() {
+        if c.is_ascii_uppercase() {
+            result.extend(CAPITAL_LETTER);
+        }
+        result.push(encode_computer_char(c)?);
+    }
+    Ok(result)
+}
+
+/// `text`가 `` ` ``로 시작할 때 코드 표시 구간의 길이(바이트)를 반환한다.
+pub fn code_span_len(text: &str) -> Option<usize> {
+    let body = text.strip_prefix('`')?;
+    let end = body.find('`').filter(|end| *end > 0)?;
+    Some(end + 2)
+}
+
+/// `word` 안에서 `` `…` ``로 표시한 코드 구간의 바이트 범위를 찾는다.
+pub fn find_code_markup(word: &str) -> Option<(usize, usize)> {
+    let start = word.find('`')?;
+    code_span_len(&word[start..]).map(|len| (start, start + len))
+}
+
+fn is_url_char(c: char) -> bool {
+    c.is_ascii_graphic()
+}
+
+fn is_email_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-')
+}
+
+fn is_email_domain_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '-')
+}
+
+/// 구간 끝에 붙은 문장 부호는 구간에서 뺀다.
+fn trim_trailing_punctuation(span: &str) -> &str {
+    let mut span = span;
+    while let Some(last) = span.chars().last() {
+        let unmatched_paren = last == ')' && span.matches('(').count() < span.matches(')').count();
+        if matches!(last, '.' | ',' | ';' | ':' | '!' | '?' | '"' | '\'') || unmatched_paren {
+            span = &span[..span.len() - 1];
+        } else {
+            break;
+        }
+    }
+    span
+}
+
+fn find_url(word: &str) -> Option<(usize, usize)> {
+    let start = URL_PREFIXES
+        .iter()
+        .filter_map(|prefix| word.find(prefix))
+        .filter(|start| {
+            !word[..*start]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_ascii_alphanumeric())
+        })
+        .min()?;
+    let rest = &word[start..];
+    let len = rest.find(|c| !is_url_char(c)).unwrap_or(rest.len());
+    let span = trim_trailing_punctuation(&rest[..len]);
+    URL_PREFIXES
+        .iter()
+        .any(|prefix| span.len() > prefix.len() && span.starts_with(prefix))
+        .then_some((start, start + span.len()))
+}
+
+fn find_email(word: &str) -> Option<(usize, usize)> {
+    let at = word.find('@')?;
+    let start = word[..at]
+        .rfind(|c| !is_email_local_char(c))
+        .map_or(0, |idx| {
+            idx + word[idx..].chars().next().map_or(1, char::len_utf8)
+        });
+    let domain = &word[at + 1..];
+    let domain_len = domain
+        .find(|c| !is_email_domain_char(c))
+        .unwrap_or(domain.len());
+    let domain = domain[..domain_len].trim_end_matches(['.', '-']);
+    let tld = domain.rsplit_once('.')?.1;
+    if start == at || tld.len() < 2 || !tld.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    Some((start, at + 1 + domain.len()))
+}
+
+fn find_code(word: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    for run in word.split(|c: char| !c.is_ascii_graphic()) {
+        let start = offset;
+        offset += run.len()
+            + word[start + run.len()..]
+                .chars()
+                .next()
+                .map_or(0, char::len_utf8);
+        let span = trim_trailing_punctuation(run);
+        if span.chars().any(|c| c.is_ascii_alphabetic())
+            && (CODE_PATTERNS.iter().any(|pattern| span.contains(pattern)) || has_code_brace(span))
+        {
+            return Some((start, start + span.len()));
+        }
+    }
+    None
+}
+
+/// `word` 안에서 웹 주소, 전자 우편 주소, 프로그램 코드로 보이는 구간의 바이트 범위를 찾는다.
+pub fn find_computer_span(word: &str) -> Option<(usize, usize)> {
+    find_url(word)
+        .or_else(|| find_email(word))
+        .or_else(|| find_code(word))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(span: &str) -> String {
+        encode_computer(span, false)
+            .unwrap()
+            .into_iter()
+            .map(encode_unicode)
+            .collect()
+    }
+
+    fn span(word: &str) -> Option<&str> {
+        find_computer_span(word).map(|(start, end)| &word[start..end])
+    }
+
+    #[test]
+    fn test_find_computer_span() {
+        assert_eq!(
+            span("https://braillify.kr에서"),
+            Some("https://braillify.kr")
+        );
+        assert_eq!(span("(www.example.com)."), Some("www.example.com"));
+        assert_eq!(span("owjs39@gmail.com으로"), Some("owjs39@gmail.com"));
+        assert_eq!(span("a_b->c()"), Some("a_b->c()"));
+        assert_eq!(span("print()."), Some("print()"));
+        assert_eq!(span("user@localhost"), None);
+        assert_eq!(span("(a)"), None);
+        assert_eq!(span("__"), None);
+        assert_eq!(span("a_b"), None);
+        assert_eq!(span("{x}"), None);
+        assert_eq!(span("main(){"), Some("main(){"));
+        assert_eq!(span("x={a}"), Some("x={a}"));
+        assert_eq!(span("안녕"), None);
+    }
+
+    #[test]
+    fn test_find_code_markup() {
+        assert_eq!(find_code_markup("`ls`를"), Some((0, 4)));
+        assert_eq!(code_span_len("`a b` c"), Some(5));
+        assert_eq!(find_code_markup("``"), None);
+    }
+
+    #[test]
+    fn test_encode_computer_eight_dot() {
+        let cells = encode_computer("Ab@`_", true).unwrap();
+        assert_eq!(
+            cells.into_iter().map(encode_unicode).collect::<String>(),
+            "⡁⠃⡈⠈⡸"
+        );
+    }
+
+    #[test]
+    fn test_encode_computer() {
+        assert_eq!(encode_str("https://braillify.kr"), "⠓⠞⠞⠏⠎⠱⠌⠌⠃⠗⠁⠊⠇⠇⠊⠋⠽⠨⠅⠗");
+        assert_eq!(encode_str("owjs39@gmail.com"), "⠕⠺⠚⠎⠒⠔⠈⠛⠍⠁⠊⠇⠨⠉⠕⠍");
+        assert_eq!(encode_str("`a_b->c()`"), "⠁⠸⠃⠤⠜⠉⠷⠾");
+        assert!(encode_computer("한", false).is_err());
+    }
+
+    #[test]
+    fn test_encode_computer_capital() {
+        assert_eq!(encode_str("https://Braillify.kr"), "⠓⠞⠞⠏⠎⠱⠌⠌⠠⠃⠗⠁⠊⠇⠇⠊⠋⠽⠨⠅⠗");
+        assert_eq!(encode_str("A&&B"), "⠠⠁⠯⠯⠠⠃");
+        assert_eq!(encode_str("`getElementById()`"), "⠛⠑⠞⠠⠑⠇⠑⠍⠑⠝⠞⠠⠃⠽⠠⠊⠙⠷⠾");
+    }
+}
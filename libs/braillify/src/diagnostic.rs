@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// 점역은 계속할 수 있지만 결과가 원문과 완전히 일치하지 않을 수 있는 경우를 알린다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// 지원하지 않는 LaTeX 명령. 명령 이름은 생략하고 인자만 점역한다.
+    UnsupportedLatexCommand(String),
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::UnsupportedLatexCommand(name) => {
+                write!(f, "Unsupported LaTeX command: \\{}", name)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Diagnostic::UnsupportedLatexCommand("mathbb".to_string()).to_string(),
+            "Unsupported LaTeX command: \\mathbb"
+        );
+    }
+}
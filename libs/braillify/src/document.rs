@@ -0,0 +1,680 @@
+use crate::{
+    EncodeOptions, LineBreak, computer, emphasis, encode_with_emphasis, encode_with_encoder,
+    layout::{wrap, wrap_indented},
+    tokenize,
+    unicode::decode_unicode,
+};
+
+/// 묵자 쪽 바뀜 줄을 채우는 점형 ⠤
+const PAGE_BREAK_FILL: u8 = decode_unicode('⠤');
+
+/// 순서 없는 목록의 항목 기호 ⠸⠲
+const BULLET: [u8; 2] = [decode_unicode('⠸'), decode_unicode('⠲')];
+
+/// 순서 없는 목록의 항목 기호로 보는 글자
+const BULLET_MARKERS: [char; 7] = ['-', '*', '+', '•', '◦', '▪', '·'];
+
+/// 문장을 끝내는 문장 부호. 이것으로 끝나는 줄은 제목으로 보지 않는다.
+const SENTENCE_ENDINGS: [char; 10] = ['.', '?', '!', ',', ';', ':', '…', '。', '」', '"'];
+
+/// 제목으로 볼 수 있는 줄의 최대 글자 수
+const HEADING_MAX_CHARS: usize = 40;
+
+/// 문서를 이루는 단위. 마크다운, HTML, DOCX에서 읽은 블록의 글에는 강조 구간이 표시 문자로 들어 있다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// 앞뒤가 빈 줄로 나뉜, 문장 부호로 끝나지 않는 짧은 한 줄
+    Heading(String),
+    /// 문단. 여러 줄에 걸쳐 쓴 문단은 띄어쓰기로 이어 붙인다.
+    Paragraph(String),
+    /// 목록 항목. `depth`는 들여쓰기 단계(0부터), `marker`는 `1.`, `가)`, `(1)` 같은 번호이며
+    /// `-`, `•` 같은 기호로 시작하는 항목이면 `None`이다.
+    ListItem {
+        depth: usize,
+        marker: Option<String>,
+        text: String,
+    },
+    /// 인용문
+    Quote(String),
+    /// 프로그램 코드 묶음. 줄마다 컴퓨터 점자로 적는다.
+    Code(String),
+    /// 표의 한 행
+    TableRow(Vec<String>),
+    /// 묵자 쪽이 바뀌는 곳. 새 쪽의 번호를 가진다.
+    PageBreak(String),
+    /// 빈 줄
+    BlankLine,
+}
+
+/// 문서 편집 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentOptions {
+    /// 한 줄의 칸 수
+    pub width: usize,
+    /// 문단 첫 줄의 들여쓰기 칸 수
+    pub paragraph_indent: usize,
+    /// 목록 항목의 들여쓰기 단계마다 더하는 칸 수. 항목의 둘째 줄부터는 한 단계 더 들여 쓴다.
+    pub list_indent: usize,
+    /// 점역 설정
+    pub encode: EncodeOptions,
+}
+
+impl Default for DocumentOptions {
+    fn default() -> Self {
+        Self {
+            width: 32,
+            paragraph_indent: 2,
+            list_indent: 2,
+            encode: EncodeOptions::default(),
+        }
+    }
+}
+
+/// 줄 첫머리의 들여쓰기 칸 수와 나머지 글
+fn split_indent(line: &str) -> (usize, &str) {
+    let mut width = 0;
+    for (idx, c) in line.char_indices() {
+        match tokenize::classify(c) {
+            Some(tokenize::Blank::Tab) => width += tokenize::TAB_WIDTH,
+            Some(_) => width += 1,
+            None => return (width, &line[idx..]),
+        }
+    }
+    (width, "")
+}
+
+/// `1.`, `1)`, `(1)`, `가.`, `가)`, `a.`, `①`처럼 순서 있는 목록의 번호인지 확인한다.
+fn is_ordered_marker(marker: &str) -> bool {
+    let body = marker
+        .strip_prefix('(')
+        .and_then(|body| body.strip_suffix(')'))
+        .or_else(|| marker.strip_suffix('.'))
+        .or_else(|| marker.strip_suffix(')'));
+    match body {
+        // `2024. 3. 1.`처럼 쓴 날짜와 나누려고 두 자리 수까지만 번호로 본다.
+        Some(body) if body.chars().all(|c| c.is_ascii_digit()) => (1..=2).contains(&body.len()),
+        Some(body) => {
+            let mut chars = body.chars();
+            chars.next().is_some_and(|c| {
+                c.is_ascii_alphabetic() || ('가'..='하').contains(&c) || ('ㄱ'..='ㅎ').contains(&c)
+            }) && chars.next().is_none()
+        }
+        None => {
+            let mut chars = marker.chars();
+            chars.next().is_some_and(|c| ('①'..='⑳').contains(&c)) && chars.next().is_none()
+        }
+    }
+}
+
+/// 목록 항목의 번호(기호 목록이면 `None`)와 항목의 글
+fn split_list_marker(line: &str) -> Option<(Option<&str>, &str)> {
+    let (marker, text) = line
+        .split_once(|c| tokenize::classify(c).is_some())
+        .unwrap_or((line, ""));
+    let text = text.trim_start();
+    let mut chars = marker.chars();
+    if chars.next().is_some_and(|c| BULLET_MARKERS.contains(&c)) && chars.next().is_none() {
+        return Some((None, text));
+    }
+    (is_ordered_marker(marker) && !text.is_empty()).then_some((Some(marker), text))
+}
+
+fn is_heading_line(line: &str) -> bool {
+    line.chars().count() <= HEADING_MAX_CHARS
+        && !line.ends_with(SENTENCE_ENDINGS)
+        && split_list_marker(line).is_none()
+}
+
+/// 일반 텍스트에서 문단, 목록 항목, 제목, 빈 줄을 찾는다.
+///
+/// 빈 줄이나 들여 쓴 줄에서 새 문단이 시작되고, 이어지는 줄은 앞 문단에 붙인다.
+/// `-`, `•` 같은 기호나 `1.`, `가)` 같은 번호로 시작하는 줄은 목록 항목이며, 들여쓰기 두 칸을
+/// 한 단계로 본다. 항목보다 깊게 들여 쓴 다음 줄은 그 항목에 붙인다.
+pub fn parse_blocks(text: &str) -> Vec<Block> {
+    // 블록과 그 블록을 이루는 줄 수
+    let mut blocks: Vec<(Block, usize)> = Vec::new();
+    // 지금 이어 붙일 수 있는 블록의 들여쓰기
+    let mut open: Option<usize> = None;
+    let text = emphasis::replace_marks(text);
+    for line in text.lines() {
+        let (indent, line) = split_indent(line);
+        let line = line.trim_end();
+        if line.is_empty() {
+            blocks.push((Block::BlankLine, 1));
+            open = None;
+            continue;
+        }
+        if let Some((marker, item)) = split_list_marker(line) {
+            blocks.push((
+                Block::ListItem {
+                    depth: indent / 2,
+                    marker: marker.map(str::to_string),
+                    text: item.to_string(),
+                },
+                1,
+            ));
+            open = Some(indent);
+            continue;
+        }
+        match (open, blocks.last_mut()) {
+            (Some(item_indent), Some((Block::ListItem { text, .. }, lines)))
+                if indent > item_indent =>
+            {
+                text.push(' ');
+                text.push_str(line);
+                *lines += 1;
+            }
+            (Some(_), Some((Block::Paragraph(text), lines))) if indent == 0 => {
+                text.push(' ');
+                text.push_str(line);
+                *lines += 1;
+            }
+            _ => {
+                blocks.push((Block::Paragraph(line.to_string()), 1));
+                open = Some(0);
+            }
+        }
+    }
+    mark_headings(&mut blocks);
+    blocks.into_iter().map(|(block, _)| block).collect()
+}
+
+/// 앞뒤가 빈 줄이고 뒤에 다른 글이 이어지는, 문장 부호로 끝나지 않는 한 줄짜리 문단을 제목으로 바꾼다.
+fn mark_headings(blocks: &mut [(Block, usize)]) {
+    for idx in 0..blocks.len() {
+        let (Block::Paragraph(paragraph), 1) = &blocks[idx] else {
+            continue;
+        };
+        let after_blank = idx == 0 || blocks[idx - 1].0 == Block::BlankLine;
+        let before_blank = blocks
+            .get(idx + 1)
+            .is_some_and(|(block, _)| *block == Block::BlankLine);
+        let has_more = blocks[idx + 1..]
+            .iter()
+            .any(|(block, _)| *block != Block::BlankLine);
+        if after_blank && before_blank && has_more && is_heading_line(paragraph) {
+            blocks[idx].0 = Block::Heading(paragraph.clone());
+        }
+    }
+}
+
+/// 표의 칸 사이에 두는 빈칸 수
+const TABLE_CELL_GAP: usize = 2;
+
+/// 프로그램 코드 묶음을 줄마다 적는다. 첫 줄 앞에 컴퓨터 점자 시작 표를, 마지막 줄 뒤에
+/// 종료 표를 적고, 컴퓨터 점자로 적을 수 없는 글자가 있는 줄은 일반 점자로 적는다.
+fn render_code(code: &str, options: &DocumentOptions) -> Result<Vec<Vec<u8>>, String> {
+    let code_lines = code.lines().collect::<Vec<_>>();
+    let mut lines = Vec::new();
+    for (idx, line) in code_lines.iter().enumerate() {
+        let mut cells = Vec::new();
+        if idx == 0 {
+            cells.extend(computer::COMPUTER_START);
+        }
+        match computer::encode_computer(line, options.encode.eight_dot) {
+            Ok(code) => cells.extend(code),
+            Err(_) => cells.extend(encode_with_encoder(line, &options.encode)?.0),
+        }
+        if idx + 1 == code_lines.len() {
+            cells.extend(computer::COMPUTER_END);
+        }
+        lines.extend(wrap(&cells, &[], options.width));
+    }
+    Ok(lines)
+}
+
+/// 표의 한 행을 칸마다 점역해 [`TABLE_CELL_GAP`]칸씩 띄어 잇는다.
+fn encode_table_row(
+    row: &[String],
+    options: &DocumentOptions,
+) -> Result<(Vec<u8>, Vec<LineBreak>), String> {
+    let mut cells = Vec::new();
+    let mut breaks = Vec::new();
+    for (idx, text) in row.iter().enumerate() {
+        if idx > 0 {
+            cells.extend(std::iter::repeat_n(0, TABLE_CELL_GAP));
+            breaks.push(LineBreak::Space(cells.len() - 1));
+        }
+        let (encoded, encoder) = encode_with_emphasis(text, &options.encode)?;
+        let offset = cells.len();
+        breaks.extend(
+            encoder
+                .line_breaks()
+                .iter()
+                .map(|line_break| line_break.shifted(offset)),
+        );
+        cells.extend(encoded);
+    }
+    Ok((cells, breaks))
+}
+
+/// 묵자 쪽 바뀜 줄. 줄을 ⠤으로 채우고 끝에 새 쪽의 번호를 적는다.
+pub(crate) fn page_break_line(page: &str, options: &DocumentOptions) -> Result<Vec<u8>, String> {
+    let number = encode_with_encoder(page, &options.encode)?.0;
+    let mut line = vec![PAGE_BREAK_FILL; options.width.saturating_sub(number.len())];
+    line.extend(number);
+    Ok(line)
+}
+
+/// 블록을 점역해 줄마다 나눈다.
+///
+/// 제목은 가운데에 적고, 문단은 첫 줄을 `paragraph_indent`칸 들여 쓴다. 목록 항목은 단계마다
+/// `list_indent`칸씩 들여 쓰고, 둘째 줄부터는 한 단계 더 들여 쓴다. 인용문은 모든 줄을
+/// `list_indent`칸 들여 쓰고, 표는 한 행을 한 항목처럼 적는다. 묵자 쪽이 바뀌는 곳에는
+/// ⠤으로 채운 줄 끝에 쪽 번호를 적고, 빈 줄은 그대로 둔다.
+pub fn render_blocks(blocks: &[Block], options: &DocumentOptions) -> Result<Vec<Vec<u8>>, String> {
+    let mut lines = Vec::new();
+    for block in blocks {
+        match block {
+            Block::Heading(text) => {
+                let (cells, encoder) = encode_with_emphasis(text, &options.encode)?;
+                for line in wrap_indented(&cells, encoder.line_breaks(), options.width, 0, 0) {
+                    let margin = options.width.saturating_sub(line.len()) / 2;
+                    let mut centered = vec![0; margin];
+                    centered.extend(line);
+                    lines.push(centered);
+                }
+            }
+            Block::Paragraph(text) => {
+                let (cells, encoder) = encode_with_emphasis(text, &options.encode)?;
+                lines.extend(wrap_indented(
+                    &cells,
+                    encoder.line_breaks(),
+                    options.width,
+                    options.paragraph_indent,
+                    0,
+                ));
+            }
+            Block::ListItem {
+                depth,
+                marker,
+                text,
+            } => {
+                let (mut cells, encoder) = match marker {
+                    Some(marker) => {
+                        encode_with_emphasis(&format!("{marker} {text}"), &options.encode)?
+                    }
+                    None => encode_with_emphasis(text, &options.encode)?,
+                };
+                let mut breaks = encoder.line_breaks().to_vec();
+                if marker.is_none() {
+                    cells.splice(0..0, BULLET.into_iter().chain([0]));
+                    breaks = breaks
+                        .into_iter()
+                        .map(|line_break| line_break.shifted(BULLET.len() + 1))
+                        .collect();
+                }
+                let indent = depth * options.list_indent;
+                lines.extend(wrap_indented(
+                    &cells,
+                    &breaks,
+                    options.width,
+                    indent,
+                    indent + options.list_indent,
+                ));
+            }
+            Block::Quote(text) => {
+                let (cells, encoder) = encode_with_emphasis(text, &options.encode)?;
+                lines.extend(wrap_indented(
+                    &cells,
+                    encoder.line_breaks(),
+                    options.width,
+                    options.list_indent,
+                    options.list_indent,
+                ));
+            }
+            Block::Code(code) => lines.extend(render_code(code, options)?),
+            Block::TableRow(row) => {
+                let (cells, breaks) = encode_table_row(row, options)?;
+                lines.extend(wrap_indented(
+                    &cells,
+                    &breaks,
+                    options.width,
+                    0,
+                    options.list_indent,
+                ));
+            }
+            Block::PageBreak(page) => lines.push(page_break_line(page, options)?),
+            Block::BlankLine => lines.push(Vec::new()),
+        }
+    }
+    Ok(lines)
+}
+
+/// 마크다운이나 HTML처럼 구조를 가진 입력을 여는 표시와 닫는 표시를 따라 블록으로 모은다.
+#[cfg(any(feature = "markdown", feature = "html"))]
+#[derive(Default)]
+pub(crate) struct BlockBuilder {
+    blocks: Vec<Block>,
+    /// 지금 모으고 있는 글. 기울임과 굵은 글씨는 강조 구간 표시 문자로 남긴다.
+    text: String,
+    /// 열린 목록마다 다음 번호 (기호 목록이면 `None`)
+    lists: Vec<Option<u64>>,
+    /// 아직 블록으로 만들지 않은 목록 항목의 단계와 번호
+    item: Option<(usize, Option<String>)>,
+    quote_depth: usize,
+    code: Option<String>,
+    row: Option<Vec<String>>,
+    /// 글 중간에서 만나 지금 블록 뒤에 둘 묵자 쪽 번호
+    page_breaks: Vec<String>,
+}
+
+#[cfg(any(feature = "markdown", feature = "html"))]
+impl BlockBuilder {
+    /// 블록을 더하고, 그 블록을 만드는 중에 만난 묵자 쪽 바뀜을 뒤에 둔다.
+    fn push(&mut self, block: Block) {
+        self.blocks.push(block);
+        self.blocks
+            .extend(self.page_breaks.drain(..).map(Block::PageBreak));
+    }
+
+    fn take_text(&mut self) -> String {
+        std::mem::take(&mut self.text).trim().to_string()
+    }
+
+    /// 지금 모으고 있는 글
+    #[cfg(feature = "html")]
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 입력의 글을 더한다. 프로그램 코드 묶음 안이면 코드에 더한다.
+    pub(crate) fn push_str(&mut self, text: &str) {
+        let text = emphasis::replace_marks(text);
+        match self.code.as_mut() {
+            Some(code) => code.push_str(&text),
+            None => self.text.push_str(&text),
+        }
+    }
+
+    /// 강조 구간 표시 문자를 더한다. 닫는 표시 앞의 띄어쓰기는 표시 뒤로 옮긴다.
+    pub(crate) fn push_mark(&mut self, mark: char) {
+        if matches!(mark, emphasis::EMPHASIS_CLOSE | emphasis::BOLD_CLOSE)
+            && self.text.ends_with(' ')
+        {
+            self.text.pop();
+            self.text.push(mark);
+            self.text.push(' ');
+        } else {
+            self.text.push(mark);
+        }
+    }
+
+    /// 모은 글을 목록 항목이나 인용문, 문단으로 만든다. 표의 칸 안이면 칸의 글에 남겨 두고
+    /// 다음 글과 띄어 적는다.
+    pub(crate) fn flush(&mut self) {
+        if self.row.is_some() {
+            if !self.text.trim().is_empty() && !self.text.ends_with(' ') {
+                self.text.push(' ');
+            }
+            return;
+        }
+        let text = self.take_text();
+        if let Some((depth, marker)) = self.item.take() {
+            self.push(Block::ListItem {
+                depth,
+                marker,
+                text,
+            });
+            return;
+        }
+        if text.is_empty() {
+            return;
+        }
+        if let (Some(Block::ListItem { text: item, .. }), false) =
+            (self.blocks.last_mut(), self.lists.is_empty())
+        {
+            // 목록 항목 안의 둘째 문단은 그 항목에 이어 적는다.
+            item.push(' ');
+            item.push_str(&text);
+        } else if self.quote_depth > 0 {
+            self.push(Block::Quote(text));
+        } else {
+            self.push(Block::Paragraph(text));
+        }
+    }
+
+    /// 문단을 시작한다. 앞에 블록으로 만들지 않은 글이 있으면 먼저 문단으로 만든다.
+    pub(crate) fn start_paragraph(&mut self) {
+        if !self.text.trim().is_empty() {
+            self.flush();
+        }
+    }
+
+    pub(crate) fn start_heading(&mut self) {
+        self.flush();
+        // 제목 앞에는 빈 줄을 둔다.
+        if self
+            .blocks
+            .last()
+            .is_some_and(|block| *block != Block::BlankLine)
+        {
+            self.push(Block::BlankLine);
+        }
+    }
+
+    pub(crate) fn end_heading(&mut self) {
+        let text = self.take_text();
+        self.push(Block::Heading(text));
+    }
+
+    pub(crate) fn start_quote(&mut self) {
+        self.flush();
+        self.quote_depth += 1;
+    }
+
+    pub(crate) fn end_quote(&mut self) {
+        self.flush();
+        self.quote_depth = self.quote_depth.saturating_sub(1);
+    }
+
+    pub(crate) fn start_code(&mut self) {
+        self.flush();
+        self.code = Some(String::new());
+    }
+
+    pub(crate) fn end_code(&mut self) {
+        if let Some(code) = self.code.take() {
+            let code = code.trim_start_matches('\n').trim_end();
+            self.push(Block::Code(code.to_string()));
+        }
+    }
+
+    /// 목록을 시작한다. `start`는 순서 있는 목록의 첫 번호이다.
+    pub(crate) fn start_list(&mut self, start: Option<u64>) {
+        // 줄 간격 없이 쓴 목록에서는 항목의 글이 문단 없이 바로 온다.
+        if self.item.is_some() || !self.text.trim().is_empty() {
+            self.flush();
+        }
+        self.lists.push(start);
+    }
+
+    pub(crate) fn end_list(&mut self) {
+        self.end_item();
+        self.lists.pop();
+    }
+
+    pub(crate) fn start_item(&mut self) {
+        self.end_item();
+        let depth = self.lists.len().saturating_sub(1);
+        let marker = self.lists.last_mut().and_then(|next| {
+            next.as_mut().map(|number| {
+                *number += 1;
+                format!("{}.", *number - 1)
+            })
+        });
+        self.item = Some((depth, marker));
+    }
+
+    pub(crate) fn end_item(&mut self) {
+        if self.item.is_some() {
+            self.flush();
+        }
+    }
+
+    pub(crate) fn start_row(&mut self) {
+        self.flush();
+        self.row = Some(Vec::new());
+    }
+
+    pub(crate) fn end_row(&mut self) {
+        if let Some(row) = self.row.take() {
+            self.push(Block::TableRow(row));
+        }
+    }
+
+    pub(crate) fn start_cell(&mut self) {
+        self.text.clear();
+    }
+
+    pub(crate) fn end_cell(&mut self) {
+        let text = self.take_text();
+        if let Some(row) = self.row.as_mut() {
+            row.push(text);
+        }
+    }
+
+    /// 묵자 쪽이 바뀐다. 블록 중간이면 그 블록을 다 적은 뒤에 바뀜을 표시한다.
+    #[cfg(feature = "html")]
+    pub(crate) fn page_break(&mut self, page: String) {
+        if self.item.is_none() && self.row.is_none() && self.text.trim().is_empty() {
+            self.push(Block::PageBreak(page));
+        } else {
+            self.page_breaks.push(page);
+        }
+    }
+
+    /// 주제 구분선은 빈 줄로 적는다.
+    pub(crate) fn rule(&mut self) {
+        self.flush();
+        self.push(Block::BlankLine);
+    }
+
+    pub(crate) fn finish(mut self) -> Vec<Block> {
+        self.flush();
+        self.blocks
+            .extend(self.page_breaks.drain(..).map(Block::PageBreak));
+        self.blocks
+    }
+}
+
+/// 일반 텍스트의 문단, 목록, 제목을 찾아 점자 편집 규칙에 맞게 줄마다 나누어 점역한다.
+pub fn encode_document(text: &str, options: &DocumentOptions) -> Result<Vec<Vec<u8>>, String> {
+    render_blocks(&parse_blocks(text), options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn to_unicode(lines: &[Vec<u8>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_paragraphs() {
+        assert_eq!(
+            parse_blocks("첫째 줄\n이어지는 줄\n  새 문단\n\n셋째 문단"),
+            vec![
+                Block::Paragraph("첫째 줄 이어지는 줄".to_string()),
+                Block::Paragraph("새 문단".to_string()),
+                Block::BlankLine,
+                Block::Paragraph("셋째 문단".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        assert_eq!(
+            parse_blocks("- 사과\n  1. 부사\n     맛있다.\n• 배"),
+            vec![
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "사과".to_string()
+                },
+                Block::ListItem {
+                    depth: 1,
+                    marker: Some("1.".to_string()),
+                    text: "부사 맛있다.".to_string()
+                },
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "배".to_string()
+                },
+            ]
+        );
+        // 번호 뒤에 글이 없거나 번호처럼 보이지 않으면 목록이 아니다.
+        assert_eq!(
+            parse_blocks("2024. 3. 1. 개교"),
+            vec![Block::Paragraph("2024. 3. 1. 개교".to_string())]
+        );
+        assert_eq!(parse_blocks("1."), vec![Block::Paragraph("1.".to_string())]);
+        assert_eq!(
+            parse_blocks("ab. 나는"),
+            vec![Block::Paragraph("ab. 나는".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_headings() {
+        assert_eq!(
+            parse_blocks("제1장 점자\n\n점자는 읽는 글자이다."),
+            vec![
+                Block::Heading("제1장 점자".to_string()),
+                Block::BlankLine,
+                Block::Paragraph("점자는 읽는 글자이다.".to_string()),
+            ]
+        );
+        // 문장 부호로 끝나거나 뒤에 글이 없으면 제목이 아니다.
+        assert_eq!(
+            parse_blocks("점자를 읽는다.\n\n다음 문단"),
+            vec![
+                Block::Paragraph("점자를 읽는다.".to_string()),
+                Block::BlankLine,
+                Block::Paragraph("다음 문단".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_quote_code_and_table() {
+        let options = DocumentOptions {
+            width: 10,
+            ..Default::default()
+        };
+        let blocks = [
+            Block::Quote("상 상 상 상".to_string()),
+            Block::Code("a=1\nb".to_string()),
+            Block::TableRow(vec!["상".to_string(), "상 상".to_string()]),
+        ];
+        assert_eq!(
+            to_unicode(&render_blocks(&blocks, &options).unwrap()),
+            vec!["⠀⠀⠇⠶⠀⠇⠶⠀⠇⠶", "⠀⠀⠇⠶", "⠸⠫⠁⠿⠂", "⠃⠸⠱", "⠇⠶⠀⠀⠇⠶⠀⠇⠶",]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let options = DocumentOptions {
+            width: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            to_unicode(&encode_document("상 상 상 상.\n\n- 상 상 상 상", &options).unwrap()),
+            vec!["⠀⠀⠇⠶⠀⠇⠶⠀⠇⠶", "⠇⠶⠲", "", "⠸⠲⠀⠇⠶⠀⠇⠶", "⠀⠀⠇⠶⠀⠇⠶",]
+        );
+        assert_eq!(
+            to_unicode(&encode_document("상\n\n상.", &options).unwrap()),
+            vec!["⠀⠀⠀⠀⠇⠶", "", "⠀⠀⠇⠶⠲"]
+        );
+        // 일반 텍스트에 섞인 사용자 정의 영역 문자는 강조 표시로 읽지 않는다.
+        assert!(encode_document("\u{E000}상\u{E001}", &options).is_err());
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::HashMap;
+
+use roxmltree::Node;
+
+use crate::{
+    archive::{Archive, parse_xml},
+    book::{BookOptions, render_book},
+    document::Block,
+    emphasis::{BOLD_CLOSE, BOLD_OPEN, EMPHASIS_CLOSE, EMPHASIS_OPEN, replace_marks},
+};
+
+/// WordprocessingML 이름공간
+const W: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+/// 목록 단계의 번호 형식
+struct Level {
+    /// `bullet`, `decimal`, `ganada`처럼 쓴 번호 형식
+    format: String,
+    /// `%1.`, `(%2)`처럼 쓴 번호 모양
+    text: String,
+    start: u64,
+}
+
+fn child<'a, 'input>(node: Node<'a, 'input>, name: &str) -> Option<Node<'a, 'input>> {
+    node.children().find(|child| child.has_tag_name((W, name)))
+}
+
+fn value<'a>(node: Node<'a, '_>) -> Option<&'a str> {
+    node.attribute((W, "val"))
+}
+
+/// `<w:b/>`, `<w:b w:val="0"/>`처럼 쓴 켜고 끄는 속성
+fn is_on(properties: Option<Node>, name: &str) -> bool {
+    properties
+        .and_then(|properties| child(properties, name))
+        .is_some_and(|node| !matches!(value(node), Some("0" | "false" | "off" | "none")))
+}
+
+/// 번호를 형식에 맞게 적는다.
+fn format_number(format: &str, number: u64) -> String {
+    const GANADA: [char; 14] = [
+        '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+    ];
+    const CHOSUNG: [char; 14] = [
+        'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+    ];
+    let nth = |chars: &[char]| {
+        let idx = (number.max(1) - 1) as usize % chars.len();
+        chars[idx].to_string()
+    };
+    let letter = |base: u8| char::from(base + ((number.max(1) - 1) % 26) as u8).to_string();
+    match format {
+        "ganada" => nth(&GANADA),
+        "chosung" => nth(&CHOSUNG),
+        "lowerLetter" => letter(b'a'),
+        "upperLetter" => letter(b'A'),
+        "decimalEnclosedCircle" if (1..=20).contains(&number) => {
+            char::from_u32('①' as u32 + number as u32 - 1)
+                .unwrap_or('①')
+                .to_string()
+        }
+        _ => number.to_string(),
+    }
+}
+
+/// DOCX 문서를 읽는 중의 상태
+#[derive(Default)]
+struct Reader {
+    /// 스타일 ID와 이름
+    styles: HashMap<String, String>,
+    /// 목록 번호 ID마다 단계별 번호 형식
+    numbering: HashMap<String, Vec<Level>>,
+    /// 목록 번호 ID마다 단계별 지금 번호
+    counters: HashMap<String, Vec<u64>>,
+    blocks: Vec<Block>,
+    /// 지금까지 만난 묵자 쪽 수
+    page: usize,
+}
+
+impl Reader {
+    fn read_styles(&mut self, styles: &str) -> Result<(), String> {
+        let styles = parse_xml(styles)?;
+        for style in styles
+            .descendants()
+            .filter(|node| node.has_tag_name((W, "style")))
+        {
+            if let (Some(id), Some(name)) = (
+                style.attribute((W, "styleId")),
+                child(style, "name").and_then(value),
+            ) {
+                self.styles.insert(id.to_string(), name.to_string());
+            }
+        }
+        Ok(())
+    }
+
+    fn read_numbering(&mut self, numbering: &str) -> Result<(), String> {
+        let numbering = parse_xml(numbering)?;
+        let mut abstract_levels: HashMap<&str, Vec<Level>> = HashMap::new();
+        for node in numbering
+            .descendants()
+            .filter(|node| node.has_tag_name((W, "abstractNum")))
+        {
+            let Some(id) = node.attribute((W, "abstractNumId")) else {
+                continue;
+            };
+            let levels = node
+                .children()
+                .filter(|level| level.has_tag_name((W, "lvl")))
+                .map(|level| Level {
+                    format: child(level, "numFmt")
+                        .and_then(value)
+                        .unwrap_or("decimal")
+                        .to_string(),
+                    text: child(level, "lvlText")
+                        .and_then(value)
+                        .unwrap_or_default()
+                        .to_string(),
+                    start: child(level, "start")
+                        .and_then(value)
+                        .and_then(|start| start.parse().ok())
+                        .unwrap_or(1),
+                })
+                .collect();
+            abstract_levels.insert(id, levels);
+        }
+        for node in numbering
+            .descendants()
+            .filter(|node| node.has_tag_name((W, "num")))
+        {
+            if let (Some(id), Some(levels)) = (
+                node.attribute((W, "numId")),
+                child(node, "abstractNumId")
+                    .and_then(value)
+                    .and_then(|id| abstract_levels.remove(id)),
+            ) {
+                self.numbering.insert(id.to_string(), levels);
+            }
+        }
+        Ok(())
+    }
+
+    fn page_break(&mut self) {
+        self.page += 1;
+        self.blocks.push(Block::PageBreak(self.page.to_string()));
+    }
+
+    /// 문단 스타일이 제목인지 확인한다.
+    fn is_heading(&self, properties: Option<Node>) -> bool {
+        let Some(style) = properties.and_then(|p| child(p, "pStyle")).and_then(value) else {
+            return false;
+        };
+        let name = self
+            .styles
+            .get(style)
+            .map_or(style, String::as_str)
+            .to_lowercase();
+        name.starts_with("heading") || name == "title"
+    }
+
+    /// 목록 항목의 단계와 번호. 기호 목록이면 번호는 `None`이다.
+    fn list_marker(&mut self, properties: Option<Node>) -> Option<(usize, Option<String>)> {
+        let numbering = properties.and_then(|p| child(p, "numPr"))?;
+        let id = child(numbering, "numId").and_then(value)?;
+        if id == "0" {
+            return None;
+        }
+        let depth: usize = child(numbering, "ilvl")
+            .and_then(value)
+            .and_then(|level| level.parse().ok())
+            .unwrap_or(0);
+        let Some(levels) = self.numbering.get(id) else {
+            return Some((depth, None));
+        };
+        let level = levels.get(depth)?;
+        if level.format == "bullet" || level.format == "none" {
+            return Some((depth, None));
+        }
+        let counters = self.counters.entry(id.to_string()).or_default();
+        counters.truncate(depth + 1);
+        while counters.len() <= depth {
+            let start = levels.get(counters.len()).map_or(1, |level| level.start);
+            counters.push(start.saturating_sub(1));
+        }
+        counters[depth] += 1;
+        let mut marker = if level.text.is_empty() {
+            format!("%{}.", depth + 1)
+        } else {
+            level.text.clone()
+        };
+        for (idx, number) in counters.iter().enumerate() {
+            let format = levels.get(idx).map_or("decimal", |level| &level.format);
+            marker = marker.replace(&format!("%{}", idx + 1), &format_number(format, *number));
+        }
+        Some((depth, Some(marker)))
+    }
+
+    /// 문단의 글. 굵은 글씨와 기울임, 밑줄은 강조 구간 표시 문자로 남기고, 글 중간의
+    /// 쪽 나눔은 문단 뒤에 둘 수 있도록 그 수를 함께 돌려준다.
+    fn paragraph_text(&mut self, paragraph: Node) -> (String, usize) {
+        let mut text = String::new();
+        let mut page_breaks = 0;
+        // 지금 열린 굵은 글씨와 기울임
+        let mut open = (false, false);
+        let close = |text: &mut String, open: &mut (bool, bool)| {
+            // 닫는 표시 앞의 띄어쓰기는 표시 뒤로 옮긴다.
+            let space = text.ends_with(' ');
+            if space {
+                text.pop();
+            }
+            if open.1 {
+                text.push(EMPHASIS_CLOSE);
+            }
+            if open.0 {
+                text.push(BOLD_CLOSE);
+            }
+            if space {
+                text.push(' ');
+            }
+            *open = (false, false);
+        };
+        for run in paragraph
+            .descendants()
+            .filter(|node| node.has_tag_name((W, "r")))
+        {
+            let properties = child(run, "rPr");
+            let style = (
+                is_on(properties, "b"),
+                is_on(properties, "i") || is_on(properties, "u"),
+            );
+            for node in run.children() {
+                let content = match node.tag_name().name() {
+                    "t" if node.tag_name().namespace() == Some(W) => {
+                        replace_marks(node.text().unwrap_or_default())
+                    }
+                    "tab" => " ".to_string(),
+                    "noBreakHyphen" => "-".to_string(),
+                    "br" if node.attribute((W, "type")) == Some("page") => {
+                        page_breaks += 1;
+                        continue;
+                    }
+                    "br" | "cr" => "\n".to_string(),
+                    _ => continue,
+                };
+                if !content.trim().is_empty() && style != open {
+                    close(&mut text, &mut open);
+                    if style.0 {
+                        text.push(BOLD_OPEN);
+                    }
+                    if style.1 {
+                        text.push(EMPHASIS_OPEN);
+                    }
+                    open = style;
+                }
+                text.push_str(&content);
+            }
+        }
+        close(&mut text, &mut open);
+        (text.trim().to_string(), page_breaks)
+    }
+
+    fn read_paragraph(&mut self, paragraph: Node) {
+        let properties = child(paragraph, "pPr");
+        if is_on(properties, "pageBreakBefore") {
+            self.page_break();
+        }
+        let (text, page_breaks) = self.paragraph_text(paragraph);
+        if text.is_empty() {
+            (0..page_breaks).for_each(|_| self.page_break());
+            return;
+        }
+        let block = if self.is_heading(properties) {
+            Block::Heading(text)
+        } else if let Some((depth, marker)) = self.list_marker(properties) {
+            Block::ListItem {
+                depth,
+                marker,
+                text,
+            }
+        } else {
+            Block::Paragraph(text)
+        };
+        self.blocks.push(block);
+        (0..page_breaks).for_each(|_| self.page_break());
+    }
+
+    fn read_table(&mut self, table: Node) {
+        let mut page_breaks = 0;
+        for row in table.children().filter(|node| node.has_tag_name((W, "tr"))) {
+            let cells = row
+                .children()
+                .filter(|node| node.has_tag_name((W, "tc")))
+                .map(|cell| {
+                    cell.children()
+                        .filter(|node| node.has_tag_name((W, "p")))
+                        .map(|paragraph| {
+                            let (text, breaks) = self.paragraph_text(paragraph);
+                            page_breaks += breaks;
+                            text
+                        })
+                        .filter(|text| !text.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect();
+            self.blocks.push(Block::TableRow(cells));
+        }
+        (0..page_breaks).for_each(|_| self.page_break());
+    }
+
+    fn read_body(&mut self, body: Node) {
+        for node in body.children() {
+            match node.tag_name().name() {
+                "p" => self.read_paragraph(node),
+                "tbl" => self.read_table(node),
+                // 내용 컨트롤 안의 문단
+                "sdt" => {
+                    if let Some(content) = child(node, "sdtContent") {
+                        self.read_body(content);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// DOCX를 문서 블록으로 바꾼다.
+///
+/// 제목 스타일 문단은 제목으로, 번호 매기기를 쓴 문단은 목록 항목으로, 표는 행마다 바꾼다.
+/// 굵은 글씨는 제56항 굵은 글자 표로, 기울임과 밑줄은 드러냄표로 강조한다. 묵자 쪽은
+/// 문서 첫머리를 1쪽으로 하고 쪽 나누기(`<w:br w:type="page"/>`, 앞에서 쪽 나누기)마다 센다.
+pub fn parse_docx(bytes: &[u8]) -> Result<Vec<Block>, String> {
+    let mut archive = Archive::new(bytes)?;
+    let mut reader = Reader::default();
+    if let Some(styles) = archive.read("word/styles.xml")? {
+        reader.read_styles(&styles)?;
+    }
+    if let Some(numbering) = archive.read("word/numbering.xml")? {
+        reader.read_numbering(&numbering)?;
+    }
+    let document = archive.read_required("word/document.xml")?;
+    let document = parse_xml(&document)?;
+    let body = document
+        .descendants()
+        .find(|node| node.has_tag_name((W, "body")))
+        .ok_or("Missing body in document.xml")?;
+    reader.page_break();
+    reader.read_body(body);
+    Ok(reader.blocks)
+}
+
+/// DOCX를 점역해 묵자 쪽 번호를 적은 점자책의 면마다 나눈다.
+pub fn encode_docx(bytes: &[u8], options: &BookOptions) -> Result<Vec<Vec<Vec<u8>>>, String> {
+    render_book(&parse_docx(bytes)?, options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::test::zip;
+
+    fn document(body: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="{W}"><w:body>{body}<w:sectPr/></w:body></w:document>"#
+        )
+    }
+
+    const STYLES: &str = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:style w:type="paragraph" w:styleId="1"><w:name w:val="heading 1"/></w:style>
+<w:style w:type="paragraph" w:styleId="a"><w:name w:val="Normal"/></w:style>
+</w:styles>"#;
+
+    const NUMBERING: &str = r#"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:abstractNum w:abstractNumId="0">
+  <w:lvl w:ilvl="0"><w:start w:val="1"/><w:numFmt w:val="decimal"/><w:lvlText w:val="%1."/></w:lvl>
+  <w:lvl w:ilvl="1"><w:start w:val="1"/><w:numFmt w:val="ganada"/><w:lvlText w:val="%2)"/></w:lvl>
+</w:abstractNum>
+<w:abstractNum w:abstractNumId="1"><w:lvl w:ilvl="0"><w:numFmt w:val="bullet"/><w:lvlText w:val="•"/></w:lvl></w:abstractNum>
+<w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+<w:num w:numId="2"><w:abstractNumId w:val="1"/></w:num>
+</w:numbering>"#;
+
+    fn item(depth: usize, marker: Option<&str>, text: &str) -> Block {
+        Block::ListItem {
+            depth,
+            marker: marker.map(str::to_string),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number("decimal", 12), "12");
+        assert_eq!(format_number("ganada", 3), "다");
+        assert_eq!(format_number("chosung", 15), "ㄱ");
+        assert_eq!(format_number("lowerLetter", 2), "b");
+        assert_eq!(format_number("decimalEnclosedCircle", 3), "③");
+    }
+
+    #[test]
+    fn test_parse_docx() {
+        let body = r#"
+<w:p><w:pPr><w:pStyle w:val="1"/></w:pPr><w:r><w:t>점자</w:t></w:r></w:p>
+<w:p><w:r><w:t xml:space="preserve">서울은 </w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">대한민국 </w:t></w:r>
+  <w:r><w:t>의 </w:t></w:r><w:r><w:rPr><w:i/><w:b w:val="0"/></w:rPr><w:t>수도</w:t></w:r><w:r><w:t>이다.</w:t><w:br w:type="page"/></w:r></w:p>
+<w:p/>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>하나</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="1"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>가</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="1"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>나</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>둘</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="2"/></w:numPr></w:pPr><w:r><w:t>기호</w:t></w:r></w:p>
+<w:p><w:pPr><w:pageBreakBefore/></w:pPr><w:r><w:t>줄</w:t><w:br/><w:t>바꿈</w:t></w:r></w:p>
+<w:tbl><w:tr><w:tc><w:p><w:r><w:t>이름</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>값</w:t></w:r></w:p></w:tc></w:tr></w:tbl>"#;
+        let bytes = zip(&[
+            ("word/document.xml", &document(body)),
+            ("word/styles.xml", STYLES),
+            ("word/numbering.xml", NUMBERING),
+        ]);
+        assert_eq!(
+            parse_docx(&bytes).unwrap(),
+            vec![
+                Block::PageBreak("1".to_string()),
+                Block::Heading("점자".to_string()),
+                Block::Paragraph(
+                    "서울은 \u{E002}대한민국\u{E003} 의 \u{E000}수도\u{E001}이다.".to_string()
+                ),
+                Block::PageBreak("2".to_string()),
+                item(0, Some("1."), "하나"),
+                item(1, Some("가)"), "가"),
+                item(1, Some("나)"), "나"),
+                item(0, Some("2."), "둘"),
+                item(0, None, "기호"),
+                Block::PageBreak("3".to_string()),
+                Block::Paragraph("줄\n바꿈".to_string()),
+                Block::TableRow(vec!["이름".to_string(), "값".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_docx_errors() {
+        assert!(parse_docx(&zip(&[("word/styles.xml", STYLES)])).is_err());
+        assert!(parse_docx(&zip(&[("word/document.xml", "<w:document")])).is_err());
+        assert!(parse_docx(b"PK").is_err());
+    }
+
+    #[test]
+    fn test_encode_docx() {
+        let bytes = zip(&[(
+            "word/document.xml",
+            &document("<w:p><w:r><w:t>상</w:t></w:r></w:p>"),
+        )]);
+        let pages = encode_docx(&bytes, &BookOptions::default()).unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0][1], vec![0, 0, 7, 54]);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::unicode::encode_ascii;
+
+const ESC: u8 = 0x1B;
+/// 줄 끝에 적는 문자. 점자 프린터는 대부분 CR과 LF를 함께 받는다.
+const LINE_END: &[u8] = b"\r\n";
+/// 면 끝에 적는 쪽 넘김 문자
+const PAGE_END: u8 = 0x0C;
+
+/// 점자 프린터의 명령 체계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum EmbosserProfile {
+    /// 설정 명령 없이 점자 ASCII만 보낸다. 프린터에서 직접 설정한 대로 찍는다.
+    #[default]
+    Generic,
+    /// Index Braille 프린터의 `ESC D` 설정 명령
+    Index,
+    /// Enabling Technologies 프린터의 `ESC` 설정 명령
+    EnablingTechnologies,
+}
+
+/// 점자 프린터 설정
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbosserOptions {
+    /// 명령 체계
+    pub profile: EmbosserProfile,
+    /// 한 줄의 칸 수
+    pub cells_per_line: usize,
+    /// 한 면의 줄 수
+    pub lines_per_page: usize,
+    /// 종이의 앞뒤에 찍는 양면 점자
+    pub interpoint: bool,
+}
+
+impl Default for EmbosserOptions {
+    fn default() -> Self {
+        Self {
+            profile: EmbosserProfile::default(),
+            cells_per_line: 32,
+            lines_per_page: 25,
+            interpoint: false,
+        }
+    }
+}
+
+/// 찍기 전에 보내는 설정 명령
+fn setup(options: &EmbosserOptions) -> Vec<u8> {
+    match options.profile {
+        EmbosserProfile::Generic => Vec::new(),
+        // ESC D 뒤에 `이름값`을 쉼표로 이어 적고 `;`로 끝낸다. BT0은 북미 점자 ASCII 표,
+        // DP1은 단면, DP2는 양면이고 PN0은 프린터의 쪽 번호를 끈다.
+        EmbosserProfile::Index => {
+            let mut command = vec![ESC, b'D'];
+            command.extend_from_slice(
+                format!(
+                    "BT0,CH{},LP{},DP{},PN0;",
+                    options.cells_per_line,
+                    options.lines_per_page,
+                    if options.interpoint { 2 } else { 1 }
+                )
+                .as_bytes(),
+            );
+            command
+        }
+        // 명령마다 ESC와 명령 문자 뒤에 값을 `@`(64)에 더한 문자 하나로 적는다.
+        // A는 면의 줄 수, B는 한 줄의 칸 수, i는 양면 여부이다.
+        EmbosserProfile::EnablingTechnologies => [
+            (b'A', options.lines_per_page),
+            (b'B', options.cells_per_line),
+            (b'i', usize::from(options.interpoint)),
+        ]
+        .into_iter()
+        .flat_map(|(command, value)| [ESC, command, b'@' + value as u8])
+        .collect(),
+    }
+}
+
+/// 면마다 나눈 점역 결과를 점자 프린터 명령과 함께 보낼 바이트로 만든다.
+///
+/// 맨 앞에 명령 체계에 맞는 설정 명령을 적고, 줄마다 점자 ASCII 뒤에 CR LF를, 면마다 쪽 넘김
+/// 문자를 적는다. 줄이나 면이 설정보다 길거나 8점 점자가 있으면 오류를 낸다.
+pub fn emboss(pages: &[Vec<Vec<u8>>], options: &EmbosserOptions) -> Result<Vec<u8>, String> {
+    if options.cells_per_line == 0 || options.lines_per_page == 0 {
+        return Err("Cells per line and lines per page must be positive".to_string());
+    }
+    if options.profile == EmbosserProfile::EnablingTechnologies
+        && options.cells_per_line.max(options.lines_per_page) > usize::from(u8::MAX - b'@')
+    {
+        return Err("Page size is too large for the embosser".to_string());
+    }
+    let mut bytes = setup(options);
+    for (idx, page) in pages.iter().enumerate() {
+        if page.len() > options.lines_per_page {
+            return Err(format!(
+                "Page {} has {} lines, more than {}",
+                idx + 1,
+                page.len(),
+                options.lines_per_page
+            ));
+        }
+        for line in page {
+            if line.len() > options.cells_per_line {
+                return Err(format!(
+                    "Page {} has a line of {} cells, more than {}",
+                    idx + 1,
+                    line.len(),
+                    options.cells_per_line
+                ));
+            }
+            for cell in line {
+                bytes.push(encode_ascii(*cell)? as u8);
+            }
+            bytes.extend_from_slice(LINE_END);
+        }
+        bytes.push(PAGE_END);
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        BookOptions, DocumentOptions, EncodeOptions, parse_blocks, render_book,
+        unicode::decode_unicode,
+    };
+
+    const GOLDEN_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../test_cases/embosser");
+
+    /// 두 면으로 나뉘는 짧은 점자책
+    fn pages(options: &EmbosserOptions) -> Vec<Vec<Vec<u8>>> {
+        let book = BookOptions {
+            lines: options.lines_per_page,
+            document: DocumentOptions {
+                width: options.cells_per_line,
+                encode: EncodeOptions::default(),
+                ..Default::default()
+            },
+        };
+        let blocks = parse_blocks("점자\n\n상 상 상 상 상.\n\n2 3 4");
+        render_book(&blocks, &book).unwrap()
+    }
+
+    #[test]
+    fn test_emboss_by_golden_file() {
+        // 결과를 바꾼 뒤에는 BRAILLIFY_UPDATE_GOLDEN=1로 실행해 파일을 새로 적는다.
+        let update = std::env::var_os("BRAILLIFY_UPDATE_GOLDEN").is_some();
+        for (name, profile, interpoint) in [
+            ("generic.brf", EmbosserProfile::Generic, false),
+            ("index.bin", EmbosserProfile::Index, false),
+            ("index_interpoint.bin", EmbosserProfile::Index, true),
+            (
+                "enabling_technologies.bin",
+                EmbosserProfile::EnablingTechnologies,
+                true,
+            ),
+        ] {
+            let options = EmbosserOptions {
+                profile,
+                cells_per_line: 12,
+                lines_per_page: 5,
+                interpoint,
+            };
+            let bytes = emboss(&pages(&options), &options).unwrap();
+            let path = format!("{GOLDEN_DIR}/{name}");
+            if update {
+                std::fs::create_dir_all(GOLDEN_DIR).unwrap();
+                std::fs::write(&path, &bytes).unwrap();
+            }
+            assert_eq!(bytes, std::fs::read(&path).unwrap(), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_setup() {
+        let options = EmbosserOptions {
+            profile: EmbosserProfile::Index,
+            interpoint: true,
+            ..Default::default()
+        };
+        assert_eq!(setup(&options), b"\x1bDBT0,CH32,LP25,DP2,PN0;");
+        let options = EmbosserOptions {
+            profile: EmbosserProfile::EnablingTechnologies,
+            ..Default::default()
+        };
+        assert_eq!(setup(&options), b"\x1bAY\x1bB`\x1bi@");
+        assert!(setup(&EmbosserOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_emboss_errors() {
+        let options = EmbosserOptions {
+            cells_per_line: 2,
+            lines_per_page: 1,
+            ..Default::default()
+        };
+        let cell = decode_unicode('⠁');
+        assert_eq!(
+            emboss(&[vec![vec![cell, cell]]], &options),
+            Ok(b"AA\r\n\x0C".to_vec())
+        );
+        assert!(emboss(&[vec![vec![cell; 3]]], &options).is_err());
+        assert!(emboss(&[vec![vec![cell], vec![cell]]], &options).is_err());
+        assert!(emboss(&[vec![vec![0x40]]], &options).is_err());
+        let options = EmbosserOptions {
+            profile: EmbosserProfile::EnablingTechnologies,
+            cells_per_line: 200,
+            ..Default::default()
+        };
+        assert!(emboss(&[], &options).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::unicode::decode_unicode;
+
+/// 제56항 드러냄표나 밑줄로 강조한 구간의 시작과 끝을 블록의 글 안에 표시하는 문자.
+/// 마크다운처럼 글자 모양을 따로 가진 입력을 읽을 때만 넣는 사용자 정의 영역 문자로,
+/// 공개된 점역 함수는 이 문자를 받지 않고 문서의 블록을 점역할 때만 강조 표시로 읽는다.
+pub const EMPHASIS_OPEN: char = '\u{E000}';
+pub const EMPHASIS_CLOSE: char = '\u{E001}';
+/// 제56항 굵은 글자로 강조한 구간의 시작과 끝을 입력 안에 표시하는 문자
+pub const BOLD_OPEN: char = '\u{E002}';
+pub const BOLD_CLOSE: char = '\u{E003}';
+
+/// 제56항 드러냄표나 밑줄로 강조된 글자체는 ⠠⠤ … ⠤⠄으로 묶어 나타낸다.
+const EMPHASIS_START: [u8; 2] = [decode_unicode('⠠'), decode_unicode('⠤')];
+const EMPHASIS_END: [u8; 2] = [decode_unicode('⠤'), decode_unicode('⠄')];
+/// 제56항 굵은 글자로 강조된 글자체는 ⠰⠤ … ⠤⠆으로 묶어 나타낸다.
+const BOLD_START: [u8; 2] = [decode_unicode('⠰'), decode_unicode('⠤')];
+const BOLD_END: [u8; 2] = [decode_unicode('⠤'), decode_unicode('⠆')];
+
+/// 강조 구간을 표시하는 문자인지 확인한다.
+pub fn is_emphasis_mark(c: char) -> bool {
+    matches!(c, EMPHASIS_OPEN..=BOLD_CLOSE)
+}
+
+/// 강조 구간 표시 문자의 점형
+pub fn encode_emphasis_mark(c: char) -> Result<&'static [u8], String> {
+    match c {
+        EMPHASIS_OPEN => Ok(&EMPHASIS_START),
+        EMPHASIS_CLOSE => Ok(&EMPHASIS_END),
+        BOLD_OPEN => Ok(&BOLD_START),
+        BOLD_CLOSE => Ok(&BOLD_END),
+        _ => Err("Invalid emphasis mark".to_string()),
+    }
+}
+
+/// 입력의 글에 섞인 강조 구간 표시 문자를 U+FFFD로 바꾼다. 입력 그대로의 사용자 정의 영역
+/// 문자가 강조 표시로 읽히지 않고 점역할 수 없는 문자로 남는다.
+pub fn replace_marks(text: &str) -> String {
+    text.replace(is_emphasis_mark, "\u{FFFD}")
+}
+
+/// 단어 첫머리에 이어진 강조 구간 표시 문자의 길이(바이트)
+pub fn leading_marks_len(word: &str) -> usize {
+    word.find(|c| !is_emphasis_mark(c)).unwrap_or(word.len())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_emphasis_marks() {
+        assert!(is_emphasis_mark(EMPHASIS_OPEN));
+        assert!(is_emphasis_mark(BOLD_CLOSE));
+        assert!(!is_emphasis_mark('가'));
+        assert_eq!(encode_emphasis_mark(BOLD_OPEN).unwrap(), &BOLD_START);
+        assert!(encode_emphasis_mark('a').is_err());
+        assert_eq!(leading_marks_len("\u{E002}\u{E000}수도"), 6);
+        assert_eq!(leading_marks_len("수도\u{E003}"), 0);
+        assert_eq!(leading_marks_len("\u{E003}"), 3);
+        assert_eq!(
+            replace_marks("\u{E000}수도\u{E001}"),
+            "\u{FFFD}수도\u{FFFD}"
+        );
+    }
+}
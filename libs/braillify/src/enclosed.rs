@@ -0,0 +1,72 @@
+use unicode_normalization::UnicodeNormalization;
+
+/// 원문자와 괄호 문자에 쓰이는 자음자 순서
+const CONSONANTS: [char; 14] = [
+    'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 번호로 쓰인 원문자(①, ㉠, ㉮, ⓐ), 괄호 문자(⑴, ㈀, ㈎, ⒜), 마침표 숫자(⒈)와
+/// 로마 숫자(Ⅰ, ⅰ)를 점역할 수 있는 글자로 풀어 쓴다.
+///
+/// 원문자는 안의 글자만, 괄호 문자는 괄호와 함께 적는다. 자음자는 제9항에 따라 온표를 앞세워
+/// 받침으로 적히도록 호환 자모로 풀고, 로마 숫자는 로마자 대문자(소문자)로 푼다.
+fn decompose_enclosed(c: char) -> Option<String> {
+    match c {
+        '\u{3260}'..='\u{326D}' => Some(CONSONANTS[c as usize - 0x3260].to_string()),
+        '\u{3200}'..='\u{320D}' => Some(format!("({})", CONSONANTS[c as usize - 0x3200])),
+        '\u{2460}'..='\u{24FF}'
+        | '\u{2776}'..='\u{2793}'
+        | '\u{3251}'..='\u{325F}'
+        | '\u{32B1}'..='\u{32BF}'
+        | '\u{320E}'..='\u{321B}'
+        | '\u{326E}'..='\u{327B}'
+        | '\u{2160}'..='\u{217F}' => {
+            let decomposed = c.nfkc().collect::<String>();
+            decomposed
+                .chars()
+                .all(|ch| {
+                    ch.is_ascii_alphanumeric()
+                        || matches!(ch, '(' | ')' | '.')
+                        || ('가'..='힣').contains(&ch)
+                })
+                .then_some(decomposed)
+        }
+        _ => None,
+    }
+}
+
+/// 단어 안의 원문자, 괄호 문자, 로마 숫자를 풀어 쓴다.
+pub fn decompose_enclosed_chars(word: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+    for c in word.chars() {
+        match decompose_enclosed(c) {
+            Some(decomposed) => result.push_str(&decomposed),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_decompose_enclosed_chars() {
+        assert_eq!(decompose_enclosed_chars("①"), "1");
+        assert_eq!(decompose_enclosed_chars("⑳"), "20");
+        assert_eq!(decompose_enclosed_chars("㉑"), "21");
+        assert_eq!(decompose_enclosed_chars("⑴"), "(1)");
+        assert_eq!(decompose_enclosed_chars("⒈"), "1.");
+        assert_eq!(decompose_enclosed_chars("ⓐ"), "a");
+        assert_eq!(decompose_enclosed_chars("⒜"), "(a)");
+        assert_eq!(decompose_enclosed_chars("㉠"), "ㄱ");
+        assert_eq!(decompose_enclosed_chars("㉭"), "ㅎ");
+        assert_eq!(decompose_enclosed_chars("㈀"), "(ㄱ)");
+        assert_eq!(decompose_enclosed_chars("㉮"), "가");
+        assert_eq!(decompose_enclosed_chars("㈎"), "(가)");
+        assert_eq!(decompose_enclosed_chars("Ⅳ장"), "IV장");
+        assert_eq!(decompose_enclosed_chars("ⅻ"), "xii");
+        assert_eq!(decompose_enclosed_chars("가나"), "가나");
+    }
+}
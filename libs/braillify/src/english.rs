@@ -1,6 +1,7 @@
 use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
 
-use crate::unicode::decode_unicode;
+use crate::unicode::{DOT_7, decode_unicode};
 
 pub static ENGLISH_MAP: phf::Map<char, u8> = phf_map! {
     'a' => decode_unicode('⠁'),
@@ -37,3 +38,142 @@ pub fn encode_english(text: char) -> Result<u8, String> {
     }
     Err("Invalid English character".to_string())
 }
+
+/// ｢통일영어점자 규정｣ 4.2 악센트 기호. 악센트 기호는 꾸미는 글자 앞에 적는다.
+static MODIFIER_MAP: phf::Map<char, [u8; 2]> = phf_map! {
+    // acute (é)
+    '\u{0301}' => [decode_unicode('⠘'), decode_unicode('⠌')],
+    // grave (è)
+    '\u{0300}' => [decode_unicode('⠘'), decode_unicode('⠡')],
+    // circumflex (ê)
+    '\u{0302}' => [decode_unicode('⠘'), decode_unicode('⠩')],
+    // tilde (ñ)
+    '\u{0303}' => [decode_unicode('⠘'), decode_unicode('⠻')],
+    // diaeresis (ü)
+    '\u{0308}' => [decode_unicode('⠘'), decode_unicode('⠒')],
+    // cedilla (ç)
+    '\u{0327}' => [decode_unicode('⠘'), decode_unicode('⠯')],
+    // ring (å)
+    '\u{030A}' => [decode_unicode('⠘'), decode_unicode('⠫')],
+    // caron (č)
+    '\u{030C}' => [decode_unicode('⠘'), decode_unicode('⠬')],
+    // breve (ğ)
+    '\u{0306}' => [decode_unicode('⠈'), decode_unicode('⠬')],
+    // macron (ā)
+    '\u{0304}' => [decode_unicode('⠈'), decode_unicode('⠤')],
+};
+
+/// 사선 기호 (ø, ł)
+const STROKE: [u8; 2] = [decode_unicode('⠈'), decode_unicode('⠒')];
+/// 합자 기호 (æ, œ). 합자를 이루는 두 글자 사이에 적는다.
+const LIGATURE: [u8; 2] = [decode_unicode('⠘'), decode_unicode('⠖')];
+
+/// 악센트가 붙은 로마자(Latin-1, Latin Extended-A)를 악센트 기호와 기본 글자로 나눈다.
+fn split_accented(c: char) -> Option<(Vec<u8>, Vec<char>)> {
+    if !('\u{00C0}'..='\u{017F}').contains(&c) {
+        return None;
+    }
+    match c.to_lowercase().next()? {
+        'ø' => return Some((STROKE.to_vec(), vec!['o'])),
+        'đ' => return Some((STROKE.to_vec(), vec!['d'])),
+        'ħ' => return Some((STROKE.to_vec(), vec!['h'])),
+        'ł' => return Some((STROKE.to_vec(), vec!['l'])),
+        'æ' => return Some((Vec::new(), vec!['a', 'e'])),
+        'œ' => return Some((Vec::new(), vec!['o', 'e'])),
+        _ => {}
+    }
+    let mut decomposed = c.nfd();
+    let base = decomposed
+        .next()
+        .filter(|base| base.is_ascii_alphabetic())?;
+    let mut modifiers = Vec::new();
+    for mark in decomposed {
+        modifiers.extend(MODIFIER_MAP.get(&mark)?);
+    }
+    if modifiers.is_empty() {
+        return None;
+    }
+    Some((modifiers, vec![base]))
+}
+
+/// 통일영어점자로 적을 수 있는 악센트 붙은 로마자인지 확인한다.
+pub fn is_accented_letter(c: char) -> bool {
+    split_accented(c).is_some()
+}
+
+/// 로마자 한 글자를 적는다. 악센트가 붙은 글자는 악센트 기호를 앞세워 적는다.
+pub fn encode_latin_letter(c: char) -> Result<Vec<u8>, String> {
+    if c.is_ascii_alphabetic() {
+        return Ok(vec![encode_english(c)?]);
+    }
+    let (mut result, base) = split_accented(c).ok_or("Invalid English character")?;
+    for (idx, letter) in base.iter().enumerate() {
+        if idx > 0 {
+            result.extend(LIGATURE);
+        }
+        result.push(encode_english(*letter)?);
+    }
+    Ok(result)
+}
+
+/// 8점 점자로 로마자 한 글자를 적는다. 대문자는 7점을 더해 나타낸다.
+pub fn encode_latin_letter_eight_dot(c: char) -> Result<Vec<u8>, String> {
+    let mut result = encode_latin_letter(c)?;
+    if c.is_uppercase()
+        && let Some(letter) = result.last_mut()
+    {
+        *letter |= DOT_7;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(text: &str) -> String {
+        text.chars()
+            .flat_map(|c| encode_latin_letter(c).unwrap())
+            .map(encode_unicode)
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_latin_letter() {
+        assert_eq!(encode_str("e"), "⠑");
+        assert_eq!(encode_str("é"), "⠘⠌⠑");
+        assert_eq!(encode_str("É"), "⠘⠌⠑");
+        assert_eq!(encode_str("ü"), "⠘⠒⠥");
+        assert_eq!(encode_str("ñ"), "⠘⠻⠝");
+        assert_eq!(encode_str("ç"), "⠘⠯⠉");
+        assert_eq!(encode_str("š"), "⠘⠬⠎");
+        assert_eq!(encode_str("ø"), "⠈⠒⠕");
+        assert_eq!(encode_str("æ"), "⠁⠘⠖⠑");
+        assert!(encode_latin_letter('ß').is_err());
+        assert!(encode_latin_letter('가').is_err());
+    }
+
+    #[test]
+    fn test_encode_latin_letter_eight_dot() {
+        let encode = |c| {
+            encode_latin_letter_eight_dot(c)
+                .unwrap()
+                .into_iter()
+                .map(encode_unicode)
+                .collect::<String>()
+        };
+        assert_eq!(encode('a'), "⠁");
+        assert_eq!(encode('A'), "⡁");
+        assert_eq!(encode('É'), "⠘⠌⡑");
+    }
+
+    #[test]
+    fn test_is_accented_letter() {
+        assert!(is_accented_letter('é'));
+        assert!(is_accented_letter('Ü'));
+        assert!(!is_accented_letter('e'));
+        assert!(!is_accented_letter('ß'));
+        assert!(!is_accented_letter('×'));
+    }
+}
@@ -31,15 +31,16 @@ pub(crate) fn requires_single_letter_continuation(letter: char) -> bool {
     letter.is_ascii_lowercase() && !matches!(letter, 'a' | 'i' | 'o')
 }
 
-fn is_ascii_letter_or_digit(ch: Option<char>) -> bool {
-    ch.is_some_and(|c| c.is_ascii_alphanumeric())
+/// 로마자(악센트가 붙은 글자 포함)나 숫자인지 확인한다.
+fn is_letter_or_digit(c: char) -> bool {
+    c.is_ascii_digit() || utils::is_english_letter(c)
 }
 
-pub(crate) fn prev_ascii_letter_or_digit(word_chars: &[char], index: usize) -> bool {
+pub(crate) fn prev_letter_or_digit(word_chars: &[char], index: usize) -> bool {
     let mut j = index;
     while j > 0 {
         let ch = word_chars[j - 1];
-        if ch.is_ascii_alphanumeric() {
+        if is_letter_or_digit(ch) {
             return true;
         }
         if symbol_shortcut::is_english_symbol_char(ch) {
@@ -51,7 +52,7 @@ pub(crate) fn prev_ascii_letter_or_digit(word_chars: &[char], index: usize) -> b
     false
 }
 
-pub(crate) fn next_ascii_letter_or_digit(
+pub(crate) fn next_letter_or_digit(
     word_chars: &[char],
     index: usize,
     remaining_words: &[&str],
@@ -59,7 +60,7 @@ pub(crate) fn next_ascii_letter_or_digit(
     let mut j = index + 1;
     while j < word_chars.len() {
         let ch = word_chars[j];
-        if ch.is_ascii_alphanumeric() {
+        if is_letter_or_digit(ch) {
             return true;
         }
         if symbol_shortcut::is_english_symbol_char(ch) {
@@ -71,7 +72,7 @@ pub(crate) fn next_ascii_letter_or_digit(
 
     for word in remaining_words {
         for ch in word.chars() {
-            if ch.is_ascii_alphanumeric() {
+            if is_letter_or_digit(ch) {
                 return true;
             }
             if symbol_shortcut::is_english_symbol_char(ch) {
@@ -114,17 +115,17 @@ pub(crate) fn should_render_symbol_as_english(
     };
 
     match symbol {
-        '(' => is_ascii_letter_or_digit(next_char) && !prev_char.is_some_and(utils::is_korean_char),
+        '(' => next_char.is_some_and(is_letter_or_digit) && !prev_char.is_some_and(utils::is_korean_char),
         ')' => parenthesis_stack.last().copied().unwrap_or(false),
         ',' => {
             if !is_english {
                 return false;
             }
 
-            let prev_ascii = prev_ascii_letter_or_digit(word_chars, index);
-            let next_ascii = next_ascii_letter_or_digit(word_chars, index, remaining_words);
+            let prev_letter = prev_letter_or_digit(word_chars, index);
+            let next_letter = next_letter_or_digit(word_chars, index, remaining_words);
 
-            prev_ascii && next_ascii
+            prev_letter && next_letter
         }
         _ => false,
     }
@@ -163,31 +164,31 @@ mod tests {
     }
 
     #[test]
-    fn prev_ascii_letter_or_digit_skips_english_symbols() {
+    fn prev_letter_or_digit_skips_english_symbols() {
         let word: Vec<char> = "A(,B".chars().collect();
-        assert!(prev_ascii_letter_or_digit(&word, 2));
+        assert!(prev_letter_or_digit(&word, 2));
 
         let hangul: Vec<char> = "가,".chars().collect();
-        assert!(!prev_ascii_letter_or_digit(&hangul, 1));
+        assert!(!prev_letter_or_digit(&hangul, 1));
     }
 
     #[test]
-    fn next_ascii_letter_or_digit_checks_future_ascii() {
+    fn next_letter_or_digit_checks_following_letters() {
         let contiguous: Vec<char> = "A,B".chars().collect();
-        assert!(next_ascii_letter_or_digit(&contiguous, 1, &[]));
+        assert!(next_letter_or_digit(&contiguous, 1, &[]));
 
         let with_symbol: Vec<char> = "A,(B".chars().collect();
-        assert!(next_ascii_letter_or_digit(&with_symbol, 1, &[]));
+        assert!(next_letter_or_digit(&with_symbol, 1, &[]));
 
         let with_remaining: Vec<char> = "A,".chars().collect();
-        assert!(next_ascii_letter_or_digit(&with_remaining, 1, &["B"]));
+        assert!(next_letter_or_digit(&with_remaining, 1, &["B"]));
 
         let hangul: Vec<char> = "A,가".chars().collect();
-        assert!(!next_ascii_letter_or_digit(&hangul, 1, &[]));
+        assert!(!next_letter_or_digit(&hangul, 1, &[]));
 
         let only_symbols: Vec<char> = "A,".chars().collect();
-        assert!(next_ascii_letter_or_digit(&only_symbols, 1, &["(B"]));
-        assert!(!next_ascii_letter_or_digit(&only_symbols, 1, &["()"]));
+        assert!(next_letter_or_digit(&only_symbols, 1, &["(B"]));
+        assert!(!next_letter_or_digit(&only_symbols, 1, &["()"]));
     }
 
     #[test]
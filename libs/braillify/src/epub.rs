@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::{
+    archive::{Archive, parse_xml},
+    book::{BookOptions, render_book},
+    document::Block,
+    html::parse_html,
+};
+
+/// `%20`처럼 쓴 URL 문자를 푼다.
+fn percent_decode(href: &str) -> String {
+    let bytes = href.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let hex = (bytes[idx] == b'%')
+            .then(|| href.get(idx + 1..idx + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                idx += 3;
+            }
+            None => {
+                decoded.push(bytes[idx]);
+                idx += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// `base` 파일에서 본 상대 경로 `href`를 묶음 안의 경로로 바꾼다.
+fn resolve(base: &str, href: &str) -> String {
+    let href = href.split('#').next().unwrap_or_default();
+    let mut parts: Vec<&str> = match base.rfind('/') {
+        Some(idx) => base[..idx].split('/').collect(),
+        None => Vec::new(),
+    };
+    for part in href.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    percent_decode(&parts.join("/"))
+}
+
+/// EPUB을 읽는 순서(spine)대로 문서 블록으로 바꾼다.
+///
+/// `META-INF/container.xml`이 가리키는 패키지 문서에서 읽는 순서를 찾고, 각 XHTML 문서를
+/// [`parse_html`]로 바꾼다. `linear="no"`인 항목은 건너뛴다.
+pub fn parse_epub(bytes: &[u8]) -> Result<Vec<Block>, String> {
+    let mut archive = Archive::new(bytes)?;
+    let container = archive.read_required("META-INF/container.xml")?;
+    let container = parse_xml(&container)?;
+    let package_path = container
+        .descendants()
+        .find(|node| node.has_tag_name("rootfile"))
+        .and_then(|node| node.attribute("full-path"))
+        .ok_or("Missing rootfile in container.xml")?
+        .to_string();
+    let package = archive.read_required(&package_path)?;
+    let package = parse_xml(&package)?;
+
+    let manifest: HashMap<&str, (&str, &str)> = package
+        .descendants()
+        .filter(|node| node.has_tag_name("item"))
+        .filter_map(|node| {
+            Some((
+                node.attribute("id")?,
+                (
+                    node.attribute("href")?,
+                    node.attribute("media-type").unwrap_or_default(),
+                ),
+            ))
+        })
+        .collect();
+
+    let mut blocks = Vec::new();
+    for itemref in package
+        .descendants()
+        .filter(|node| node.has_tag_name("itemref"))
+        .filter(|node| node.attribute("linear") != Some("no"))
+    {
+        let Some((href, media_type)) = itemref.attribute("idref").and_then(|id| manifest.get(id))
+        else {
+            continue;
+        };
+        if !media_type.contains("html") {
+            continue;
+        }
+        let path = resolve(&package_path, href);
+        blocks.extend(parse_html(&archive.read_required(&path)?));
+    }
+    Ok(blocks)
+}
+
+/// EPUB을 점역해 묵자 쪽 번호를 적은 점자책의 면마다 나눈다.
+pub fn encode_epub(bytes: &[u8], options: &BookOptions) -> Result<Vec<Vec<Vec<u8>>>, String> {
+    render_book(&parse_epub(bytes)?, options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::archive::test::zip;
+
+    const CONTAINER: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#;
+
+    const PACKAGE: &str = r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+  <manifest>
+    <item id="c1" href="text/chapter%201.xhtml" media-type="application/xhtml+xml"/>
+    <item id="c2" href="text/c2.xhtml" media-type="application/xhtml+xml"/>
+    <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml"/>
+    <item id="css" href="style.css" media-type="text/css"/>
+  </manifest>
+  <spine><itemref idref="nav" linear="no"/><itemref idref="c2"/><itemref idref="c1"/></spine>
+</package>"#;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            resolve("OEBPS/content.opf", "text/a.xhtml"),
+            "OEBPS/text/a.xhtml"
+        );
+        assert_eq!(
+            resolve("OEBPS/text/a.xhtml", "../b%20c.xhtml#x"),
+            "OEBPS/b c.xhtml"
+        );
+        assert_eq!(resolve("content.opf", "./a.xhtml"), "a.xhtml");
+    }
+
+    #[test]
+    fn test_parse_epub() {
+        let bytes = zip(&[
+            ("mimetype", "application/epub+zip"),
+            ("META-INF/container.xml", CONTAINER),
+            ("OEBPS/content.opf", PACKAGE),
+            ("OEBPS/nav.xhtml", "<html><body><p>차례</p></body></html>"),
+            (
+                "OEBPS/text/chapter 1.xhtml",
+                r#"<?xml version="1.0"?><!DOCTYPE html><html xmlns:epub="http://www.idpf.org/2007/ops">
+<head><title>1장</title></head><body><h1>둘째</h1><p>나<span epub:type="pagebreak" title="2"/></p></body></html>"#,
+            ),
+            (
+                "OEBPS/text/c2.xhtml",
+                r#"<html><body><span epub:type="pagebreak" title="1"/><p><em>첫째</em></p></body></html>"#,
+            ),
+        ]);
+        assert_eq!(
+            parse_epub(&bytes).unwrap(),
+            vec![
+                Block::PageBreak("1".to_string()),
+                Block::Paragraph("\u{E000}첫째\u{E001}".to_string()),
+                Block::Heading("둘째".to_string()),
+                Block::Paragraph("나".to_string()),
+                Block::PageBreak("2".to_string()),
+            ]
+        );
+        assert!(parse_epub(&zip(&[("mimetype", "application/epub+zip")])).is_err());
+    }
+
+    #[test]
+    fn test_encode_epub() {
+        let bytes = zip(&[
+            ("META-INF/container.xml", CONTAINER),
+            ("OEBPS/content.opf", PACKAGE),
+            ("OEBPS/text/chapter 1.xhtml", "<p>상</p>"),
+            ("OEBPS/text/c2.xhtml", "<p>상</p>"),
+        ]);
+        let pages = encode_epub(&bytes, &BookOptions::default()).unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].len(), 25);
+        assert_eq!(pages[0][1], vec![0, 0, 7, 54]);
+    }
+}
@@ -49,7 +49,7 @@ pub fn encode_mixed_fraction(whole: &str, numerator: &str, denominator: &str) ->
     Ok(result)
 }
 
-fn normalize_digit(c: char) -> Option<char> {
+pub(crate) fn normalize_digit(c: char) -> Option<char> {
     match c {
         '0' | '⁰' | '₀' => Some('0'),
         '1' | '¹' | '₁' => Some('1'),
@@ -0,0 +1,135 @@
+/// 점자를 그리거나 찍을 때의 치수(mm). 기본값은 일반 점자의 표준 치수이다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellDimensions {
+    /// 한 칸 안에서 이웃한 점의 중심 사이 거리
+    pub dot_spacing: f64,
+    /// 이웃한 칸에서 같은 점의 중심 사이 거리
+    pub cell_spacing: f64,
+    /// 이웃한 줄에서 같은 점의 중심 사이 거리
+    pub line_spacing: f64,
+    /// 점의 밑면 지름
+    pub dot_diameter: f64,
+}
+
+impl Default for CellDimensions {
+    fn default() -> Self {
+        Self {
+            dot_spacing: 2.5,
+            cell_spacing: 6.0,
+            line_spacing: 10.0,
+            dot_diameter: 1.5,
+        }
+    }
+}
+
+impl CellDimensions {
+    /// 칸의 1점 중심에서 본 `dot`점(0부터 센다)의 중심. 1~3점은 왼쪽, 4~6점은 오른쪽 열에
+    /// 위에서부터 놓고, 8점 점자의 7점과 8점은 그 아래 줄의 왼쪽과 오른쪽에 놓는다.
+    pub(crate) fn dot_offset(&self, dot: usize) -> (f64, f64) {
+        let (column, row) = match dot {
+            0..3 => (0, dot),
+            3..6 => (1, dot - 3),
+            _ => (dot - 6, 3),
+        };
+        (
+            column as f64 * self.dot_spacing,
+            row as f64 * self.dot_spacing,
+        )
+    }
+
+    /// `line`번째 줄의 `column`번째 칸에서 1점 중심의 위치. 왼쪽 위 점의 가장자리가 원점에 닿는다.
+    pub(crate) fn cell_origin(&self, column: usize, line: usize) -> (f64, f64) {
+        let radius = self.dot_diameter / 2.0;
+        (
+            radius + column as f64 * self.cell_spacing,
+            radius + line as f64 * self.line_spacing,
+        )
+    }
+
+    /// `columns`칸, `lines`줄을 적는 데 필요한 너비와 높이. 칸마다 점을 `rows`줄 적는다.
+    pub(crate) fn size(&self, columns: usize, lines: usize, rows: usize) -> (f64, f64) {
+        let width = match columns {
+            0 => 0.0,
+            _ => (columns - 1) as f64 * self.cell_spacing + self.dot_spacing + self.dot_diameter,
+        };
+        let height = match lines {
+            0 => 0.0,
+            _ => {
+                (lines - 1) as f64 * self.line_spacing
+                    + (rows - 1) as f64 * self.dot_spacing
+                    + self.dot_diameter
+            }
+        };
+        (width, height)
+    }
+}
+
+/// 점형들을 적는 데 필요한 칸의 점 줄 수. 7점이나 8점을 찍는 점형이 있으면 넷째 줄까지 쓴다.
+pub(crate) fn dot_rows<'a>(cells: impl IntoIterator<Item = &'a u8>) -> usize {
+    if cells.into_iter().any(|cell| cell & 0xC0 != 0) {
+        4
+    } else {
+        3
+    }
+}
+
+/// 점형에서 찍는 점의 번호(0부터 센다)
+pub(crate) fn raised_dots(cell: u8) -> impl Iterator<Item = usize> {
+    (0..8).filter(move |dot| cell & (1 << dot) != 0)
+}
+
+/// 소수점 아래 셋째 자리에서 반올림하고 끝의 0을 지워 적는다.
+pub(crate) fn format_mm(value: f64) -> String {
+    let text = format!("{value:.3}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    match text {
+        "-0" => "0".to_string(),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::decode_unicode;
+
+    #[test]
+    fn test_raised_dots() {
+        assert_eq!(
+            raised_dots(decode_unicode('⠇')).collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+        assert_eq!(
+            raised_dots(decode_unicode('⡸')).collect::<Vec<_>>(),
+            [3, 4, 5, 6]
+        );
+        assert_eq!(raised_dots(0).count(), 0);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let dimensions = CellDimensions::default();
+        assert_eq!(dimensions.dot_offset(0), (0.0, 0.0));
+        assert_eq!(dimensions.dot_offset(4), (2.5, 2.5));
+        assert_eq!(dimensions.dot_offset(7), (2.5, 7.5));
+        assert_eq!(dimensions.cell_origin(1, 2), (6.75, 20.75));
+        assert_eq!(dimensions.size(2, 1, 3), (10.0, 6.5));
+        assert_eq!(dimensions.size(2, 1, 4), (10.0, 9.0));
+        assert_eq!(dimensions.size(0, 0, 3), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_dot_rows() {
+        assert_eq!(dot_rows(&[decode_unicode('⠿'), 0]), 3);
+        assert_eq!(dot_rows(&[decode_unicode('⠁'), decode_unicode('⣀')]), 4);
+        assert_eq!(dot_rows(&[]), 3);
+    }
+
+    #[test]
+    fn test_format_mm() {
+        assert_eq!(format_mm(2.5), "2.5");
+        assert_eq!(format_mm(10.0), "10");
+        assert_eq!(format_mm(1.0 / 3.0), "0.333");
+        assert_eq!(format_mm(-0.0001), "0");
+    }
+}
@@ -0,0 +1,576 @@
+use std::borrow::Cow;
+
+use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{options::HanjaPolicy, unicode::decode_unicode};
+
+/// 한자 시작 표. 한자를 점자로 옮길 때에는 한자의 음을 한자표 사이에 적는다.
+pub const HANJA_OPEN: [u8; 2] = [decode_unicode('⠐'), decode_unicode('⠆')];
+/// 한자 종료 표
+pub const HANJA_CLOSE: [u8; 2] = [decode_unicode('⠰'), decode_unicode('⠂')];
+
+/// 교육용 기초 한자 1,800자와 자주 쓰는 한자의 음 (본음). 두음 법칙은 [`hanja_reading`]에서 적용한다.
+static HANJA_READING_MAP: phf::Map<char, char> = phf_map! {
+    '家' => '가', '歌' => '가', '價' => '가', '加' => '가', '可' => '가', '街' => '가', '假' => '가', '佳' => '가', '架' => '가', '暇' => '가',
+    '各' => '각', '角' => '각', '脚' => '각', '覺' => '각', '閣' => '각', '却' => '각', '刻' => '각',
+    '間' => '간', '看' => '간', '干' => '간', '簡' => '간', '幹' => '간', '刊' => '간', '肝' => '간', '姦' => '간', '懇' => '간',
+    '渴' => '갈',
+    '感' => '감', '減' => '감', '甘' => '감', '監' => '감', '敢' => '감', '鑑' => '감',
+    '甲' => '갑',
+    '江' => '강', '強' => '강', '降' => '강', '講' => '강', '康' => '강', '剛' => '강', '鋼' => '강', '綱' => '강',
+    '開' => '개', '改' => '개', '個' => '개', '皆' => '개', '介' => '개', '慨' => '개', '概' => '개', '蓋' => '개',
+    '客' => '객',
+    '去' => '거', '巨' => '거', '居' => '거', '車' => '거', '擧' => '거', '距' => '거', '拒' => '거', '據' => '거',
+    '建' => '건', '件' => '건', '健' => '건', '乾' => '건',
+    '傑' => '걸',
+    '檢' => '검', '儉' => '검', '劍' => '검',
+    '格' => '격', '激' => '격', '擊' => '격', '隔' => '격',
+    '見' => '견', '堅' => '견', '犬' => '견', '肩' => '견', '絹' => '견', '遣' => '견',
+    '結' => '결', '決' => '결', '潔' => '결', '缺' => '결',
+    '兼' => '겸', '謙' => '겸',
+    '京' => '경', '景' => '경', '經' => '경', '敬' => '경', '輕' => '경', '慶' => '경', '競' => '경', '驚' => '경', '境' => '경', '耕' => '경', '庚' => '경', '警' => '경', '傾' => '경', '鏡' => '경', '竟' => '경', '頃' => '경', '硬' => '경', '徑' => '경', '卿' => '경', '更' => '경',
+    '計' => '계', '界' => '계', '季' => '계', '溪' => '계', '癸' => '계', '鷄' => '계', '係' => '계', '階' => '계', '繼' => '계', '系' => '계', '戒' => '계', '械' => '계', '契' => '계', '桂' => '계', '啓' => '계', '繫' => '계',
+    '古' => '고', '高' => '고', '告' => '고', '考' => '고', '固' => '고', '故' => '고', '苦' => '고', '庫' => '고', '孤' => '고', '枯' => '고', '姑' => '고', '鼓' => '고', '稿' => '고', '顧' => '고',
+    '曲' => '곡', '穀' => '곡', '谷' => '곡', '哭' => '곡',
+    '困' => '곤', '坤' => '곤',
+    '骨' => '골',
+    '工' => '공', '公' => '공', '空' => '공', '功' => '공', '共' => '공', '攻' => '공', '孔' => '공', '供' => '공', '恭' => '공', '恐' => '공', '貢' => '공',
+    '科' => '과', '過' => '과', '果' => '과', '課' => '과', '誇' => '과', '戈' => '과', '瓜' => '과', '寡' => '과',
+    '郭' => '곽',
+    '觀' => '관', '官' => '관', '關' => '관', '管' => '관', '館' => '관', '貫' => '관', '慣' => '관', '冠' => '관', '寬' => '관',
+    '光' => '광', '廣' => '광', '鑛' => '광', '狂' => '광',
+    '掛' => '괘',
+    '塊' => '괴', '愧' => '괴', '怪' => '괴', '壞' => '괴',
+    '敎' => '교', '校' => '교', '交' => '교', '橋' => '교', '郊' => '교', '較' => '교', '巧' => '교', '矯' => '교',
+    '九' => '구', '口' => '구', '求' => '구', '救' => '구', '究' => '구', '久' => '구', '句' => '구', '舊' => '구', '區' => '구', '具' => '구', '球' => '구', '構' => '구', '俱' => '구', '驅' => '구', '鷗' => '구', '苟' => '구', '拘' => '구', '狗' => '구', '丘' => '구', '懼' => '구', '龜' => '구',
+    '國' => '국', '局' => '국', '菊' => '국',
+    '軍' => '군', '君' => '군', '郡' => '군', '群' => '군',
+    '屈' => '굴',
+    '弓' => '궁', '宮' => '궁', '窮' => '궁',
+    '權' => '권', '勸' => '권', '卷' => '권', '券' => '권', '拳' => '권',
+    '厥' => '궐',
+    '軌' => '궤',
+    '貴' => '귀', '歸' => '귀', '鬼' => '귀',
+    '規' => '규', '叫' => '규', '閨' => '규',
+    '均' => '균', '菌' => '균',
+    '極' => '극', '劇' => '극', '克' => '극',
+    '近' => '근', '根' => '근', '勤' => '근', '斤' => '근', '僅' => '근', '謹' => '근',
+    '金' => '금', '今' => '금', '禁' => '금', '錦' => '금', '禽' => '금', '琴' => '금',
+    '急' => '급', '給' => '급', '級' => '급', '及' => '급',
+    '肯' => '긍',
+    '己' => '기', '記' => '기', '起' => '기', '基' => '기', '氣' => '기', '技' => '기', '期' => '기', '旗' => '기', '機' => '기', '其' => '기', '幾' => '기', '器' => '기', '寄' => '기', '紀' => '기', '旣' => '기', '忌' => '기', '欺' => '기', '奇' => '기', '騎' => '기', '豈' => '기', '棄' => '기', '祈' => '기', '企' => '기', '畿' => '기', '飢' => '기',
+    '緊' => '긴',
+    '吉' => '길',
+    '那' => '나',
+    '諾' => '낙',
+    '暖' => '난', '難' => '난',
+    '南' => '남', '男' => '남',
+    '納' => '납',
+    '娘' => '낭',
+    '內' => '내', '乃' => '내', '奈' => '내', '耐' => '내',
+    '女' => '녀',
+    '年' => '년',
+    '念' => '념',
+    '寧' => '녕',
+    '怒' => '노', '努' => '노', '奴' => '노',
+    '農' => '농',
+    '腦' => '뇌', '惱' => '뇌',
+    '能' => '능',
+    '泥' => '니',
+    '多' => '다', '茶' => '다',
+    '單' => '단', '短' => '단', '端' => '단', '團' => '단', '檀' => '단', '丹' => '단', '但' => '단', '旦' => '단', '段' => '단', '壇' => '단', '斷' => '단',
+    '達' => '달',
+    '談' => '담', '擔' => '담', '淡' => '담',
+    '答' => '답', '畓' => '답', '踏' => '답',
+    '堂' => '당', '當' => '당', '黨' => '당', '唐' => '당', '糖' => '당',
+    '大' => '대', '代' => '대', '對' => '대', '待' => '대', '隊' => '대', '臺' => '대', '帶' => '대', '貸' => '대',
+    '德' => '덕',
+    '道' => '도', '度' => '도', '島' => '도', '都' => '도', '圖' => '도', '刀' => '도', '到' => '도', '導' => '도', '徒' => '도', '倒' => '도', '挑' => '도', '桃' => '도', '跳' => '도', '逃' => '도', '渡' => '도', '陶' => '도', '途' => '도', '稻' => '도', '盜' => '도', '塗' => '도',
+    '讀' => '독', '獨' => '독', '毒' => '독', '督' => '독', '篤' => '독',
+    '豚' => '돈', '敦' => '돈',
+    '突' => '돌',
+    '東' => '동', '動' => '동', '同' => '동', '冬' => '동', '洞' => '동', '童' => '동', '銅' => '동', '凍' => '동',
+    '斗' => '두', '頭' => '두', '豆' => '두',
+    '鈍' => '둔',
+    '得' => '득',
+    '等' => '등', '登' => '등', '燈' => '등', '騰' => '등',
+    '羅' => '라',
+    '落' => '락', '樂' => '락', '絡' => '락',
+    '卵' => '란', '亂' => '란', '蘭' => '란', '欄' => '란',
+    '覽' => '람', '濫' => '람',
+    '朗' => '랑', '浪' => '랑', '郞' => '랑', '郎' => '랑', '廊' => '랑',
+    '來' => '래',
+    '冷' => '랭',
+    '略' => '략', '掠' => '략',
+    '良' => '량', '兩' => '량', '量' => '량', '糧' => '량', '涼' => '량', '梁' => '량', '諒' => '량',
+    '旅' => '려', '慮' => '려', '麗' => '려', '勵' => '려',
+    '力' => '력', '歷' => '력', '曆' => '력',
+    '連' => '련', '練' => '련', '鍊' => '련', '憐' => '련', '聯' => '련', '戀' => '련', '蓮' => '련',
+    '列' => '렬', '烈' => '렬', '裂' => '렬', '劣' => '렬',
+    '廉' => '렴',
+    '獵' => '렵',
+    '令' => '령', '領' => '령', '嶺' => '령', '零' => '령', '靈' => '령',
+    '例' => '례', '禮' => '례', '隷' => '례',
+    '路' => '로', '老' => '로', '勞' => '로', '露' => '로', '爐' => '로',
+    '綠' => '록', '錄' => '록', '祿' => '록', '鹿' => '록',
+    '論' => '론',
+    '弄' => '롱',
+    '雷' => '뢰', '賴' => '뢰',
+    '料' => '료', '了' => '료', '僚' => '료',
+    '龍' => '룡',
+    '屢' => '루', '樓' => '루', '累' => '루', '淚' => '루', '漏' => '루',
+    '流' => '류', '留' => '류', '類' => '류', '柳' => '류',
+    '六' => '륙', '陸' => '륙',
+    '倫' => '륜', '輪' => '륜',
+    '律' => '률', '率' => '률', '栗' => '률',
+    '隆' => '륭',
+    '陵' => '릉',
+    '里' => '리', '理' => '리', '利' => '리', '李' => '리', '梨' => '리', '吏' => '리', '離' => '리', '裏' => '리', '履' => '리',
+    '隣' => '린',
+    '林' => '림', '臨' => '림',
+    '立' => '립',
+    '馬' => '마', '麻' => '마', '磨' => '마',
+    '莫' => '막', '幕' => '막', '漠' => '막',
+    '萬' => '만', '滿' => '만', '晩' => '만', '慢' => '만', '漫' => '만',
+    '末' => '말',
+    '望' => '망', '亡' => '망', '忘' => '망', '忙' => '망', '茫' => '망', '妄' => '망', '罔' => '망',
+    '每' => '매', '買' => '매', '賣' => '매', '妹' => '매', '梅' => '매', '埋' => '매', '媒' => '매',
+    '麥' => '맥', '脈' => '맥',
+    '孟' => '맹', '猛' => '맹', '盟' => '맹', '盲' => '맹',
+    '面' => '면', '免' => '면', '勉' => '면', '眠' => '면', '綿' => '면',
+    '滅' => '멸',
+    '名' => '명', '命' => '명', '明' => '명', '鳴' => '명', '銘' => '명', '冥' => '명',
+    '母' => '모', '毛' => '모', '暮' => '모', '模' => '모', '某' => '모', '謀' => '모', '貌' => '모', '募' => '모', '慕' => '모', '冒' => '모', '侮' => '모',
+    '木' => '목', '目' => '목', '牧' => '목', '睦' => '목',
+    '沒' => '몰',
+    '夢' => '몽', '蒙' => '몽',
+    '妙' => '묘', '卯' => '묘', '苗' => '묘', '廟' => '묘', '墓' => '묘',
+    '無' => '무', '武' => '무', '務' => '무', '舞' => '무', '戊' => '무', '茂' => '무', '貿' => '무', '霧' => '무',
+    '墨' => '묵', '默' => '묵',
+    '文' => '문', '門' => '문', '問' => '문', '聞' => '문',
+    '物' => '물', '勿' => '물',
+    '米' => '미', '未' => '미', '美' => '미', '味' => '미', '尾' => '미', '迷' => '미', '微' => '미', '眉' => '미',
+    '民' => '민', '敏' => '민', '憫' => '민',
+    '密' => '밀', '蜜' => '밀',
+    '朴' => '박', '博' => '박', '泊' => '박', '拍' => '박', '迫' => '박', '薄' => '박',
+    '反' => '반', '半' => '반', '班' => '반', '飯' => '반', '般' => '반', '盤' => '반', '返' => '반', '叛' => '반', '伴' => '반',
+    '發' => '발', '拔' => '발', '髮' => '발',
+    '方' => '방', '房' => '방', '防' => '방', '放' => '방', '訪' => '방', '芳' => '방', '傍' => '방', '妨' => '방', '倣' => '방', '邦' => '방',
+    '拜' => '배', '杯' => '배', '倍' => '배', '培' => '배', '配' => '배', '排' => '배', '輩' => '배', '背' => '배',
+    '白' => '백', '百' => '백', '伯' => '백',
+    '番' => '번', '煩' => '번', '繁' => '번', '飜' => '번',
+    '伐' => '벌', '罰' => '벌',
+    '凡' => '범', '犯' => '범', '範' => '범', '汎' => '범',
+    '法' => '법',
+    '壁' => '벽', '碧' => '벽',
+    '變' => '변', '邊' => '변', '辯' => '변', '辨' => '변',
+    '別' => '별',
+    '兵' => '병', '病' => '병', '丙' => '병', '竝' => '병', '屛' => '병',
+    '保' => '보', '步' => '보', '報' => '보', '寶' => '보', '普' => '보', '譜' => '보', '補' => '보',
+    '福' => '복', '服' => '복', '復' => '복', '伏' => '복', '腹' => '복', '複' => '복', '卜' => '복', '覆' => '복',
+    '本' => '본',
+    '奉' => '봉', '逢' => '봉', '峯' => '봉', '蜂' => '봉', '封' => '봉', '鳳' => '봉',
+    '父' => '부', '夫' => '부', '部' => '부', '婦' => '부', '富' => '부', '府' => '부', '副' => '부', '扶' => '부', '否' => '부', '浮' => '부', '付' => '부', '符' => '부', '附' => '부', '腐' => '부', '負' => '부', '簿' => '부', '赴' => '부', '賦' => '부',
+    '北' => '북',
+    '分' => '분', '紛' => '분', '粉' => '분', '奔' => '분', '墳' => '분', '憤' => '분', '奮' => '분',
+    '不' => '불', '佛' => '불', '拂' => '불',
+    '朋' => '붕', '崩' => '붕',
+    '比' => '비', '非' => '비', '悲' => '비', '飛' => '비', '鼻' => '비', '備' => '비', '費' => '비', '批' => '비', '卑' => '비', '婢' => '비', '碑' => '비', '妃' => '비', '肥' => '비', '祕' => '비',
+    '貧' => '빈', '賓' => '빈', '頻' => '빈',
+    '氷' => '빙', '聘' => '빙',
+    '四' => '사', '士' => '사', '史' => '사', '使' => '사', '死' => '사', '事' => '사', '思' => '사', '寺' => '사', '師' => '사', '私' => '사', '絲' => '사', '射' => '사', '謝' => '사', '社' => '사', '仕' => '사', '舍' => '사', '査' => '사', '巳' => '사', '司' => '사', '詞' => '사', '蛇' => '사', '捨' => '사', '邪' => '사', '賜' => '사', '斜' => '사', '詐' => '사', '沙' => '사', '似' => '사', '寫' => '사', '辭' => '사', '斯' => '사', '祀' => '사',
+    '削' => '삭', '朔' => '삭',
+    '山' => '산', '産' => '산', '散' => '산', '算' => '산', '產' => '산', '酸' => '산',
+    '殺' => '살',
+    '三' => '삼', '森' => '삼',
+    '上' => '상', '商' => '상', '相' => '상', '想' => '상', '常' => '상', '賞' => '상', '象' => '상', '狀' => '상', '尙' => '상', '霜' => '상', '傷' => '상', '喪' => '상', '嘗' => '상', '裳' => '상', '詳' => '상', '祥' => '상', '床' => '상', '像' => '상', '桑' => '상', '償' => '상',
+    '塞' => '새',
+    '色' => '색', '索' => '색',
+    '生' => '생',
+    '西' => '서', '書' => '서', '序' => '서', '暑' => '서', '敍' => '서', '徐' => '서', '庶' => '서', '恕' => '서', '署' => '서', '緖' => '서', '誓' => '서', '逝' => '서',
+    '石' => '석', '夕' => '석', '席' => '석', '昔' => '석', '惜' => '석', '析' => '석', '釋' => '석',
+    '先' => '선', '線' => '선', '善' => '선', '船' => '선', '選' => '선', '仙' => '선', '鮮' => '선', '宣' => '선', '旋' => '선', '禪' => '선',
+    '雪' => '설', '說' => '설', '設' => '설', '舌' => '설',
+    '涉' => '섭', '攝' => '섭',
+    '成' => '성', '性' => '성', '姓' => '성', '城' => '성', '星' => '성', '聖' => '성', '誠' => '성', '盛' => '성', '省' => '성', '聲' => '성',
+    '世' => '세', '洗' => '세', '稅' => '세', '歲' => '세', '勢' => '세', '細' => '세',
+    '小' => '소', '少' => '소', '所' => '소', '消' => '소', '素' => '소', '笑' => '소', '召' => '소', '昭' => '소', '蘇' => '소', '騷' => '소', '燒' => '소', '訴' => '소', '掃' => '소', '疏' => '소', '蔬' => '소',
+    '俗' => '속', '速' => '속', '續' => '속', '束' => '속', '粟' => '속', '屬' => '속',
+    '孫' => '손', '損' => '손',
+    '松' => '송', '送' => '송', '頌' => '송', '訟' => '송', '誦' => '송',
+    '刷' => '쇄', '鎖' => '쇄',
+    '衰' => '쇠',
+    '水' => '수', '手' => '수', '數' => '수', '受' => '수', '首' => '수', '修' => '수', '守' => '수', '收' => '수', '秀' => '수', '樹' => '수', '壽' => '수', '授' => '수', '誰' => '수', '須' => '수', '雖' => '수', '愁' => '수', '囚' => '수', '需' => '수', '帥' => '수', '殊' => '수', '隨' => '수', '輸' => '수', '獸' => '수', '睡' => '수', '遂' => '수', '垂' => '수', '搜' => '수',
+    '宿' => '숙', '叔' => '숙', '淑' => '숙', '孰' => '숙', '熟' => '숙', '肅' => '숙',
+    '順' => '순', '純' => '순', '旬' => '순', '殉' => '순', '盾' => '순', '循' => '순', '脣' => '순', '瞬' => '순', '巡' => '순',
+    '術' => '술', '戌' => '술', '述' => '술',
+    '崇' => '숭',
+    '習' => '습', '拾' => '습', '濕' => '습', '襲' => '습',
+    '勝' => '승', '承' => '승', '乘' => '승', '升' => '승', '昇' => '승', '僧' => '승',
+    '時' => '시', '市' => '시', '始' => '시', '示' => '시', '詩' => '시', '試' => '시', '是' => '시', '視' => '시', '施' => '시', '矢' => '시', '侍' => '시',
+    '食' => '식', '式' => '식', '植' => '식', '識' => '식', '息' => '식', '飾' => '식',
+    '身' => '신', '新' => '신', '信' => '신', '神' => '신', '臣' => '신', '申' => '신', '辛' => '신', '伸' => '신', '晨' => '신', '愼' => '신',
+    '室' => '실', '失' => '실', '實' => '실',
+    '心' => '심', '深' => '심', '甚' => '심', '尋' => '심', '審' => '심',
+    '十' => '십',
+    '雙' => '쌍',
+    '氏' => '씨',
+    '兒' => '아', '我' => '아', '牙' => '아', '芽' => '아', '雅' => '아', '亞' => '아', '阿' => '아', '餓' => '아',
+    '惡' => '악', '岳' => '악',
+    '安' => '안', '案' => '안', '眼' => '안', '顔' => '안', '岸' => '안', '雁' => '안',
+    '謁' => '알',
+    '暗' => '암', '巖' => '암',
+    '壓' => '압', '押' => '압',
+    '仰' => '앙', '央' => '앙', '殃' => '앙',
+    '愛' => '애', '哀' => '애', '涯' => '애',
+    '厄' => '액', '額' => '액',
+    '夜' => '야', '野' => '야', '也' => '야', '耶' => '야',
+    '約' => '약', '藥' => '약', '弱' => '약', '若' => '약',
+    '羊' => '양', '洋' => '양', '養' => '양', '陽' => '양', '揚' => '양', '讓' => '양', '壤' => '양', '樣' => '양', '楊' => '양',
+    '魚' => '어', '語' => '어', '漁' => '어', '於' => '어', '御' => '어',
+    '億' => '억', '憶' => '억', '抑' => '억',
+    '言' => '언', '焉' => '언',
+    '嚴' => '엄',
+    '業' => '업',
+    '如' => '여', '餘' => '여', '余' => '여', '汝' => '여', '與' => '여', '予' => '여', '輿' => '여',
+    '逆' => '역', '亦' => '역', '易' => '역', '譯' => '역', '驛' => '역', '役' => '역', '疫' => '역', '域' => '역',
+    '然' => '연', '硏' => '연', '煙' => '연', '硯' => '연', '延' => '연', '燃' => '연', '燕' => '연', '沿' => '연', '鉛' => '연', '宴' => '연', '軟' => '연', '演' => '연', '緣' => '연',
+    '熱' => '열', '悅' => '열', '閱' => '열',
+    '炎' => '염', '染' => '염', '鹽' => '염',
+    '葉' => '엽',
+    '永' => '영', '英' => '영', '榮' => '영', '營' => '영', '迎' => '영', '泳' => '영', '詠' => '영', '影' => '영', '映' => '영',
+    '藝' => '예', '豫' => '예', '譽' => '예', '銳' => '예',
+    '五' => '오', '午' => '오', '誤' => '오', '烏' => '오', '吾' => '오', '悟' => '오', '汚' => '오', '嗚' => '오', '娛' => '오', '傲' => '오',
+    '玉' => '옥', '屋' => '옥', '獄' => '옥',
+    '溫' => '온',
+    '翁' => '옹', '擁' => '옹',
+    '瓦' => '와', '臥' => '와',
+    '完' => '완', '緩' => '완',
+    '曰' => '왈',
+    '王' => '왕', '往' => '왕',
+    '外' => '외', '畏' => '외',
+    '要' => '요', '腰' => '요', '搖' => '요', '遙' => '요', '謠' => '요',
+    '浴' => '욕', '欲' => '욕', '慾' => '욕', '辱' => '욕',
+    '用' => '용', '勇' => '용', '容' => '용', '庸' => '용',
+    '右' => '우', '雨' => '우', '友' => '우', '牛' => '우', '于' => '우', '遇' => '우', '宇' => '우', '憂' => '우', '又' => '우', '尤' => '우', '羽' => '우', '郵' => '우', '愚' => '우', '偶' => '우', '優' => '우',
+    '雲' => '운', '運' => '운', '云' => '운', '韻' => '운',
+    '鬱' => '울',
+    '雄' => '웅',
+    '元' => '원', '原' => '원', '遠' => '원', '院' => '원', '願' => '원', '園' => '원', '圓' => '원', '員' => '원', '怨' => '원', '源' => '원', '援' => '원',
+    '月' => '월', '越' => '월',
+    '位' => '위', '偉' => '위', '危' => '위', '爲' => '위', '衛' => '위', '圍' => '위', '威' => '위', '胃' => '위', '謂' => '위', '緯' => '위', '違' => '위', '委' => '위', '慰' => '위', '僞' => '위',
+    '有' => '유', '由' => '유', '油' => '유', '遺' => '유', '幼' => '유', '酉' => '유', '猶' => '유', '唯' => '유', '遊' => '유', '柔' => '유', '幽' => '유', '惟' => '유', '維' => '유', '乳' => '유', '儒' => '유', '裕' => '유', '誘' => '유', '愈' => '유', '悠' => '유',
+    '育' => '육', '肉' => '육',
+    '閏' => '윤', '潤' => '윤',
+    '銀' => '은', '恩' => '은', '隱' => '은',
+    '乙' => '을',
+    '音' => '음', '飮' => '음', '陰' => '음', '吟' => '음', '淫' => '음',
+    '邑' => '읍', '泣' => '읍',
+    '應' => '응', '凝' => '응',
+    '意' => '의', '衣' => '의', '義' => '의', '醫' => '의', '議' => '의', '依' => '의', '矣' => '의', '宜' => '의', '儀' => '의', '疑' => '의',
+    '二' => '이', '耳' => '이', '以' => '이', '已' => '이', '異' => '이', '移' => '이', '貳' => '이', '而' => '이', '夷' => '이',
+    '益' => '익', '翼' => '익',
+    '人' => '인', '因' => '인', '引' => '인', '印' => '인', '認' => '인', '仁' => '인', '忍' => '인', '寅' => '인', '刃' => '인', '姻' => '인',
+    '一' => '일', '日' => '일', '壹' => '일', '逸' => '일',
+    '任' => '임', '壬' => '임', '賃' => '임',
+    '入' => '입',
+    '子' => '자', '自' => '자', '字' => '자', '者' => '자', '姉' => '자', '資' => '자', '慈' => '자', '玆' => '자', '雌' => '자', '紫' => '자', '姿' => '자', '恣' => '자', '刺' => '자',
+    '作' => '작', '昨' => '작', '酌' => '작', '爵' => '작',
+    '殘' => '잔',
+    '潛' => '잠', '暫' => '잠', '蠶' => '잠',
+    '雜' => '잡',
+    '長' => '장', '場' => '장', '章' => '장', '將' => '장', '壯' => '장', '張' => '장', '丈' => '장', '帳' => '장', '莊' => '장', '裝' => '장', '奬' => '장', '墻' => '장', '葬' => '장', '粧' => '장', '掌' => '장', '藏' => '장', '臟' => '장', '障' => '장', '腸' => '장',
+    '在' => '재', '才' => '재', '再' => '재', '材' => '재', '財' => '재', '栽' => '재', '哉' => '재', '災' => '재', '裁' => '재', '載' => '재', '宰' => '재',
+    '爭' => '쟁',
+    '低' => '저', '貯' => '저', '著' => '저', '底' => '저', '抵' => '저',
+    '赤' => '적', '的' => '적', '敵' => '적', '適' => '적', '笛' => '적', '滴' => '적', '摘' => '적', '寂' => '적', '籍' => '적', '賊' => '적', '跡' => '적', '蹟' => '적', '積' => '적', '績' => '적',
+    '田' => '전', '全' => '전', '前' => '전', '典' => '전', '戰' => '전', '電' => '전', '傳' => '전', '展' => '전', '錢' => '전', '專' => '전', '轉' => '전', '殿' => '전',
+    '節' => '절', '絶' => '절', '切' => '절', '折' => '절', '竊' => '절',
+    '店' => '점', '點' => '점', '占' => '점', '漸' => '점',
+    '接' => '접', '蝶' => '접',
+    '正' => '정', '定' => '정', '政' => '정', '情' => '정', '精' => '정', '庭' => '정', '停' => '정', '靜' => '정', '丁' => '정', '頂' => '정', '井' => '정', '貞' => '정', '淨' => '정', '亭' => '정', '訂' => '정', '廷' => '정', '程' => '정', '征' => '정', '整' => '정',
+    '弟' => '제', '第' => '제', '祭' => '제', '題' => '제', '製' => '제', '除' => '제', '帝' => '제', '濟' => '제', '諸' => '제', '提' => '제', '堤' => '제', '制' => '제', '際' => '제', '齊' => '제',
+    '鳥' => '조', '早' => '조', '朝' => '조', '助' => '조', '祖' => '조', '造' => '조', '調' => '조', '兆' => '조', '弔' => '조', '燥' => '조', '操' => '조', '照' => '조', '條' => '조', '潮' => '조', '租' => '조', '組' => '조',
+    '足' => '족', '族' => '족',
+    '存' => '존', '尊' => '존',
+    '卒' => '졸', '拙' => '졸',
+    '宗' => '종', '種' => '종', '終' => '종', '鍾' => '종', '從' => '종', '鐘' => '종', '縱' => '종',
+    '左' => '좌', '坐' => '좌', '佐' => '좌', '座' => '좌',
+    '罪' => '죄',
+    '主' => '주', '注' => '주', '住' => '주', '晝' => '주', '走' => '주', '朱' => '주', '酒' => '주', '州' => '주', '宙' => '주', '舟' => '주', '周' => '주', '株' => '주', '洲' => '주', '柱' => '주', '奏' => '주', '珠' => '주', '鑄' => '주',
+    '竹' => '죽',
+    '準' => '준', '俊' => '준', '遵' => '준',
+    '中' => '중', '重' => '중', '衆' => '중', '仲' => '중',
+    '卽' => '즉',
+    '增' => '증', '證' => '증', '曾' => '증', '憎' => '증', '贈' => '증', '症' => '증', '蒸' => '증',
+    '地' => '지', '知' => '지', '止' => '지', '志' => '지', '指' => '지', '至' => '지', '紙' => '지', '支' => '지', '持' => '지', '只' => '지', '枝' => '지', '之' => '지', '池' => '지', '誌' => '지', '智' => '지', '遲' => '지',
+    '直' => '직', '職' => '직', '織' => '직',
+    '眞' => '진', '進' => '진', '盡' => '진', '辰' => '진', '振' => '진', '鎭' => '진', '陣' => '진', '陳' => '진', '珍' => '진', '震' => '진',
+    '質' => '질', '秩' => '질', '疾' => '질', '姪' => '질',
+    '集' => '집', '執' => '집',
+    '徵' => '징', '懲' => '징',
+    '次' => '차', '差' => '차', '且' => '차', '此' => '차', '借' => '차',
+    '着' => '착', '錯' => '착', '捉' => '착',
+    '贊' => '찬', '讚' => '찬',
+    '察' => '찰',
+    '參' => '참', '慘' => '참', '慙' => '참',
+    '窓' => '창', '唱' => '창', '創' => '창', '昌' => '창', '倉' => '창', '蒼' => '창', '暢' => '창',
+    '採' => '채', '菜' => '채', '彩' => '채', '債' => '채',
+    '冊' => '책', '責' => '책', '策' => '책',
+    '處' => '처', '妻' => '처', '悽' => '처',
+    '尺' => '척', '斥' => '척', '拓' => '척', '戚' => '척',
+    '千' => '천', '天' => '천', '川' => '천', '泉' => '천', '淺' => '천', '賤' => '천', '踐' => '천', '遷' => '천', '薦' => '천',
+    '鐵' => '철', '哲' => '철', '徹' => '철',
+    '尖' => '첨', '添' => '첨',
+    '妾' => '첩',
+    '靑' => '청', '淸' => '청', '晴' => '청', '請' => '청', '聽' => '청', '廳' => '청',
+    '體' => '체', '替' => '체', '滯' => '체', '逮' => '체', '遞' => '체',
+    '草' => '초', '初' => '초', '招' => '초', '肖' => '초', '超' => '초', '抄' => '초', '礎' => '초', '秒' => '초',
+    '促' => '촉', '燭' => '촉', '觸' => '촉',
+    '村' => '촌', '寸' => '촌',
+    '總' => '총', '銃' => '총', '聰' => '총',
+    '最' => '최', '催' => '최',
+    '秋' => '추', '追' => '추', '推' => '추', '抽' => '추', '醜' => '추',
+    '祝' => '축', '築' => '축', '丑' => '축', '蓄' => '축', '畜' => '축', '逐' => '축', '縮' => '축',
+    '春' => '춘',
+    '出' => '출',
+    '忠' => '충', '蟲' => '충', '充' => '충', '衝' => '충',
+    '取' => '취', '就' => '취', '吹' => '취', '臭' => '취', '醉' => '취', '趣' => '취',
+    '測' => '측', '側' => '측',
+    '層' => '층',
+    '治' => '치', '致' => '치', '齒' => '치', '置' => '치', '値' => '치', '恥' => '치',
+    '則' => '칙',
+    '親' => '친',
+    '七' => '칠', '漆' => '칠',
+    '針' => '침', '侵' => '침', '浸' => '침', '寢' => '침', '沈' => '침', '枕' => '침',
+    '稱' => '칭',
+    '快' => '쾌',
+    '打' => '타', '他' => '타', '妥' => '타', '墮' => '타',
+    '濁' => '탁', '托' => '탁', '濯' => '탁', '卓' => '탁',
+    '炭' => '탄', '歎' => '탄', '彈' => '탄', '誕' => '탄',
+    '脫' => '탈', '奪' => '탈',
+    '探' => '탐', '貪' => '탐',
+    '塔' => '탑',
+    '湯' => '탕',
+    '太' => '태', '泰' => '태', '態' => '태', '怠' => '태', '殆' => '태',
+    '宅' => '택', '澤' => '택', '擇' => '택',
+    '土' => '토', '討' => '토', '吐' => '토',
+    '通' => '통', '統' => '통', '痛' => '통',
+    '退' => '퇴',
+    '投' => '투', '透' => '투', '鬪' => '투',
+    '特' => '특',
+    '波' => '파', '破' => '파', '派' => '파', '播' => '파', '罷' => '파', '頗' => '파', '把' => '파',
+    '判' => '판', '板' => '판', '販' => '판', '版' => '판',
+    '八' => '팔',
+    '敗' => '패', '貝' => '패',
+    '便' => '편', '片' => '편', '篇' => '편', '編' => '편', '遍' => '편', '偏' => '편',
+    '平' => '평', '評' => '평',
+    '閉' => '폐', '肺' => '폐', '廢' => '폐', '弊' => '폐', '蔽' => '폐', '幣' => '폐',
+    '布' => '포', '抱' => '포', '包' => '포', '胞' => '포', '飽' => '포', '浦' => '포', '捕' => '포',
+    '暴' => '폭', '爆' => '폭', '幅' => '폭',
+    '表' => '표', '票' => '표', '標' => '표', '漂' => '표',
+    '品' => '품',
+    '風' => '풍', '豊' => '풍', '豐' => '풍',
+    '皮' => '피', '彼' => '피', '疲' => '피', '被' => '피', '避' => '피',
+    '必' => '필', '筆' => '필', '匹' => '필', '畢' => '필',
+    '下' => '하', '夏' => '하', '河' => '하', '賀' => '하', '何' => '하', '荷' => '하',
+    '學' => '학', '鶴' => '학',
+    '韓' => '한', '漢' => '한', '寒' => '한', '限' => '한', '恨' => '한', '閑' => '한', '旱' => '한', '汗' => '한',
+    '割' => '할',
+    '含' => '함', '咸' => '함', '陷' => '함',
+    '合' => '합',
+    '港' => '항', '航' => '항', '恒' => '항', '巷' => '항', '項' => '항', '抗' => '항',
+    '海' => '해', '害' => '해', '解' => '해', '亥' => '해', '奚' => '해', '該' => '해',
+    '核' => '핵',
+    '行' => '행', '幸' => '행',
+    '向' => '향', '香' => '향', '鄕' => '향', '響' => '향', '享' => '향',
+    '許' => '허', '虛' => '허',
+    '軒' => '헌', '憲' => '헌', '獻' => '헌',
+    '險' => '험', '驗' => '험',
+    '革' => '혁',
+    '現' => '현', '賢' => '현', '玄' => '현', '絃' => '현', '縣' => '현', '懸' => '현', '顯' => '현',
+    '血' => '혈', '穴' => '혈',
+    '嫌' => '혐',
+    '協' => '협', '脅' => '협',
+    '兄' => '형', '形' => '형', '刑' => '형', '亨' => '형', '螢' => '형', '衡' => '형',
+    '惠' => '혜', '慧' => '혜', '兮' => '혜',
+    '好' => '호', '號' => '호', '湖' => '호', '戶' => '호', '呼' => '호', '虎' => '호', '乎' => '호', '互' => '호', '胡' => '호', '浩' => '호', '毫' => '호', '豪' => '호', '護' => '호',
+    '或' => '혹', '惑' => '혹',
+    '婚' => '혼', '混' => '혼', '昏' => '혼', '魂' => '혼',
+    '忽' => '홀',
+    '紅' => '홍', '洪' => '홍', '弘' => '홍', '鴻' => '홍',
+    '火' => '화', '花' => '화', '化' => '화', '話' => '화', '和' => '화', '畫' => '화', '華' => '화', '貨' => '화', '禾' => '화', '禍' => '화',
+    '確' => '확', '穫' => '확', '擴' => '확',
+    '歡' => '환', '患' => '환', '丸' => '환', '換' => '환', '環' => '환', '還' => '환',
+    '活' => '활',
+    '黃' => '황', '皇' => '황', '況' => '황', '荒' => '황',
+    '回' => '회', '會' => '회', '悔' => '회', '懷' => '회',
+    '獲' => '획', '劃' => '획',
+    '橫' => '횡',
+    '孝' => '효', '效' => '효', '曉' => '효',
+    '後' => '후', '厚' => '후', '侯' => '후', '候' => '후',
+    '訓' => '훈',
+    '毁' => '훼',
+    '揮' => '휘', '輝' => '휘',
+    '休' => '휴', '携' => '휴',
+    '凶' => '흉', '胸' => '흉',
+    '黑' => '흑',
+    '吸' => '흡',
+    '興' => '흥',
+    '希' => '희', '喜' => '희', '稀' => '희', '戲' => '희', '噫' => '희',
+};
+
+/// 한자인지 여부 (CJK 통합 한자, 확장 A, 호환 한자)
+pub fn is_hanja(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}')
+}
+
+/// 단어 첫머리의 ㄹ, ㄴ을 두음 법칙에 따라 바꾼다. (녀 → 여, 리 → 이, 래 → 내)
+fn apply_initial_sound_rule(syllable: char) -> char {
+    const NIEUN: u32 = 2;
+    const RIEUL: u32 = 5;
+    const IEUNG: u32 = 11;
+    let code = syllable as u32 - 0xAC00;
+    let cho = code / 588;
+    let jung = code % 588 / 28;
+    // ㅑ, ㅕ, ㅖ, ㅛ, ㅠ, ㅣ
+    let is_y_vowel = matches!(jung, 2 | 6 | 7 | 12 | 17 | 20);
+    let new_cho = match cho {
+        RIEUL if is_y_vowel => IEUNG,
+        RIEUL => NIEUN,
+        // 녜는 두음 법칙을 적용하지 않는다.
+        NIEUN if is_y_vowel && jung != 7 => IEUNG,
+        _ => return syllable,
+    };
+    char::from_u32(0xAC00 + new_cho * 588 + code % 588).unwrap_or(syllable)
+}
+
+/// 한자 낱말의 음을 한글로 돌려준다. 첫 글자에는 두음 법칙을 적용한다.
+pub fn hanja_reading(hanja: &str) -> Result<String, String> {
+    let mut reading = String::new();
+    for (idx, c) in hanja.chars().enumerate() {
+        // 호환 한자는 통합 한자로 바꾸어 찾는다.
+        let c = c.nfc().next().unwrap_or(c);
+        let syllable = *HANJA_READING_MAP
+            .get(&c)
+            .ok_or_else(|| format!("Unknown Hanja reading: {c}"))?;
+        reading.push(if idx == 0 {
+            apply_initial_sound_rule(syllable)
+        } else {
+            syllable
+        });
+    }
+    Ok(reading)
+}
+
+/// 단어 안에서 처음 나오는 한자 구간의 바이트 범위
+pub fn find_hanja_span(word: &str) -> Option<(usize, usize)> {
+    let start = word.find(is_hanja)?;
+    let end = word[start..]
+        .find(|c| !is_hanja(c))
+        .map_or(word.len(), |len| start + len);
+    Some((start, end))
+}
+
+/// 점역하기 전에 한자 처리 방법에 따라 한자를 지우거나 한글 음으로 바꾼다.
+///
+/// `대한민국(大韓民國)`처럼 한글 뒤 괄호 안에 한자를 함께 적은 경우, 한자를 지울 때와
+/// 한자의 음이 앞의 한글과 같을 때에는 괄호까지 지운다. 한자표로 적는 경우는 점역하면서 처리한다.
+pub fn apply_policy(text: &str, policy: HanjaPolicy) -> Result<Cow<'_, str>, String> {
+    if policy == HanjaPolicy::Indicator || !text.chars().any(is_hanja) {
+        return Ok(Cow::Borrowed(text));
+    }
+    if policy == HanjaPolicy::Error {
+        return Err("Hanja is not allowed".to_string());
+    }
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < chars.len() {
+        if !is_hanja(chars[idx]) {
+            result.push(chars[idx]);
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        while idx < chars.len() && is_hanja(chars[idx]) {
+            idx += 1;
+        }
+        let run = chars[start..idx].iter().collect::<String>();
+        let parenthesized = result.ends_with('(') && chars.get(idx) == Some(&')');
+        let reading = match policy {
+            HanjaPolicy::Reading => Some(hanja_reading(&run)?),
+            _ => None,
+        };
+        let redundant = match &reading {
+            Some(reading) => {
+                parenthesized && result[..result.len() - 1].ends_with(reading.as_str())
+            }
+            None => parenthesized,
+        };
+        if redundant {
+            result.pop();
+            idx += 1;
+        } else if let Some(reading) = reading {
+            result.push_str(&reading);
+        }
+    }
+    Ok(Cow::Owned(result))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hanja_reading() {
+        assert_eq!(hanja_reading("大韓民國").unwrap(), "대한민국");
+        assert_eq!(hanja_reading("訓民正音").unwrap(), "훈민정음");
+        // 두음 법칙
+        assert_eq!(hanja_reading("女子").unwrap(), "여자");
+        assert_eq!(hanja_reading("男女").unwrap(), "남녀");
+        assert_eq!(hanja_reading("老人").unwrap(), "노인");
+        assert_eq!(hanja_reading("理由").unwrap(), "이유");
+        assert!(hanja_reading("龘").is_err());
+        // 교육용 기초 한자
+        assert!(HANJA_READING_MAP.len() >= 1800);
+        assert_eq!(hanja_reading("沙漠").unwrap(), "사막");
+        assert_eq!(hanja_reading("王陵").unwrap(), "왕릉");
+        assert_eq!(hanja_reading("陵墓").unwrap(), "능묘");
+        assert_eq!(hanja_reading("憂鬱").unwrap(), "우울");
+    }
+
+    #[test]
+    fn test_find_hanja_span() {
+        assert_eq!(find_hanja_span("대한민국(大韓民國)"), Some((13, 25)));
+        assert_eq!(find_hanja_span("한글"), None);
+    }
+
+    #[test]
+    fn test_apply_policy() {
+        let text = "대한민국(大韓民國)의 國民";
+        assert!(apply_policy(text, HanjaPolicy::Error).is_err());
+        assert_eq!(
+            apply_policy(text, HanjaPolicy::Drop).unwrap(),
+            "대한민국의 "
+        );
+        assert_eq!(
+            apply_policy(text, HanjaPolicy::Reading).unwrap(),
+            "대한민국의 국민"
+        );
+        assert_eq!(
+            apply_policy("나라(國家)", HanjaPolicy::Reading).unwrap(),
+            "나라(국가)"
+        );
+        assert!(matches!(
+            apply_policy(text, HanjaPolicy::Indicator).unwrap(),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            apply_policy("한글", HanjaPolicy::Error).unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -0,0 +1,493 @@
+use crate::{
+    document::{Block, BlockBuilder, DocumentOptions, render_blocks},
+    emphasis::{BOLD_CLOSE, BOLD_OPEN, EMPHASIS_CLOSE, EMPHASIS_OPEN},
+};
+
+/// 내용을 점역하지 않는 요소
+const SKIPPED_ELEMENTS: [&str; 5] = ["script", "style", "template", "title", "head"];
+
+/// 앞뒤에서 문단을 나누는 요소
+const BLOCK_ELEMENTS: [&str; 16] = [
+    "html",
+    "body",
+    "div",
+    "section",
+    "article",
+    "header",
+    "footer",
+    "main",
+    "nav",
+    "aside",
+    "figure",
+    "figcaption",
+    "address",
+    "caption",
+    "dl",
+    "dd",
+];
+
+/// HTML 조각
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event<'a> {
+    /// 여는 태그. 이름은 소문자로 바꾸고, 속성은 그대로 둔다.
+    Start(String, &'a str),
+    /// 닫는 태그
+    End(String),
+    /// 문자 참조를 풀어 쓴 글
+    Text(String),
+}
+
+/// 이름 있는 문자 참조
+static ENTITIES: phf::Map<&'static str, char> = phf::phf_map! {
+    "amp" => '&',
+    "lt" => '<',
+    "gt" => '>',
+    "quot" => '"',
+    "apos" => '\'',
+    "nbsp" => '\u{00A0}',
+    "middot" => '·',
+    "hellip" => '…',
+    "ndash" => '–',
+    "mdash" => '—',
+    "lsquo" => '‘',
+    "rsquo" => '’',
+    "ldquo" => '“',
+    "rdquo" => '”',
+    "laquo" => '«',
+    "raquo" => '»',
+    "bull" => '•',
+    "times" => '×',
+    "divide" => '÷',
+    "deg" => '°',
+    "copy" => '©',
+};
+
+/// `&amp;`, `&#44032;`, `&#xAC00;` 같은 문자 참조를 푼다. 알 수 없는 참조는 그대로 둔다.
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        result.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let decoded = rest[1..].find(';').and_then(|end| {
+            let name = &rest[1..=end];
+            let c = match name.strip_prefix('#') {
+                Some(hex) if hex.starts_with(['x', 'X']) => u32::from_str_radix(&hex[1..], 16)
+                    .ok()
+                    .and_then(char::from_u32),
+                Some(dec) => dec.parse().ok().and_then(char::from_u32),
+                None => ENTITIES.get(name).copied(),
+            };
+            c.map(|c| (c, end + 2))
+        });
+        match decoded {
+            Some((c, len)) => {
+                result.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// `<`, `</`와 이름, `>`로 된 태그의 이름과 속성, 태그 전체의 길이
+fn parse_tag(rest: &str) -> Option<(&str, &str, usize)> {
+    let body = rest.strip_prefix("</").or_else(|| rest.strip_prefix('<'))?;
+    if !body.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let name_len = body
+        .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+        .unwrap_or(body.len());
+    // 따옴표 안의 `>`는 태그의 끝이 아니다.
+    let mut quote = None;
+    let end = body.char_indices().skip(name_len).find_map(|(idx, c)| {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(idx),
+            _ => {}
+        }
+        None
+    })?;
+    let attributes = body[name_len..end].trim_end_matches('/');
+    Some((
+        &body[..name_len],
+        attributes,
+        rest.len() - body.len() + end + 1,
+    ))
+}
+
+/// 대소문자를 가리지 않고 `</name`을 찾아 그 태그 뒤의 위치를 돌려준다.
+fn skip_element(html: &str, name: &str) -> usize {
+    let lower = html.to_ascii_lowercase();
+    let close = format!("</{name}");
+    lower
+        .find(&close)
+        .map_or(html.len(), |idx| match html[idx..].find('>') {
+            Some(end) => idx + end + 1,
+            None => html.len(),
+        })
+}
+
+/// HTML을 태그와 글로 나눈다. 주석, 문서 형식 선언, 스크립트와 스타일은 건너뛴다.
+fn events(html: &str) -> Vec<Event<'_>> {
+    let mut events = Vec::new();
+    let mut pos = 0;
+    while pos < html.len() {
+        let rest = &html[pos..];
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            pos += 4 + comment.find("-->").map_or(comment.len(), |end| end + 3);
+            continue;
+        }
+        if rest.starts_with("<!") || rest.starts_with("<?") {
+            pos += rest.find('>').map_or(rest.len(), |end| end + 1);
+            continue;
+        }
+        if let Some((name, attributes, len)) = parse_tag(rest) {
+            let name = name.to_ascii_lowercase();
+            pos += len;
+            if rest.starts_with("</") {
+                events.push(Event::End(name));
+            } else if SKIPPED_ELEMENTS.contains(&name.as_str()) {
+                pos += skip_element(&html[pos..], &name);
+            } else {
+                events.push(Event::Start(name, attributes));
+            }
+            continue;
+        }
+        // 태그가 아닌 `<`는 글로 본다.
+        let skip = usize::from(rest.starts_with('<'));
+        let len = rest[skip..].find('<').map_or(rest.len(), |idx| idx + skip);
+        events.push(Event::Text(decode_entities(&rest[..len])));
+        pos += len;
+    }
+    events
+}
+
+/// 속성 값. 따옴표 없이 쓴 값과 값 없는 속성도 읽는다.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let key_len = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = &rest[..key_len];
+        rest = rest[key_len..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (text, len) = match value.chars().next() {
+                    Some(q @ ('"' | '\'')) => {
+                        let end = value[1..].find(q).map_or(value.len(), |end| end + 1);
+                        (&value[1..end], (end + 1).min(value.len()))
+                    }
+                    _ => {
+                        let end = value
+                            .find(|c: char| c.is_ascii_whitespace())
+                            .unwrap_or(value.len());
+                        (&value[..end], end)
+                    }
+                };
+                rest = value[len..].trim_start();
+                text
+            }
+            None => "",
+        };
+        if key.eq_ignore_ascii_case(name) {
+            return Some(decode_entities(value));
+        }
+    }
+    None
+}
+
+/// EPUB의 `epub:type="pagebreak"`나 `role="doc-pagebreak"`로 표시한 묵자 쪽 바뀜의 쪽 번호.
+/// `title`, `aria-label`, `id`(`page12`처럼 쓴 것) 순서로 번호를 찾는다.
+fn page_break(attributes: &str) -> Option<String> {
+    let is_page_break = attribute(attributes, "epub:type")
+        .is_some_and(|types| types.split_whitespace().any(|t| t == "pagebreak"))
+        || attribute(attributes, "role").is_some_and(|role| role == "doc-pagebreak");
+    if !is_page_break {
+        return None;
+    }
+    attribute(attributes, "title")
+        .or_else(|| attribute(attributes, "aria-label"))
+        .or_else(|| {
+            attribute(attributes, "id").map(|id| {
+                id.trim_start_matches(|c: char| !c.is_ascii_digit())
+                    .to_string()
+            })
+        })
+        .map(|page| page.trim().to_string())
+        .filter(|page| !page.is_empty())
+}
+
+/// HTML의 글을 문서 블록으로 모으는 중의 상태
+#[derive(Default)]
+struct Converter {
+    builder: BlockBuilder,
+    /// `<pre>` 안에서는 공백을 그대로 둔다.
+    pre_depth: usize,
+}
+
+impl Converter {
+    /// 글을 더한다. `<pre>` 밖에서는 이어진 공백을 띄어쓰기 하나로 줄인다.
+    fn push_text(&mut self, text: &str) {
+        if self.pre_depth > 0 {
+            self.builder.push_str(text);
+            return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        let mut space = self
+            .builder
+            .text()
+            .ends_with(|c: char| c.is_whitespace() || matches!(c, EMPHASIS_OPEN | BOLD_OPEN))
+            || self.builder.text().is_empty();
+        for c in text.chars() {
+            if c.is_ascii_whitespace() {
+                if !space {
+                    collapsed.push(' ');
+                }
+                space = true;
+            } else {
+                collapsed.push(c);
+                space = false;
+            }
+        }
+        self.builder.push_str(&collapsed);
+    }
+
+    fn start(&mut self, name: &str, attributes: &str) {
+        if let Some(page) = page_break(attributes) {
+            self.builder.page_break(page);
+            return;
+        }
+        match name {
+            "p" => self.builder.start_paragraph(),
+            "br" => self.builder.push_str("\n"),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.builder.start_heading(),
+            "blockquote" => self.builder.start_quote(),
+            "pre" => {
+                self.builder.start_code();
+                self.pre_depth += 1;
+            }
+            "ul" => self.builder.start_list(None),
+            "ol" => {
+                let start = attribute(attributes, "start").and_then(|start| start.parse().ok());
+                self.builder.start_list(Some(start.unwrap_or(1)));
+            }
+            "li" | "dt" => self.builder.start_item(),
+            "tr" => self.builder.start_row(),
+            "td" | "th" => self.builder.start_cell(),
+            "hr" => self.builder.rule(),
+            "img" => {
+                if let Some(alt) = attribute(attributes, "alt") {
+                    self.push_text(&alt);
+                }
+            }
+            "em" | "i" | "u" => self.builder.push_mark(EMPHASIS_OPEN),
+            "strong" | "b" => self.builder.push_mark(BOLD_OPEN),
+            "code" if self.pre_depth == 0 => self.builder.push_str("`"),
+            _ if BLOCK_ELEMENTS.contains(&name) => self.builder.start_paragraph(),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        match name {
+            "p" => self.builder.flush(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.builder.end_heading(),
+            "blockquote" => self.builder.end_quote(),
+            "pre" => {
+                self.pre_depth = self.pre_depth.saturating_sub(1);
+                self.builder.end_code();
+            }
+            "ul" | "ol" => self.builder.end_list(),
+            "li" | "dt" => self.builder.end_item(),
+            "tr" => self.builder.end_row(),
+            "td" | "th" => self.builder.end_cell(),
+            "em" | "i" | "u" => self.builder.push_mark(EMPHASIS_CLOSE),
+            "strong" | "b" => self.builder.push_mark(BOLD_CLOSE),
+            "code" if self.pre_depth == 0 => self.builder.push_str("`"),
+            _ if BLOCK_ELEMENTS.contains(&name) => self.builder.start_paragraph(),
+            _ => {}
+        }
+    }
+}
+
+/// HTML을 문서 블록으로 바꾼다.
+///
+/// 스크립트와 스타일은 지우고, `<p>`, `<h1>`–`<h6>`, `<ul>`, `<ol>`, `<blockquote>`, `<pre>`,
+/// `<table>`은 같은 블록으로 바꾼다. `<br>`에서는 줄을 바꾸고, `<em>`과 `<u>`는 제56항 드러냄표로,
+/// `<strong>`은 굵은 글자 표로 강조하며, `<img>`는 대체 글(`alt`)로 적는다. EPUB의 쪽 바뀜 표시는
+/// [`Block::PageBreak`]로 바꾼다.
+pub fn parse_html(html: &str) -> Vec<Block> {
+    let mut converter = Converter::default();
+    for event in events(html) {
+        match event {
+            Event::Start(name, attributes) => converter.start(&name, attributes),
+            Event::End(name) => converter.end(&name),
+            Event::Text(text) => converter.push_text(&text),
+        }
+    }
+    converter.builder.finish()
+}
+
+/// HTML을 점자 편집 규칙에 맞게 줄마다 나누어 점역한다.
+pub fn encode_html(html: &str, options: &DocumentOptions) -> Result<Vec<Vec<u8>>, String> {
+    render_blocks(&parse_html(html), options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph(text.to_string())
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(decode_entities("a&amp;b &lt;c&gt;"), "a&b <c>");
+        assert_eq!(decode_entities("&#44032;&#xAC01;&nbsp;"), "가각\u{00A0}");
+        assert_eq!(decode_entities("AT&T &unknown; &"), "AT&T &unknown; &");
+        assert_eq!(
+            decode_entities(&crate::utils::escape_html("<\"&\">")),
+            "<\"&\">"
+        );
+    }
+
+    #[test]
+    fn test_attribute() {
+        assert_eq!(
+            attribute(r#" src="a.png" ALT='점자 책' hidden"#, "alt"),
+            Some("점자 책".to_string())
+        );
+        assert_eq!(
+            attribute(" start=3 reversed", "start"),
+            Some("3".to_string())
+        );
+        assert_eq!(attribute(" hidden", "hidden"), Some(String::new()));
+        assert_eq!(attribute(" src=a.png", "alt"), None);
+    }
+
+    #[test]
+    fn test_events_skip_scripts_and_comments() {
+        assert_eq!(
+            events(
+                "<!DOCTYPE html><P class=\"a>b\">가<!-- 주석 --><script>if (a < b) {}</SCRIPT>나 < 다</p>"
+            ),
+            vec![
+                Event::Start("p".to_string(), " class=\"a>b\""),
+                Event::Text("가".to_string()),
+                Event::Text("나 ".to_string()),
+                Event::Text("< 다".to_string()),
+                Event::End("p".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_structure() {
+        let html = "<html><head><title>제목</title><style>p { color: red }</style></head>
+            <body><h1>점자</h1><p>첫   문단<br>
+            둘째 줄</p><p>둘째 문단<div>나눔</div></body></html>";
+        assert_eq!(
+            parse_html(html),
+            vec![
+                Block::Heading("점자".to_string()),
+                paragraph("첫 문단\n둘째 줄"),
+                paragraph("둘째 문단"),
+                paragraph("나눔"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lists_and_tables() {
+        let html = "<ul><li>사과<ol start=\"3\"><li>부사<li>홍로</ol><li><p>배</p></ul>
+            <table><tr><th>이름</th><th>값</th></tr><tr><td>가</td><td>1</td></tr>
+            <tr><td><p>나</p><p>다</p></td><td><div>2</div></td></tr></table>";
+        assert_eq!(
+            parse_html(html),
+            vec![
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "사과".to_string()
+                },
+                Block::ListItem {
+                    depth: 1,
+                    marker: Some("3.".to_string()),
+                    text: "부사".to_string()
+                },
+                Block::ListItem {
+                    depth: 1,
+                    marker: Some("4.".to_string()),
+                    text: "홍로".to_string()
+                },
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "배".to_string()
+                },
+                Block::TableRow(vec!["이름".to_string(), "값".to_string()]),
+                Block::TableRow(vec!["가".to_string(), "1".to_string()]),
+                Block::TableRow(vec!["나 다".to_string(), "2".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_markup() {
+        assert_eq!(
+            parse_html(
+                "<p>서울은 <strong>대한민국 </strong>의 <em>수도</em>이다. <code>ls</code>를 \
+                 <img src=\"a.png\" alt=\"그림\"> <u>보라</u>.</p><pre>a  = 1\n  b</pre>"
+            ),
+            vec![
+                paragraph(
+                    "서울은 \u{E002}대한민국\u{E003} 의 \u{E000}수도\u{E001}이다. `ls`를 그림 \u{E000}보라\u{E001}."
+                ),
+                Block::Code("a  = 1\n  b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_page_breaks() {
+        let html = "<span epub:type=\"pagebreak\" title=\"1\"/><p>가<span role=\"doc-pagebreak\" \
+                    id=\"page2\"></span> 나</p><div epub:type=\"pagebreak\" aria-label=\" 3 \"></div>";
+        assert_eq!(
+            parse_html(html),
+            vec![
+                Block::PageBreak("1".to_string()),
+                paragraph("가 나"),
+                Block::PageBreak("2".to_string()),
+                Block::PageBreak("3".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_html() {
+        let options = DocumentOptions {
+            width: 10,
+            ..Default::default()
+        };
+        let lines = encode_html("<p>상 상<br>상 상 상 상</p>", &options).unwrap();
+        assert_eq!(
+            lines
+                .iter()
+                .map(|line| line.iter().map(|c| encode_unicode(*c)).collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["⠀⠀⠇⠶⠀⠇⠶", "⠇⠶⠀⠇⠶⠀⠇⠶", "⠇⠶"]
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    EncodeOptions, encode_with_alignment,
+    unicode::{NEWLINE, encode_unicode},
+    utils::escape_html,
+};
+
+/// 대역 출력에서 위아래로 맞추어 적는 묵자 단어와 그 점자
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterlinearWord {
+    /// 입력의 단어
+    pub print: String,
+    /// 단어의 점역 결과
+    pub cells: Vec<u8>,
+}
+
+impl InterlinearWord {
+    /// 묵자와 점자 가운데 긴 쪽에 맞춘 칸 수
+    fn width(&self) -> usize {
+        display_width(&self.print).max(self.cells.len())
+    }
+}
+
+/// 고정폭 글꼴에서 문자열이 차지하는 칸 수. 한글과 한자, 전각 문자는 두 칸으로 센다.
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x200B..=0x200F | 0x0300..=0x036F | 0x1160..=0x11FF | 0xFE00..=0xFE0F => 0,
+            0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA960..=0xA97F
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// `width`칸보다 긴 단어를 줄마다 `width`칸씩 나눈다. 묵자도 같은 칸 수를 넘지 않도록 나누어
+/// 점자와 함께 적는다.
+fn split_word(word: InterlinearWord, width: usize) -> Vec<InterlinearWord> {
+    if word.width() <= width {
+        return vec![word];
+    }
+    let width = width.max(1);
+    let mut pieces = Vec::new();
+    let mut cells = word.cells.as_slice();
+    let mut print = word.print.as_str();
+    while !cells.is_empty() || !print.is_empty() {
+        let (head, tail) = cells.split_at(cells.len().min(width));
+        let mut end = 0;
+        let mut used = 0;
+        for (idx, c) in print.char_indices() {
+            let next = idx + c.len_utf8();
+            let char_width = display_width(&print[idx..next]);
+            if end > 0 && used + char_width > width {
+                break;
+            }
+            used += char_width;
+            end = next;
+        }
+        pieces.push(InterlinearWord {
+            print: print[..end].to_string(),
+            cells: head.to_vec(),
+        });
+        cells = tail;
+        print = &print[end..];
+    }
+    pieces
+}
+
+/// 입력을 점역해 단어마다 묵자와 점자를 짝짓고, 한 줄이 `width`칸을 넘지 않도록 나눈다.
+///
+/// 단어는 묵자와 점자 가운데 긴 쪽만큼 칸을 차지하고, 단어 사이에는 한 칸을 둔다.
+/// 입력의 줄바꿈에서는 줄을 바꾸며, `width`보다 긴 단어는 여러 줄에 나누어 적는다.
+pub fn interlinear_lines(
+    text: &str,
+    options: &EncodeOptions,
+    width: usize,
+) -> Result<Vec<Vec<InterlinearWord>>, String> {
+    let (cells, alignment) = encode_with_alignment(text, options)?;
+    let mut lines: Vec<Vec<InterlinearWord>> = vec![Vec::new()];
+    let mut line_width = 0;
+    let mut prev_end = 0;
+    for word in alignment {
+        // 앞 단어와 이 단어 사이의 줄바꿈만큼 줄을 바꾼다.
+        let newlines = cells[prev_end.min(word.cells.start)..word.cells.start]
+            .iter()
+            .filter(|c| **c == NEWLINE)
+            .count();
+        for _ in 0..newlines {
+            lines.push(Vec::new());
+            line_width = 0;
+        }
+        prev_end = word.cells.end;
+
+        let word = InterlinearWord {
+            print: text[word.source].to_string(),
+            cells: cells[word.cells].to_vec(),
+        };
+        for word in split_word(word, width) {
+            let current = lines.last_mut().unwrap();
+            let needed = if current.is_empty() {
+                word.width()
+            } else {
+                line_width + 1 + word.width()
+            };
+            if !current.is_empty() && needed > width {
+                line_width = word.width();
+                lines.push(vec![word]);
+            } else {
+                line_width = needed;
+                current.push(word);
+            }
+        }
+    }
+    if lines.last().is_some_and(Vec::is_empty) {
+        lines.pop();
+    }
+    Ok(lines)
+}
+
+/// 묵자 줄 아래에 점자 줄을 적고, 두 줄 묶음 사이에는 빈 줄을 둔다.
+pub fn render_interlinear_text(lines: &[Vec<InterlinearWord>]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            let mut print = String::new();
+            let mut braille = String::new();
+            for (idx, word) in line.iter().enumerate() {
+                if idx > 0 {
+                    print.push(' ');
+                    braille.push(encode_unicode(0));
+                }
+                let width = word.width();
+                print.push_str(&word.print);
+                print.extend(std::iter::repeat_n(' ', width - display_width(&word.print)));
+                braille.extend(word.cells.iter().map(|c| encode_unicode(*c)));
+                braille.extend(std::iter::repeat_n(
+                    encode_unicode(0),
+                    width - word.cells.len(),
+                ));
+            }
+            format!(
+                "{}\n{}\n",
+                print.trim_end_matches(' '),
+                braille.trim_end_matches(encode_unicode(0))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 단어마다 묵자와 점자를 위아래로 쌓은 HTML 조각으로 적는다.
+///
+/// 줄은 `<div class="line">`, 단어는 `<span class="word">` 안의 `print`와 `braille`로 나누어
+/// 적으므로 스타일시트로 모양을 바꿀 수 있다.
+pub fn render_interlinear_html(lines: &[Vec<InterlinearWord>]) -> String {
+    let mut html = String::from("<div class=\"interlinear\">\n");
+    for line in lines {
+        html.push_str("<div class=\"line\">");
+        for word in line {
+            let braille: String = word.cells.iter().map(|c| encode_unicode(*c)).collect();
+            html.push_str(&format!(
+                "<span class=\"word\"><span class=\"print\">{}</span><span class=\"braille\">{}</span></span>",
+                escape_html(&word.print),
+                braille
+            ));
+        }
+        html.push_str("</div>\n");
+    }
+    html.push_str("</div>\n");
+    html
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("상"), 2);
+        assert_eq!(display_width("ab"), 2);
+        assert_eq!(display_width("國"), 2);
+        assert_eq!(display_width("Ａ"), 2);
+        assert_eq!(display_width("e\u{0301}"), 1);
+    }
+
+    #[test]
+    fn test_interlinear_lines() {
+        let lines = interlinear_lines("상 상 상\n나", &EncodeOptions::default(), 7).unwrap();
+        let words = |line: &[InterlinearWord]| {
+            line.iter()
+                .map(|word| word.print.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lines.len(), 3);
+        assert_eq!(words(&lines[0]), ["상", "상"]);
+        assert_eq!(words(&lines[1]), ["상"]);
+        assert_eq!(words(&lines[2]), ["나"]);
+        assert_eq!(lines[0][0].cells, vec![7, 54]);
+        assert!(
+            interlinear_lines("", &EncodeOptions::default(), 8)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_interlinear_lines_split_long_word() {
+        let lines = interlinear_lines("가 abcdefgh", &EncodeOptions::default(), 4).unwrap();
+        let pieces: Vec<(&str, usize)> = lines
+            .iter()
+            .flatten()
+            .map(|word| (word.print.as_str(), word.cells.len()))
+            .collect();
+        assert_eq!(pieces, [("가", 1), ("abcd", 4), ("efgh", 4)]);
+        assert!(lines.iter().all(|line| line.len() == 1));
+    }
+
+    #[test]
+    fn test_render_interlinear_text() {
+        let lines = interlinear_lines("상이다. abc", &EncodeOptions::default(), 40).unwrap();
+        assert_eq!(
+            render_interlinear_text(&lines),
+            "상이다. abc\n⠇⠶⠕⠊⠲⠀⠀⠀⠴⠁⠃⠉\n"
+        );
+        let lines = interlinear_lines("상\n\n상", &EncodeOptions::default(), 40).unwrap();
+        assert_eq!(render_interlinear_text(&lines), "상\n⠇⠶\n\n\n\n\n상\n⠇⠶\n");
+    }
+
+    #[test]
+    fn test_render_interlinear_html() {
+        let lines = interlinear_lines("<상>", &EncodeOptions::default(), 40).unwrap();
+        let html = render_interlinear_html(&lines);
+        assert!(html.starts_with("<div class=\"interlinear\">\n<div class=\"line\">"));
+        assert!(html.contains("<span class=\"print\">&lt;상&gt;</span>"));
+        assert!(html.ends_with("</div>\n</div>\n"));
+    }
+}
@@ -0,0 +1,236 @@
+use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::unicode::decode_unicode;
+
+/// 제31항 국어 문장 안의 외국 글자 구간 앞에는 로마자표와 같은 ⠴을, 뒤에는 종료표 ⠲을 적는다.
+pub const KANA_START: u8 = decode_unicode('⠴');
+pub const KANA_END: u8 = decode_unicode('⠲');
+
+/// 濁音符
+const DAKUON: u8 = decode_unicode('⠐');
+/// 半濁音符
+const HANDAKUON: u8 = decode_unicode('⠠');
+/// 拗音符
+const YOON: u8 = decode_unicode('⠈');
+/// 拗濁音符
+const YOON_DAKUON: u8 = decode_unicode('⠘');
+/// 拗半濁音符
+const YOON_HANDAKUON: u8 = decode_unicode('⠨');
+
+const DAKUTEN: char = '\u{3099}';
+const HANDAKUTEN: char = '\u{309A}';
+
+/// 일본 점자 가나 (청음)
+static KANA_MAP: phf::Map<char, u8> = phf_map! {
+    'あ' => decode_unicode('⠁'),
+    'い' => decode_unicode('⠃'),
+    'う' => decode_unicode('⠉'),
+    'え' => decode_unicode('⠋'),
+    'お' => decode_unicode('⠊'),
+    'か' => decode_unicode('⠡'),
+    'き' => decode_unicode('⠣'),
+    'く' => decode_unicode('⠩'),
+    'け' => decode_unicode('⠫'),
+    'こ' => decode_unicode('⠪'),
+    'さ' => decode_unicode('⠱'),
+    'し' => decode_unicode('⠳'),
+    'す' => decode_unicode('⠹'),
+    'せ' => decode_unicode('⠻'),
+    'そ' => decode_unicode('⠺'),
+    'た' => decode_unicode('⠕'),
+    'ち' => decode_unicode('⠗'),
+    'つ' => decode_unicode('⠝'),
+    'て' => decode_unicode('⠟'),
+    'と' => decode_unicode('⠞'),
+    'な' => decode_unicode('⠅'),
+    'に' => decode_unicode('⠇'),
+    'ぬ' => decode_unicode('⠍'),
+    'ね' => decode_unicode('⠏'),
+    'の' => decode_unicode('⠎'),
+    'は' => decode_unicode('⠥'),
+    'ひ' => decode_unicode('⠧'),
+    'ふ' => decode_unicode('⠭'),
+    'へ' => decode_unicode('⠯'),
+    'ほ' => decode_unicode('⠮'),
+    'ま' => decode_unicode('⠵'),
+    'み' => decode_unicode('⠷'),
+    'む' => decode_unicode('⠽'),
+    'め' => decode_unicode('⠿'),
+    'も' => decode_unicode('⠾'),
+    'や' => decode_unicode('⠌'),
+    'ゆ' => decode_unicode('⠬'),
+    'よ' => decode_unicode('⠜'),
+    'ら' => decode_unicode('⠑'),
+    'り' => decode_unicode('⠓'),
+    'る' => decode_unicode('⠙'),
+    'れ' => decode_unicode('⠛'),
+    'ろ' => decode_unicode('⠚'),
+    'わ' => decode_unicode('⠄'),
+    'ゐ' => decode_unicode('⠆'),
+    'ゑ' => decode_unicode('⠖'),
+    'を' => decode_unicode('⠔'),
+    'ん' => decode_unicode('⠴'),
+    // 促音符
+    'っ' => decode_unicode('⠂'),
+    // 長音符
+    'ー' => decode_unicode('⠒'),
+    // 句点, 読点
+    '。' => decode_unicode('⠲'),
+    '、' => decode_unicode('⠰'),
+};
+
+/// 拗音을 이루는 い段 글자와 그 행의 あ段, う段, お段 글자
+static YOON_ROW_MAP: phf::Map<char, [char; 3]> = phf_map! {
+    'き' => ['か', 'く', 'こ'],
+    'し' => ['さ', 'す', 'そ'],
+    'ち' => ['た', 'つ', 'と'],
+    'に' => ['な', 'ぬ', 'の'],
+    'ひ' => ['は', 'ふ', 'ほ'],
+    'み' => ['ま', 'む', 'も'],
+    'り' => ['ら', 'る', 'ろ'],
+};
+
+/// 히라가나, 가타카나, 장음 부호인지 확인한다.
+pub fn is_kana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{3096}' | '\u{30A1}'..='\u{30FA}' | 'ー')
+}
+
+/// 가타카나는 같은 점형으로 적으므로 히라가나로 바꾸고, 작은 글자는 큰 글자로 바꾼다.
+fn to_plain_hiragana(c: char) -> char {
+    let c = match c {
+        '\u{30A1}'..='\u{30F6}' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    };
+    match c {
+        'ぁ' => 'あ',
+        'ぃ' => 'い',
+        'ぅ' => 'う',
+        'ぇ' => 'え',
+        'ぉ' => 'お',
+        'ゃ' => 'や',
+        'ゅ' => 'ゆ',
+        'ょ' => 'よ',
+        'ゎ' => 'わ',
+        'ゕ' => 'か',
+        'ゖ' => 'け',
+        _ => c,
+    }
+}
+
+/// 글자를 청음 글자와 濁点(゛), 半濁点(゜)으로 나눈다.
+fn split_voicing(c: char) -> (char, Option<char>) {
+    let mut decomposed = c.nfd();
+    let base = decomposed.next().unwrap_or(c);
+    (base, decomposed.next())
+}
+
+/// 작은 ゃ, ゅ, ょ의 あ段, う段, お段 위치
+fn yoon_column(c: char) -> Option<usize> {
+    match c {
+        'ゃ' | 'ャ' => Some(0),
+        'ゅ' | 'ュ' => Some(1),
+        'ょ' | 'ョ' => Some(2),
+        _ => None,
+    }
+}
+
+fn encode_plain(c: char) -> Result<u8, String> {
+    KANA_MAP
+        .get(&to_plain_hiragana(c))
+        .copied()
+        .ok_or_else(|| "Invalid kana character".to_string())
+}
+
+/// `chars[start..]`에서 이어지는 가나를 일본 점자로 적는다.
+///
+/// 濁音, 半濁音은 濁音符 ⠐, 半濁音符 ⠠을, 拗音은 拗音符 ⠈(濁音 ⠘, 半濁音 ⠨)을 앞세워
+/// 그 행의 あ段, う段, お段 글자로 적는다. 점형과 읽은 글자 수를 돌려준다.
+pub fn encode_kana_run(chars: &[char], start: usize) -> Result<(Vec<u8>, usize), String> {
+    let mut result = Vec::new();
+    let mut idx = start;
+    while let Some(&c) = chars.get(idx) {
+        let is_punctuation = matches!(c, '。' | '、') && idx > start;
+        if !is_kana(c) && !is_punctuation {
+            break;
+        }
+        let (base, mut voicing) = split_voicing(to_plain_hiragana(c));
+        idx += 1;
+        // 따로 쓴 濁点, 半濁点
+        if voicing.is_none()
+            && let Some(&mark) = chars.get(idx).filter(|c| matches!(**c, DAKUTEN | HANDAKUTEN))
+        {
+            voicing = Some(mark);
+            idx += 1;
+        }
+        if let Some(row) = YOON_ROW_MAP.get(&base)
+            && let Some(column) = chars.get(idx).and_then(|c| yoon_column(*c))
+        {
+            result.push(match voicing {
+                Some(DAKUTEN) => YOON_DAKUON,
+                Some(HANDAKUTEN) => YOON_HANDAKUON,
+                _ => YOON,
+            });
+            result.push(encode_plain(row[column])?);
+            idx += 1;
+            continue;
+        }
+        match voicing {
+            Some(DAKUTEN) => result.push(DAKUON),
+            Some(HANDAKUTEN) => result.push(HANDAKUON),
+            _ => {}
+        }
+        result.push(encode_plain(base)?);
+    }
+    Ok((result, idx - start))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(text: &str) -> String {
+        let chars = text.chars().collect::<Vec<_>>();
+        let (cells, len) = encode_kana_run(&chars, 0).unwrap();
+        assert_eq!(len, chars.len());
+        cells.into_iter().map(encode_unicode).collect()
+    }
+
+    #[test]
+    fn test_encode_seion() {
+        assert_eq!(encode_str("さくら"), "⠱⠩⠑");
+        assert_eq!(encode_str("サクラ"), "⠱⠩⠑");
+        assert_eq!(encode_str("にほん"), "⠇⠮⠴");
+    }
+
+    #[test]
+    fn test_encode_dakuon_and_handakuon() {
+        assert_eq!(encode_str("がっこう"), "⠐⠡⠂⠪⠉");
+        assert_eq!(encode_str("ぱん"), "⠠⠥⠴");
+        // 濁点을 따로 쓴 경우
+        assert_eq!(encode_str("か\u{3099}"), "⠐⠡");
+    }
+
+    #[test]
+    fn test_encode_yoon() {
+        assert_eq!(encode_str("きょう"), "⠈⠪⠉");
+        assert_eq!(encode_str("しゃしん"), "⠈⠱⠳⠴");
+        assert_eq!(encode_str("ぎゅう"), "⠘⠩⠉");
+        assert_eq!(encode_str("ぴょん"), "⠨⠮⠴");
+    }
+
+    #[test]
+    fn test_encode_long_vowel() {
+        assert_eq!(encode_str("コーヒー"), "⠪⠒⠧⠒");
+        assert_eq!(encode_str("ラーメン"), "⠑⠒⠿⠴");
+    }
+
+    #[test]
+    fn test_run_stops_at_other_characters() {
+        let chars = "すし를".chars().collect::<Vec<_>>();
+        assert_eq!(encode_kana_run(&chars, 0).unwrap().1, 2);
+        let chars = "。".chars().collect::<Vec<_>>();
+        assert_eq!(encode_kana_run(&chars, 0).unwrap().1, 0);
+    }
+}
@@ -1,6 +1,8 @@
 use phf::phf_map;
 
-use crate::{moeum::jungsong::JUNGSEONG_MAP, unicode::decode_unicode};
+use crate::{
+    moeum::jungsong::JUNGSEONG_MAP, old_hangul::OLD_KOREAN_PART_MAP, unicode::decode_unicode,
+};
 
 pub static KOREAN_PART_MAP: phf::Map<char, &'static [u8]> = phf_map! {
     'ㄱ' => &[decode_unicode('⠁')],
@@ -43,5 +45,8 @@ pub fn encode_korean_part(text: char) -> Result<&'static [u8], String> {
     if let Some(code) = JUNGSEONG_MAP.get(&text) {
         return Ok(code);
     }
+    if let Some(code) = OLD_KOREAN_PART_MAP.get(&text) {
+        return Ok(code);
+    }
     Err("Invalid Korean part character".to_string())
 }
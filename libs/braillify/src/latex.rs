@@ -0,0 +1,508 @@
+use std::{iter::Peekable, str::CharIndices};
+
+use crate::{diagnostic::Diagnostic, fraction::normalize_digit};
+
+/// `$…$` 또는 `$$…$$` 안의 LaTeX 수식을 구문 분석한 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathNode {
+    /// 숫자. 소수점(.)을 포함할 수 있다.
+    Number(String),
+    /// 로마자 변수
+    Letter(char),
+    /// 그리스 문자 (`\alpha` → 'α')
+    Greek(char),
+    /// 연산 기호와 비교 기호
+    Operator(char),
+    /// 괄호, 쉼표 등 문장 부호
+    Symbol(char),
+    /// `{…}` 또는 글꼴 명령처럼 점역에 영향을 주지 않는 묶음
+    Group(Vec<MathNode>),
+    /// `\frac{분자}{분모}`
+    Fraction(Vec<MathNode>, Vec<MathNode>),
+    /// `\sqrt[지수]{근호 안}`
+    Root(Option<Vec<MathNode>>, Vec<MathNode>),
+    /// `^`
+    Superscript(Vec<MathNode>),
+    /// `_`
+    Subscript(Vec<MathNode>),
+    /// `\text{…}` 안의 일반 글
+    Text(String),
+}
+
+/// `text`가 `$`로 시작할 때 수식 구간의 길이(바이트)를 반환한다.
+///
+/// 화폐 기호로 쓰인 `$`와 구별하기 위해 여는 `$` 바로 뒤와 닫는 `$` 바로 앞에는 공백이 올 수 없고,
+/// 닫는 `$` 바로 뒤에는 숫자가 올 수 없다.
+pub fn math_span_len(text: &str) -> Option<usize> {
+    let delimiter = if text.starts_with("$$") {
+        "$$"
+    } else if text.starts_with('$') {
+        "$"
+    } else {
+        return None;
+    };
+    let body = &text[delimiter.len()..];
+    if body
+        .chars()
+        .next()
+        .is_none_or(|c| c.is_whitespace() || c == '$')
+    {
+        return None;
+    }
+
+    let mut prev = ' ';
+    let mut escaped = false;
+    for (idx, c) in body.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if body[idx..].starts_with(delimiter) && !prev.is_whitespace() && idx > 0 {
+            let end = idx + delimiter.len();
+            if !body[end..]
+                .chars()
+                .next()
+                .is_some_and(|n| n.is_ascii_digit())
+            {
+                return Some(delimiter.len() + end);
+            }
+        }
+        prev = c;
+    }
+    None
+}
+
+/// `word` 안에서 처음 나오는 수식 구간의 바이트 범위를 찾는다.
+pub fn find_math_span(word: &str) -> Option<(usize, usize)> {
+    let mut escaped = false;
+    for (idx, c) in word.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        if c == '\\' {
+            escaped = true;
+        } else if c == '$'
+            && let Some(len) = math_span_len(&word[idx..])
+        {
+            return Some((idx, idx + len));
+        }
+    }
+    None
+}
+
+/// 수식 구간(`$…$`)을 구문 분석한다.
+/// 지원하지 않는 명령은 오류 대신 `diagnostics`에 기록하고 인자만 남긴다.
+pub fn parse(span: &str, diagnostics: &mut Vec<Diagnostic>) -> Result<Vec<MathNode>, String> {
+    let body = span
+        .strip_prefix("$$")
+        .and_then(|s| s.strip_suffix("$$"))
+        .or_else(|| span.strip_prefix('$').and_then(|s| s.strip_suffix('$')))
+        .unwrap_or(span);
+    let mut parser = Parser {
+        source: body,
+        iter: body.char_indices().peekable(),
+        diagnostics,
+    };
+    let nodes = parser.parse_sequence()?;
+    if parser.iter.next().is_some() {
+        return Err("Invalid LaTeX: unbalanced braces".to_string());
+    }
+    Ok(nodes)
+}
+
+struct Parser<'a, 'd> {
+    source: &'a str,
+    iter: Peekable<CharIndices<'a>>,
+    diagnostics: &'d mut Vec<Diagnostic>,
+}
+
+impl Parser<'_, '_> {
+    fn peek(&mut self) -> Option<char> {
+        self.iter.peek().map(|(_, c)| *c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.iter.next();
+        }
+    }
+
+    /// `}` 또는 입력 끝까지 읽는다. `}`는 소비하지 않는다.
+    fn parse_sequence(&mut self) -> Result<Vec<MathNode>, String> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '}' {
+                break;
+            }
+            match c {
+                '^' | '_' => {
+                    self.iter.next();
+                    let script = self.parse_argument()?;
+                    nodes.push(if c == '^' {
+                        MathNode::Superscript(script)
+                    } else {
+                        MathNode::Subscript(script)
+                    });
+                }
+                _ => {
+                    if let Some(node) = self.parse_atom()? {
+                        nodes.push(node);
+                    }
+                }
+            }
+        }
+        Ok(nodes)
+    }
+
+    /// 명령의 인자나 첨자처럼 `{…}` 또는 한 개의 원소를 읽는다.
+    fn parse_argument(&mut self) -> Result<Vec<MathNode>, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_group(),
+            Some(c) if let Some(digit) = normalize_digit(c) => {
+                // TeX와 같이 괄호 없는 첨자는 숫자 한 개만 가진다.
+                self.iter.next();
+                Ok(vec![MathNode::Number(digit.to_string())])
+            }
+            Some(_) => Ok(self.parse_atom()?.into_iter().collect()),
+            None => Err("Invalid LaTeX: missing argument".to_string()),
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Vec<MathNode>, String> {
+        self.iter.next();
+        let nodes = self.parse_sequence()?;
+        if self.iter.next().is_none() {
+            return Err("Invalid LaTeX: unbalanced braces".to_string());
+        }
+        Ok(nodes)
+    }
+
+    fn parse_atom(&mut self) -> Result<Option<MathNode>, String> {
+        let Some(c) = self.peek() else {
+            return Ok(None);
+        };
+        if c.is_whitespace() {
+            self.iter.next();
+            return Ok(None);
+        }
+        if c == '}' {
+            return Err("Invalid LaTeX: unbalanced braces".to_string());
+        }
+        if c == '{' {
+            return Ok(Some(MathNode::Group(self.parse_group()?)));
+        }
+        if c == '\\' {
+            self.iter.next();
+            return self.parse_command();
+        }
+        if normalize_digit(c).is_some() {
+            return Ok(Some(self.parse_number()));
+        }
+        self.iter.next();
+        Ok(Some(match c {
+            'a'..='z' | 'A'..='Z' => MathNode::Letter(c),
+            '+' | '=' | '<' | '>' | '×' | '÷' | '−' => MathNode::Operator(c),
+            '-' => MathNode::Operator('−'),
+            '*' => MathNode::Operator('×'),
+            c if c.is_ascii() => MathNode::Symbol(c),
+            c => MathNode::Text(c.to_string()),
+        }))
+    }
+
+    fn parse_number(&mut self) -> MathNode {
+        let mut number = String::new();
+        while let Some(c) = self.peek() {
+            if let Some(digit) = normalize_digit(c) {
+                number.push(digit);
+                self.iter.next();
+            } else if c == '.' && !number.is_empty() {
+                // 소수점은 뒤에 숫자가 이어질 때만 숫자에 포함한다.
+                let mut lookahead = self.iter.clone();
+                lookahead.next();
+                if lookahead
+                    .peek()
+                    .is_some_and(|(_, n)| normalize_digit(*n).is_some())
+                {
+                    number.push('.');
+                    self.iter.next();
+                } else {
+                    break;
+                }
+            } else {
+                break;
+            }
+        }
+        MathNode::Number(number)
+    }
+
+    fn parse_command(&mut self) -> Result<Option<MathNode>, String> {
+        let Some((start, first)) = self.iter.next() else {
+            return Err("Invalid LaTeX: trailing backslash".to_string());
+        };
+        if !first.is_ascii_alphabetic() {
+            // \{ \} \$ \% 같은 기호 또는 \, \; \! 같은 간격 명령
+            return Ok(match first {
+                ',' | ';' | ':' | '!' | ' ' => None,
+                '\\' => Some(MathNode::Text("\n".to_string())),
+                c => Some(MathNode::Symbol(c)),
+            });
+        }
+        let mut end = start + first.len_utf8();
+        while let Some((idx, c)) = self.iter.peek().copied() {
+            if !c.is_ascii_alphabetic() {
+                break;
+            }
+            end = idx + c.len_utf8();
+            self.iter.next();
+        }
+        let name = &self.source[start..end];
+
+        if let Some(greek) = greek_letter(name) {
+            return Ok(Some(MathNode::Greek(greek)));
+        }
+        Ok(match name {
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.parse_argument()?;
+                let denominator = self.parse_argument()?;
+                Some(MathNode::Fraction(numerator, denominator))
+            }
+            "sqrt" => {
+                self.skip_whitespace();
+                let index = if self.peek() == Some('[') {
+                    self.iter.next();
+                    let mut index = Vec::new();
+                    while self.peek().is_some_and(|c| c != ']') {
+                        if let Some(node) = self.parse_atom()? {
+                            index.push(node);
+                        }
+                    }
+                    if self.iter.next().is_none() {
+                        return Err("Invalid LaTeX: unbalanced brackets".to_string());
+                    }
+                    Some(index)
+                } else {
+                    None
+                };
+                Some(MathNode::Root(index, self.parse_argument()?))
+            }
+            "times" => Some(MathNode::Operator('×')),
+            "div" => Some(MathNode::Operator('÷')),
+            "lt" => Some(MathNode::Operator('<')),
+            "gt" => Some(MathNode::Operator('>')),
+            "cdot" => Some(MathNode::Symbol('·')),
+            "ldots" | "cdots" | "dots" => Some(MathNode::Symbol('…')),
+            "sin" | "cos" | "tan" | "log" | "ln" | "exp" | "lim" | "max" | "min" => Some(
+                MathNode::Group(name.chars().map(MathNode::Letter).collect()),
+            ),
+            "text" | "textrm" | "mbox" => {
+                self.skip_whitespace();
+                Some(MathNode::Text(self.read_raw_group()?))
+            }
+            "mathrm" | "mathit" | "mathbf" | "mathsf" | "boldsymbol" | "operatorname" => {
+                Some(MathNode::Group(self.parse_argument()?))
+            }
+            "left" | "right" | "big" | "Big" | "bigg" | "Bigg" => {
+                // 크기 조절 명령은 뒤의 괄호만 남긴다. \left. 처럼 보이지 않는 괄호는 버린다.
+                self.skip_whitespace();
+                if self.peek() == Some('.') {
+                    self.iter.next();
+                }
+                None
+            }
+            "quad" | "qquad" | "displaystyle" | "textstyle" => None,
+            _ => {
+                self.diagnostics
+                    .push(Diagnostic::UnsupportedLatexCommand(name.to_string()));
+                let mut args = Vec::new();
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() != Some('{') {
+                        break;
+                    }
+                    args.extend(self.parse_group()?);
+                }
+                (!args.is_empty()).then_some(MathNode::Group(args))
+            }
+        })
+    }
+
+    /// `\text{…}`의 인자를 해석하지 않고 그대로 읽는다.
+    fn read_raw_group(&mut self) -> Result<String, String> {
+        if self.iter.next().map(|(_, c)| c) != Some('{') {
+            return Err("Invalid LaTeX: missing argument".to_string());
+        }
+        let mut depth = 0;
+        let mut text = String::new();
+        for (_, c) in self.iter.by_ref() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return Ok(text),
+                '}' => depth -= 1,
+                _ => {}
+            }
+            text.push(c);
+        }
+        Err("Invalid LaTeX: unbalanced braces".to_string())
+    }
+}
+
+fn greek_letter(name: &str) -> Option<char> {
+    Some(match name {
+        "alpha" => 'α',
+        "beta" => 'β',
+        "gamma" => 'γ',
+        "delta" => 'δ',
+        "epsilon" | "varepsilon" => 'ε',
+        "zeta" => 'ζ',
+        "eta" => 'η',
+        "theta" | "vartheta" => 'θ',
+        "iota" => 'ι',
+        "kappa" => 'κ',
+        "lambda" => 'λ',
+        "mu" => 'μ',
+        "nu" => 'ν',
+        "xi" => 'ξ',
+        "pi" => 'π',
+        "rho" => 'ρ',
+        "sigma" => 'σ',
+        "tau" => 'τ',
+        "upsilon" => 'υ',
+        "phi" | "varphi" => 'φ',
+        "chi" => 'χ',
+        "psi" => 'ψ',
+        "omega" => 'ω',
+        "Gamma" => 'Γ',
+        "Delta" => 'Δ',
+        "Theta" => 'Θ',
+        "Lambda" => 'Λ',
+        "Xi" => 'Ξ',
+        "Pi" => 'Π',
+        "Sigma" => 'Σ',
+        "Phi" => 'Φ',
+        "Psi" => 'Ψ',
+        "Omega" => 'Ω',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_ok(span: &str) -> (Vec<MathNode>, Vec<Diagnostic>) {
+        let mut diagnostics = Vec::new();
+        let nodes = parse(span, &mut diagnostics).unwrap();
+        (nodes, diagnostics)
+    }
+
+    #[test]
+    fn test_math_span_len() {
+        assert_eq!(math_span_len("$x$"), Some(3));
+        assert_eq!(math_span_len("$x + 1$은"), Some(7));
+        assert_eq!(math_span_len("$$x$$"), Some(5));
+        assert_eq!(math_span_len("$\\$$"), Some(4));
+        assert_eq!(math_span_len("$ x$"), None);
+        assert_eq!(math_span_len("$x $"), None);
+        assert_eq!(math_span_len("$5 and $10"), None);
+        assert_eq!(math_span_len("$$"), None);
+        assert_eq!(math_span_len("x$"), None);
+    }
+
+    #[test]
+    fn test_find_math_span() {
+        assert_eq!(find_math_span("($x$)"), Some((1, 4)));
+        assert_eq!(find_math_span("\\$x$"), None);
+        assert_eq!(find_math_span("$5"), None);
+    }
+
+    #[test]
+    fn test_parse_numbers_and_operators() {
+        let (nodes, diagnostics) = parse_ok("$3.14 + x - 2$");
+        assert_eq!(
+            nodes,
+            vec![
+                MathNode::Number("3.14".to_string()),
+                MathNode::Operator('+'),
+                MathNode::Letter('x'),
+                MathNode::Operator('−'),
+                MathNode::Number("2".to_string()),
+            ]
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_parse_fraction_and_scripts() {
+        let (nodes, _) = parse_ok("$\\frac{a}{2}x^{10}y_1$");
+        assert_eq!(
+            nodes,
+            vec![
+                MathNode::Fraction(
+                    vec![MathNode::Letter('a')],
+                    vec![MathNode::Number("2".to_string())]
+                ),
+                MathNode::Letter('x'),
+                MathNode::Superscript(vec![MathNode::Number("10".to_string())]),
+                MathNode::Letter('y'),
+                MathNode::Subscript(vec![MathNode::Number("1".to_string())]),
+            ]
+        );
+        let (nodes, _) = parse_ok("$x^23$");
+        assert_eq!(
+            nodes[1],
+            MathNode::Superscript(vec![MathNode::Number("2".to_string())])
+        );
+        assert_eq!(nodes[2], MathNode::Number("3".to_string()));
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        let (nodes, _) = parse_ok("$\\sqrt[3]{8} \\times \\pi \\left( a \\right.$");
+        assert_eq!(
+            nodes,
+            vec![
+                MathNode::Root(
+                    Some(vec![MathNode::Number("3".to_string())]),
+                    vec![MathNode::Number("8".to_string())]
+                ),
+                MathNode::Operator('×'),
+                MathNode::Greek('π'),
+                MathNode::Symbol('('),
+                MathNode::Letter('a'),
+            ]
+        );
+        let (nodes, _) = parse_ok("$\\text{넓이} = \\mathrm{cm}$");
+        assert_eq!(nodes[0], MathNode::Text("넓이".to_string()));
+        assert_eq!(
+            nodes[2],
+            MathNode::Group(vec![MathNode::Letter('c'), MathNode::Letter('m')])
+        );
+    }
+
+    #[test]
+    fn test_parse_unsupported_command_is_diagnostic() {
+        let (nodes, diagnostics) = parse_ok("$\\overline{AB}$");
+        assert_eq!(
+            nodes,
+            vec![MathNode::Group(vec![
+                MathNode::Letter('A'),
+                MathNode::Letter('B')
+            ])]
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::UnsupportedLatexCommand("overline".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut diagnostics = Vec::new();
+        assert!(parse("${x$", &mut diagnostics).is_err());
+        assert!(parse("$x}$", &mut diagnostics).is_err());
+        assert!(parse("$\\frac{1}$", &mut diagnostics).is_err());
+        assert!(parse("$x^$", &mut diagnostics).is_err());
+    }
+}
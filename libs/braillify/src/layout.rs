@@ -0,0 +1,167 @@
+use crate::unicode::{NEWLINE, decode_unicode};
+
+/// 제42항 긴 숫자를 두 줄에 나누어 적을 때 위 줄 끝에 적는 연결표
+const NUMBER_CONTINUATION: u8 = decode_unicode('⠠');
+
+/// 점역 결과에서 줄을 나눌 수 있는 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineBreak {
+    /// 단어 사이의 빈칸. 이 빈칸에서 줄을 바꾸면 빈칸은 적지 않는다.
+    Space(usize),
+    /// 제42항 일곱 자리 이상의 수 안. 이 위치 앞에서 줄을 바꾸면
+    /// 위 줄 끝에 연결표 ⠠을 적고 아래 줄의 첫머리에는 수표를 다시 적지 않는다.
+    Number(usize),
+}
+
+impl LineBreak {
+    /// 점역 결과 앞에 `offset`칸을 더했을 때의 위치
+    pub(crate) fn shifted(self, offset: usize) -> Self {
+        match self {
+            LineBreak::Space(idx) => LineBreak::Space(idx + offset),
+            LineBreak::Number(idx) => LineBreak::Number(idx + offset),
+        }
+    }
+}
+
+/// 점역 결과를 한 줄에 `width`칸 이하가 되도록 나눈다.
+///
+/// 단어 사이의 빈칸에서 먼저 줄을 바꾸고, 빈칸으로 나눌 수 없을 때에만 긴 숫자를 나눈다.
+/// 둘 다 불가능하면 `width`칸에서 자른다. 줄바꿈(255)은 그대로 줄을 바꾼다.
+pub fn wrap(cells: &[u8], breaks: &[LineBreak], width: usize) -> Vec<Vec<u8>> {
+    wrap_indented(cells, breaks, width, 0, 0)
+}
+
+/// [`wrap`]과 같이 줄을 나누되, 첫 줄 앞에는 `first_indent`칸, 나머지 줄 앞에는 `indent`칸의
+/// 빈칸을 둔다. 빈칸을 포함해 한 줄이 `width`칸을 넘지 않는다.
+pub fn wrap_indented(
+    cells: &[u8],
+    breaks: &[LineBreak],
+    width: usize,
+    first_indent: usize,
+    indent: usize,
+) -> Vec<Vec<u8>> {
+    let mut lines: Vec<Vec<u8>> = Vec::new();
+    let mut start = 0;
+    loop {
+        let margin = if lines.is_empty() {
+            first_indent
+        } else {
+            indent
+        };
+        let width = width.saturating_sub(margin).max(1);
+        let mut push = |line: &[u8]| {
+            let mut indented = vec![0; margin];
+            indented.extend_from_slice(line);
+            lines.push(indented);
+        };
+        let rest = &cells[start..];
+        if let Some(newline) = rest.iter().position(|c| *c == NEWLINE)
+            && newline <= width
+        {
+            push(&rest[..newline]);
+            start += newline + 1;
+            continue;
+        }
+        if rest.len() <= width {
+            push(rest);
+            break;
+        }
+
+        let limit = start + width;
+        let space = breaks
+            .iter()
+            .filter_map(|b| match b {
+                LineBreak::Space(idx) if *idx > start && *idx <= limit => Some(*idx),
+                _ => None,
+            })
+            .max();
+        let number = breaks
+            .iter()
+            .filter_map(|b| match b {
+                LineBreak::Number(idx) if *idx > start && *idx < limit => Some(*idx),
+                _ => None,
+            })
+            .max();
+
+        if let Some(idx) = space {
+            push(&cells[start..idx]);
+            start = idx + 1;
+        } else if let Some(idx) = number {
+            let mut line = cells[start..idx].to_vec();
+            line.push(NUMBER_CONTINUATION);
+            push(&line);
+            start = idx;
+        } else {
+            push(&cells[start..limit]);
+            start = limit;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn to_unicode(lines: &[Vec<u8>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|c| encode_unicode(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap_by_space() {
+        let (cells, breaks) = crate::encode_with_line_breaks("상 상 상").unwrap();
+        assert_eq!(to_unicode(&wrap(&cells, &breaks, 5)), vec!["⠇⠶⠀⠇⠶", "⠇⠶"]);
+        assert_eq!(to_unicode(&wrap(&cells, &breaks, 8)), vec!["⠇⠶⠀⠇⠶⠀⠇⠶"]);
+    }
+
+    #[test]
+    fn test_wrap_indented() {
+        let (cells, breaks) = crate::encode_with_line_breaks("상 상 상").unwrap();
+        assert_eq!(
+            to_unicode(&wrap_indented(&cells, &breaks, 7, 2, 1)),
+            vec!["⠀⠀⠇⠶⠀⠇⠶", "⠀⠇⠶"]
+        );
+    }
+
+    #[test]
+    fn test_wrap_newline() {
+        assert_eq!(wrap(&[1, 255, 2, 3], &[], 10), vec![vec![1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_wrap_without_break_opportunity() {
+        assert_eq!(
+            wrap(&[1, 2, 3, 4, 5], &[], 2),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn test_wrap_by_testcase() {
+        // 입력, 한 줄의 칸 수, 줄마다 `|`로 나눈 점역 결과
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../test_cases/line_width/rule_42.csv"
+        );
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .from_path(path)
+            .unwrap();
+        for record in reader.records() {
+            let record = record.unwrap();
+            let width = record[1].parse::<usize>().unwrap();
+            let (cells, breaks) = crate::encode_with_line_breaks(&record[0]).unwrap();
+            assert_eq!(
+                to_unicode(&wrap(&cells, &breaks, width)).join("|"),
+                &record[2],
+                "{} ({}칸)",
+                &record[0],
+                width
+            );
+        }
+    }
+}
@@ -1,8 +1,6 @@
 use jauem::choseong::encode_choseong;
 use moeum::jungsong::encode_jungsong;
 use utils::has_choseong_o;
-use once_cell::sync::Lazy;
-use regex::Regex;
 
 use crate::{
     char_struct::CharType,
@@ -11,33 +9,88 @@ use crate::{
     rule::{rule_11, rule_12},
     rule_en::{rule_en_10_4, rule_en_10_6},
     split::split_korean_jauem,
+    tokenize::{Blank, Token},
 };
 
-static FRACTION_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r#"^(\d+)\/(\d+)"#)
-        .expect("Failed to compile FRACTION_REGEX")
-});
-
+mod alignment;
+#[cfg(any(feature = "epub", feature = "docx"))]
+mod archive;
+mod book;
+mod capital;
 mod char_shortcut;
 mod char_struct;
+mod chemistry;
 #[cfg(feature = "cli")]
 pub mod cli;
+mod computer;
+mod diagnostic;
+mod document;
+#[cfg(feature = "docx")]
+mod docx;
+mod embosser;
+mod emphasis;
+mod enclosed;
 mod english;
 mod english_logic;
+#[cfg(feature = "epub")]
+mod epub;
+mod fraction;
+mod geometry;
+mod hanja;
+#[cfg(feature = "html")]
+mod html;
+mod interlinear;
 mod jauem;
+mod kana;
 mod korean_char;
 mod korean_part;
+mod latex;
+mod layout;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod math;
 mod math_symbol_shortcut;
 mod moeum;
+mod normalize;
 mod number;
+mod old_hangul;
+mod options;
+mod pdf;
 mod rule;
 mod rule_en;
+mod script;
 mod split;
+mod stl;
+mod svg;
 mod symbol_shortcut;
+mod tokenize;
 mod unicode;
+mod unit;
 mod utils;
 mod word_shortcut;
-mod fraction;
+
+pub use alignment::WordAlignment;
+pub use book::{BookOptions, render_book};
+pub use diagnostic::Diagnostic;
+pub use document::{Block, DocumentOptions, encode_document, parse_blocks, render_blocks};
+#[cfg(feature = "docx")]
+pub use docx::{encode_docx, parse_docx};
+pub use embosser::{EmbosserOptions, EmbosserProfile, emboss};
+#[cfg(feature = "epub")]
+pub use epub::{encode_epub, parse_epub};
+pub use geometry::CellDimensions;
+#[cfg(feature = "html")]
+pub use html::{encode_html, parse_html};
+pub use interlinear::{
+    InterlinearWord, interlinear_lines, render_interlinear_html, render_interlinear_text,
+};
+pub use layout::{LineBreak, wrap, wrap_indented};
+#[cfg(feature = "markdown")]
+pub use markdown::{encode_markdown, parse_markdown};
+pub use options::{EncodeOptions, HanjaPolicy, NewlinePolicy, SpacePolicy};
+pub use pdf::{PdfOptions, encode_pdf, render_pdf};
+pub use stl::{StlOptions, encode_stl, render_stl};
+pub use svg::{SvgOptions, render_svg, render_svg_with_print};
 
 pub struct Encoder {
     is_english: bool,
@@ -45,21 +98,72 @@ pub struct Encoder {
     english_indicator: bool,
     has_processed_word: bool,
     needs_english_continuation: bool,
+    /// 국어 문장 안의 가나 구간 안에 있는지 여부
+    is_kana: bool,
     parenthesis_stack: Vec<bool>,
+    diagnostics: Vec<Diagnostic>,
+    line_breaks: Vec<LineBreak>,
+    alignment: Vec<WordAlignment>,
+    /// [`Encoder::encode`]가 안에서 다시 불린 깊이. 가장 바깥 단어만 위치를 기록한다.
+    depth: usize,
+    /// 마지막으로 단어 뒤의 빈칸을 적기 시작한 칸
+    gap_start: usize,
+    /// 강조 구간 표시 문자를 받는지 여부. 문서의 블록을 점역할 때만 켠다.
+    emphasis: bool,
+    options: EncodeOptions,
 }
 
 impl Encoder {
     pub fn new(english_indicator: bool) -> Self {
+        Self::with_options(english_indicator, EncodeOptions::default())
+    }
+
+    pub fn with_options(english_indicator: bool, options: EncodeOptions) -> Self {
         Self {
             english_indicator,
             is_english: false,
             triple_big_english: false,
             has_processed_word: false,
             needs_english_continuation: false,
+            is_kana: false,
             parenthesis_stack: Vec::new(),
+            diagnostics: Vec::new(),
+            line_breaks: Vec::new(),
+            alignment: Vec::new(),
+            depth: 0,
+            gap_start: 0,
+            emphasis: false,
+            options,
         }
     }
 
+    /// 새 문단을 시작한다. 로마자표를 쓸지 문단마다 다시 정하고, 앞 문단의 로마자 구간과
+    /// 대문자 구절표는 앞 문단에서 끝난 것으로 본다.
+    pub fn start_paragraph(&mut self, english_indicator: bool) {
+        self.english_indicator = english_indicator;
+        self.is_english = false;
+        self.triple_big_english = false;
+        self.has_processed_word = false;
+        self.needs_english_continuation = false;
+        self.is_kana = false;
+        self.parenthesis_stack.clear();
+    }
+
+    /// 점역 중 기록된 진단 메시지
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// 점역 결과에서 줄을 나눌 수 있는 위치
+    pub fn line_breaks(&self) -> &[LineBreak] {
+        &self.line_breaks
+    }
+
+    /// 단어마다 점역 결과에서 차지하는 위치. 입력 위치는 [`Encoder::encode`]에 넘긴 글에서 센다.
+    pub fn alignment(&self) -> &[WordAlignment] {
+        &self.alignment
+    }
+
     fn exit_english(&mut self, needs_continuation: bool) {
         self.is_english = false;
         self.needs_english_continuation = needs_continuation;
@@ -76,49 +180,183 @@ impl Encoder {
     }
 
     pub fn encode(&mut self, text: &str, result: &mut Vec<u8>) -> Result<(), String> {
-        let words = text
-            .split(' ')
-            .filter(|word| !word.is_empty())
-            .collect::<Vec<&str>>();
+        // 줄 첫머리의 빈칸과, 단어마다 그 뒤에 오는 빈칸
+        let mut leading = Vec::new();
+        // 입력의 단어와 점역할 단어
+        let mut originals = Vec::new();
+        let mut prepared = Vec::new();
+        let mut gaps = Vec::new();
+        for token in tokenize::tokenize(text) {
+            match token {
+                Token::Word(word) => {
+                    // 자모를 정규화하고 한자는 설정에 따라 지우거나 음으로 바꾼다.
+                    // 다 지운 단어의 뒤 빈칸은 앞 단어에 붙인다.
+                    let converted = alignment::prepare_word(word, &self.options)?;
+                    if converted.is_empty() {
+                        continue;
+                    }
+                    originals.push(word);
+                    prepared.push(converted);
+                    gaps.push(Vec::new());
+                }
+                Token::Blank(blank) => gaps.last_mut().unwrap_or(&mut leading).push(blank),
+            }
+        }
+        self.encode_gap(&leading, true, result);
 
+        let words = prepared
+            .iter()
+            .map(|word| word.as_ref())
+            .collect::<Vec<_>>();
         let mut word: &str = "";
         let mut remaining_words = &words[..];
+        let mut originals = originals.into_iter();
+        let mut gaps = gaps.iter();
         while !remaining_words.is_empty() {
             let prev_word = word;
             (word, remaining_words) = remaining_words.split_first().unwrap();
+            let original = originals.next().unwrap();
+            let gap = gaps.next().unwrap();
 
             let mut skip_count = 0;
 
-            self.encode_word(word, prev_word, remaining_words, &mut skip_count, result)?;
+            let start = result.len();
+            self.depth += 1;
+            self.encode_word(
+                word,
+                prev_word,
+                remaining_words,
+                gap,
+                &mut skip_count,
+                result,
+            )?;
+            self.depth -= 1;
+            if self.depth == 0 {
+                let source = original.as_ptr() as usize - text.as_ptr() as usize;
+                self.alignment.push(WordAlignment {
+                    source: source..source + original.len(),
+                    cells: start..self.gap_start.max(start),
+                });
+            }
         }
         Ok(())
     }
 
+    /// 단어 사이의 빈칸을 설정에 따라 적는다. `is_edge`이면 줄의 앞이나 뒤의 빈칸이다.
+    fn encode_gap(&mut self, gap: &[Blank], is_edge: bool, result: &mut Vec<u8>) {
+        self.gap_start = result.len();
+        let newlines = gap.iter().map(|blank| blank.newlines()).sum::<usize>();
+        let gap =
+            if newlines > 0 && !(self.options.newlines == NewlinePolicy::Join && newlines == 1) {
+                result.extend(std::iter::repeat_n(unicode::NEWLINE, newlines));
+                if self.options.spaces == SpacePolicy::Collapse {
+                    return;
+                }
+                // 줄바꿈 뒤의 들여쓰기만 남긴다.
+                let indent = gap.iter().rposition(|blank| blank.newlines() > 0).unwrap() + 1;
+                &gap[indent..]
+            } else {
+                gap
+            };
+        match self.options.spaces {
+            SpacePolicy::Collapse => {
+                if is_edge || gap.is_empty() {
+                    return;
+                }
+                if !gap.iter().all(|blank| *blank == Blank::NoBreakSpace) {
+                    self.line_breaks.push(LineBreak::Space(result.len()));
+                }
+                result.push(0);
+            }
+            SpacePolicy::Preserve => {
+                for blank in gap {
+                    if *blank != Blank::NoBreakSpace {
+                        self.line_breaks.push(LineBreak::Space(result.len()));
+                    }
+                    let width = if *blank == Blank::Tab {
+                        tokenize::TAB_WIDTH
+                    } else {
+                        1
+                    };
+                    result.extend(std::iter::repeat_n(0, width));
+                }
+            }
+        }
+    }
+
     fn encode_word(
         &mut self,
         word: &str,
         prev_word: &str,
         remaining_words: &[&str],
+        gap: &[Blank],
         skip_count: &mut usize,
         result: &mut Vec<u8>,
     ) -> Result<(), String> {
+        // 폭 없는 문자는 점자로 적지 않는다.
         // 제53항 가운뎃점으로 쓴 줄임표(…… , …)는 ⠠⠠⠠으로, 마침표로 쓴 줄임표(...... , ...)는 ⠲⠲⠲으로 적는다.
         let normalized_word = word
+            .replace(tokenize::is_zero_width, "")
             .replace("......", "...")
             .replace("……", "…");
+        // ㎞, ㎡처럼 한 글자로 된 단위는 로마자로 풀어 쓴다.
+        let normalized_word = unit::decompose_units(&normalized_word);
+        // ①, ⑴, ㉠, Ⅱ처럼 번호로 쓰인 글자는 안의 숫자나 글자로 풀어 쓴다.
+        let normalized_word = enclosed::decompose_enclosed_chars(&normalized_word);
         let word = normalized_word.as_str();
 
-        if word.starts_with('$') && word.ends_with('$') {
-            if let Some((whole, num, den)) = fraction::parse_latex_fraction(word) {
-                if let Some(w) = whole {
-                    result.extend(fraction::encode_mixed_fraction(&w, &num, &den)?);
-                } else {
-                    result.extend(fraction::encode_fraction(&num, &den)?);
-                }
-                return Ok(());
+        let emphasis_len = if self.emphasis {
+            emphasis::leading_marks_len(word)
+        } else {
+            0
+        };
+        if emphasis_len > 0 {
+            // 강조 구간 표시는 단어 첫머리의 약어나 로마자표보다 먼저 적는다.
+            for c in word[..emphasis_len].chars() {
+                result.extend(emphasis::encode_emphasis_mark(c)?);
             }
-        }
-        if let Some((_, code, rest)) = word_shortcut::split_word_shortcut(word) {
+            if emphasis_len < word.len() {
+                self.encode(&word[emphasis_len..], result)?;
+            }
+        } else if word.starts_with('$')
+            && word.ends_with('$')
+            && let Some((whole, num, den)) = fraction::parse_latex_fraction(word)
+        {
+            if let Some(w) = whole {
+                result.extend(fraction::encode_mixed_fraction(&w, &num, &den)?);
+            } else {
+                result.extend(fraction::encode_fraction(&num, &den)?);
+            }
+        } else if let Some((start, end)) = computer::find_code_markup(word) {
+            self.encode_computer_span(word, start, end, result)?;
+        } else if let Some((start, end)) = latex::find_math_span(word) {
+            if start > 0 {
+                self.encode(&word[..start], result)?;
+            }
+            self.encode_math(&word[start..end], result)?;
+            if end < word.len() {
+                self.encode(&word[end..], result)?;
+            }
+        } else if let Some((start, end)) = computer::find_computer_span(word) {
+            self.encode_computer_span(word, start, end, result)?;
+        } else if self.options.chemistry
+            && let Some((tokens, len)) = chemistry::tokenize_formula(word)
+        {
+            self.encode_formula(&tokens, result)?;
+            if len < word.len() {
+                self.encode(&word[len..], result)?;
+            }
+        } else if self.options.hanja == HanjaPolicy::Indicator
+            && let Some((start, end)) = hanja::find_hanja_span(word)
+        {
+            if start > 0 {
+                self.encode(&word[..start], result)?;
+            }
+            self.encode_hanja(&word[start..end], result)?;
+            if end < word.len() {
+                self.encode(&word[end..], result)?;
+            }
+        } else if let Some((_, code, rest)) = word_shortcut::split_word_shortcut(word) {
             result.extend(code);
             if !rest.is_empty() {
                 // Recursively encode the rest using the current encoder state
@@ -128,51 +366,47 @@ impl Encoder {
             let word_chars = word.chars().collect::<Vec<char>>();
             let word_len = word_chars.len();
             // 단어 전체가 대문자인지 확인(타 언어인 경우 반드시 false)
-            let uppercase_stats = word_chars.iter().filter(|c| c.is_ascii_alphabetic()).fold(
-                (0, 0),
-                |(letters, uppers), ch| {
-                    (letters + 1, uppers + if ch.is_uppercase() { 1 } else { 0 })
-                },
-            );
-            let is_all_uppercase = uppercase_stats.0 >= 2 && uppercase_stats.0 == uppercase_stats.1;
+            let is_all_uppercase = capital::is_all_uppercase(&word_chars);
+            let capital_marks = capital::capital_marks(&word_chars);
             let has_korean_char = word_chars
                 .iter()
                 .any(|c| 0xAC00 <= *c as u32 && *c as u32 <= 0xD7A3);
 
-            let has_ascii_alphabetic = word_chars.iter().any(|c| c.is_ascii_alphabetic());
+            let has_ascii_alphabetic = word_chars.iter().any(|c| utils::is_english_letter(*c));
             let mut pending_english_start =
                 self.english_indicator && !self.is_english && has_ascii_alphabetic;
-            if pending_english_start && word_chars[0].is_ascii_alphabetic() {
+            if pending_english_start && utils::is_english_letter(word_chars[0]) {
                 // 제31항 국어 문장 안에 그리스 문자가 나올 때에는 그 앞에 로마자표 ⠴을 적고 그 뒤에 로마자 종료표 ⠲을 적는다
                 self.enter_english(result);
                 pending_english_start = false;
             }
 
-            let first_ascii_index = word_chars.iter().position(|c| c.is_ascii_alphabetic());
+            let first_ascii_index = word_chars.iter().position(|c| utils::is_english_letter(*c));
             let ascii_starts_at_beginning = matches!(first_ascii_index, Some(0));
 
-            if is_all_uppercase && !self.triple_big_english && ascii_starts_at_beginning {
-                if (!self.has_processed_word || !prev_word.chars().all(|c| c.is_ascii_alphabetic()))
+            // 8점 점자에서는 대문자 기호표 없이 글자마다 7점을 더해 대문자를 나타낸다.
+            if is_all_uppercase
+                && !self.options.eight_dot
+                && !self.triple_big_english
+                && ascii_starts_at_beginning
+            {
+                if (!self.has_processed_word || !prev_word.chars().all(utils::is_english_letter))
                     && remaining_words.len() >= 2
-                    && remaining_words[0].chars().all(|c| c.is_ascii_alphabetic())
-                    && remaining_words[1].chars().all(|c| c.is_ascii_alphabetic())
+                    && remaining_words[0].chars().all(utils::is_english_letter)
+                    && remaining_words[1].chars().all(utils::is_english_letter)
                 {
                     self.triple_big_english = true;
-                    result.push(32);
-                    result.push(32);
-                    result.push(32);
+                    result.extend(capital::CAPITAL_PASSAGE);
                 } else if word_len >= 2 {
-                    // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 
+                    // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고,
                     // 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표 ⠠⠠을 그 앞에 적는다.
                     // 세 개 이상의 연속된 단어가 모두 대문자일 때에는 첫 단어
                     // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
-                    result.push(32);
-                    result.push(32);
+                    result.extend(capital::CAPITAL_WORD);
                 }
             }
 
             let mut is_number = false;
-            let mut is_big_english = false;
 
             for (i, c) in word_chars.iter().enumerate() {
                 if *skip_count > 0 {
@@ -181,7 +415,7 @@ impl Encoder {
                 }
 
                 if pending_english_start
-                    && (c.is_ascii_alphabetic()
+                    && (utils::is_english_letter(*c)
                         || (english_logic::should_render_symbol_as_english(
                             self.english_indicator,
                             self.is_english,
@@ -201,6 +435,8 @@ impl Encoder {
                 if self.english_indicator && self.is_english {
                     match &char_type {
                         CharType::English(_) => {}
+                        // ㎡의 지수처럼 로마자에 붙은 첨자는 로마자 구간 안에서 적는다.
+                        CharType::Script(_) => {}
                         CharType::Number(_) => {
                             // 제35항 로마자와 숫자가 이어 나올 때에는 로마자 종료표를 적지 않는다.
                             // 숫자 뒤에 로마자가 이어질 경우 연속표가 필요하므로 종료표 대신
@@ -218,10 +454,9 @@ impl Encoder {
                                 remaining_words,
                             ) {
                                 // 영어 문장 부호는 로마자 구간을 유지한다.
-                            } else if english_logic::should_force_terminator_before_symbol(*sym) {
-                                result.push(50);
-                                self.exit_english(false);
-                            } else if !english_logic::should_skip_terminator_for_symbol(*sym) {
+                            } else if english_logic::should_force_terminator_before_symbol(*sym)
+                                || !english_logic::should_skip_terminator_for_symbol(*sym)
+                            {
                                 result.push(50);
                                 self.exit_english(false);
                             } else {
@@ -276,6 +511,31 @@ impl Encoder {
                             rule_12(&korean, word_chars[i + 1], result)?;
                         }
                     }
+                    CharType::Kana(c) => {
+                        let (cells, len) = kana::encode_kana_run(&word_chars, i)
+                            .map_err(|e| format!("{e} after {c}"))?;
+                        if self.english_indicator && !self.is_kana {
+                            // 제31항 국어 문장 안의 외국 글자 구간은 ⠴과 ⠲ 사이에 적는다.
+                            result.push(kana::KANA_START);
+                            self.is_kana = true;
+                        }
+                        result.extend(cells);
+                        *skip_count = len - 1;
+                        // 띄어 쓴 다음 단어도 가나로 시작하면 구간을 이어 간다.
+                        let continues = i + len == word_len
+                            && remaining_words
+                                .first()
+                                .is_some_and(|w| w.starts_with(kana::is_kana));
+                        if self.is_kana && !continues {
+                            result.push(kana::KANA_END);
+                            self.is_kana = false;
+                        }
+                        self.needs_english_continuation = false;
+                    }
+                    CharType::OldKorean(c) => {
+                        self.needs_english_continuation = false;
+                        result.extend(old_hangul::encode_conjoining_jamo(c)?);
+                    }
                     CharType::KoreanPart(c) => {
                         self.needs_english_continuation = false;
                         match word_len {
@@ -332,96 +592,74 @@ impl Encoder {
                             self.enter_english(result);
                         }
 
-                        if (!is_all_uppercase || word_len < 2 || !ascii_starts_at_beginning)
-                            && !is_big_english
-                            && c.is_uppercase()
-                        {
-                            // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표
-                            // ⠠⠠을 그 앞에 적는다. 세 개 이상의 연속된 단어가 모두 대문자일 때에는 첫 단어
-                            // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
-                            is_big_english = true;
-
-                            for idx in 0..std::cmp::min(word_len - i, 2) {
-                                if word_chars[i + idx].is_uppercase() {
-                                    result.push(32);
+                        if self.options.eight_dot {
+                            // 8점 점자에서는 약자를 쓰지 않고 대문자에는 7점을 더한다.
+                            result.extend(english::encode_latin_letter_eight_dot(c)?);
+                        } else {
+                            if (!is_all_uppercase || word_len < 2 || !ascii_starts_at_beginning)
+                                && let Some(mark) = capital_marks[i]
+                            {
+                                // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표
+                                // ⠠⠠을 그 앞에 적는다. 대문자 단어표 뒤에 소문자가 이어지면 대문자 종료표 ⠠⠄을 적는다.
+                                result.extend(mark.cells());
+                            }
+                            // 약자가 대신하는 글자 가운데 대문자 표를 적어야 하는 글자가 있으면 약자를 쓰지 않는다.
+                            let can_contract = |len: usize| {
+                                capital_marks[i + 1..=i + len].iter().all(Option::is_none)
+                            };
+                            if !self.is_english || i == 0 {
+                                if !is_all_uppercase
+                                    && let Some((code, len)) = rule_en_10_6(
+                                        &word_chars[i..].iter().collect::<String>().to_lowercase(),
+                                    )
+                                    && can_contract(len)
+                                {
+                                    result.push(code);
+                                    *skip_count = len;
+                                } else if !is_all_uppercase
+                                    && let Some((code, len)) = rule_en_10_4(
+                                        &word_chars[i..].iter().collect::<String>().to_lowercase(),
+                                    )
+                                    && can_contract(len)
+                                {
+                                    result.push(code);
+                                    *skip_count = len;
                                 } else {
-                                    break;
+                                    result.extend(english::encode_latin_letter(c)?);
                                 }
-                            }
-                        }
-                        if !self.is_english || i == 0 {
-                            if !is_all_uppercase
-                                && let Some((code, len)) = rule_en_10_6(
-                                    &word_chars[i..].iter().collect::<String>().to_lowercase(),
-                                )
-                            {
-                                result.push(code);
-                                *skip_count = len;
-                            } else if !is_all_uppercase
-                                && let Some((code, len)) = rule_en_10_4(
-                                    &word_chars[i..].iter().collect::<String>().to_lowercase(),
-                                )
+                            } else if let Some((code, len)) = rule_en_10_4(
+                                &word_chars[i..].iter().collect::<String>().to_lowercase(),
+                            ) && can_contract(len)
                             {
                                 result.push(code);
                                 *skip_count = len;
                             } else {
-                                result.push(english::encode_english(c)?);
+                                result.extend(english::encode_latin_letter(c)?);
                             }
-                        } else if let Some((code, len)) =
-                            rule_en_10_4(&word_chars[i..].iter().collect::<String>().to_lowercase())
-                        {
-                            result.push(code);
-                            *skip_count = len;
-                        } else {
-                            result.push(english::encode_english(c)?);
                         }
                         self.is_english = true;
                         self.needs_english_continuation = false;
                     }
                     CharType::Number(c) => {
-                        if !is_number {
-                            let remaining_word: String = word_chars[i..].iter().collect();
-
-                            if let Some(captures) = FRACTION_REGEX.captures(&remaining_word) {
-                                let numerator = &captures[1];
-                                let denominator = &captures[2];
-                                let match_len = captures[0].len();
-                                let k = i + match_len;
-
-                                let is_date_or_range = 
-                                    (numerator.len() > 1 || denominator.len() > 1) ||
-                                    (k < word_len && word_chars[k] == '/') ||
-                                    (k < word_len && word_chars[k] == '~');
-                                
-                                if !is_date_or_range {
-                                    result.extend(fraction::encode_fraction_in_context(numerator, denominator)?);
-                                    *skip_count = match_len - 1; 
-                                    is_number = true;
-                                    continue;
-                                }
-                            }
-                             // 제43항 숫자 사이에 마침표, 쉼표, 연결표가 붙어 나올 때에는 뒤의 숫자에 수표를 적지 않는다.
-                            if !(i > 0 && ['.', ','].contains(&word_chars[i - 1])) {
-                                // 제40항 숫자는 수표 ⠼을 앞세워 다음과 같이 적는다.
-                                result.push(60);
-                                // 제61항 작은따옴표(')가 숫자 앞에 올 때는 수표와 작은따옴표를 함께 사용
-                                if i > 0 && (word_chars[i - 1] == '\'' || word_chars[i - 1] == '\u{2019}') {
-                                    result.push(4); // ⠄
-                                }
-                            }
+                        if !is_number && let Some(token) = number::tokenize_number(&word_chars, i) {
+                            // 제40항 숫자는 수표 ⠼을 앞세워 적고, 숫자 사이의 기호는 제41항, 제43항, 제48항에 따라 적는다.
+                            self.encode_number_token(&token, result)?;
+                            *skip_count = token.len - 1;
                             is_number = true;
-                        }      
-                        result.extend(number::encode_number(c));
-                    },
+                            continue;
+                        }
+                        result.push(if self.options.eight_dot {
+                            computer::encode_computer_char_eight_dot(c)?
+                        } else {
+                            number::encode_number(c)?
+                        });
+                    }
                     CharType::Fraction(c) => {
                         if let Some((num_str, den_str)) = fraction::parse_unicode_fraction(c) {
-                            result.extend(fraction::encode_fraction(
-                                &num_str,
-                                &den_str
-                            )?);
-                            is_number = true; 
+                            result.extend(fraction::encode_fraction(&num_str, &den_str)?);
+                            is_number = true;
                         }
-                    },
+                    }
                     CharType::Symbol(c) => {
                         let mut use_english_symbol = english_logic::should_render_symbol_as_english(
                             self.english_indicator,
@@ -450,6 +688,16 @@ impl Encoder {
                             continue;
                         }
 
+                        if matches!(c, '-' | '.')
+                            && let Some(token) = number::tokenize_number(&word_chars, i)
+                        {
+                            // 음수 부호나 소수점으로 시작하는 수는 숫자 구간으로 함께 적는다.
+                            self.encode_number_token(&token, result)?;
+                            *skip_count = token.len - 1;
+                            is_number = true;
+                            continue;
+                        }
+
                         let mut has_numeric_prefix = false;
                         let mut has_ascii_prefix = false;
                         if c == ',' {
@@ -459,7 +707,7 @@ impl Encoder {
                                 if prev.is_ascii_digit() {
                                     has_numeric_prefix = true;
                                     break;
-                                } else if prev.is_ascii_alphabetic() {
+                                } else if utils::is_english_letter(prev) {
                                     has_ascii_prefix = true;
                                     break;
                                 } else if prev == ' ' {
@@ -476,8 +724,8 @@ impl Encoder {
                             remaining_words.first().and_then(|w| w.chars().next())
                         };
                         let next_is_digit = next_char.is_some_and(|ch| ch.is_ascii_digit());
-                        let next_is_ascii = next_char.is_some_and(|ch| ch.is_ascii_alphabetic());
-                        let next_is_korean = next_char.is_some_and(|ch| utils::is_korean_char(ch));
+                        let next_is_ascii = next_char.is_some_and(utils::is_english_letter);
+                        let next_is_korean = next_char.is_some_and(utils::is_korean_char);
                         let next_is_alphanumeric = next_is_digit || next_is_ascii;
 
                         if c == ','
@@ -506,7 +754,10 @@ impl Encoder {
                                 }
                                 result.push(7);
                                 *skip_count = count - 1;
-                            } else if (c == '\'' || c == '\u{2019}') && i + 1 < word_len && word_chars[i + 1].is_ascii_digit() {
+                            } else if (c == '\'' || c == '\u{2019}')
+                                && i + 1 < word_len
+                                && word_chars[i + 1].is_ascii_digit()
+                            {
                                 // 제61항 작은따옴표(')가 숫자 앞에 올 때는 숫자 처리에서 함께 처리하므로 건너뛴다
                                 continue;
                             } else if c == '*' {
@@ -522,8 +773,35 @@ impl Encoder {
                             }
                         }
                     }
+                    CharType::Script(c) => {
+                        let (node, len) = script::parse_script_run(&word_chars, i)
+                            .ok_or_else(|| format!("Invalid script character: {}", c))?;
+                        math::MathEncoder::new(false)
+                            .encode(std::slice::from_ref(&node), result)?;
+                        *skip_count = len - 1;
+                        if word_chars
+                            .get(i + len)
+                            .is_some_and(|c| !utils::is_korean_char(*c))
+                        {
+                            // 첨자 뒤에 식이 이어지면 수식과 같이 기준선으로 돌아온다.
+                            // 한글이 이어지면 로마자 종료표로 식을 닫는다.
+                            result.push(math::BASELINE_INDICATOR);
+                        }
+                        is_number = false;
+                        continue;
+                    }
+                    CharType::Unit(c) => {
+                        // 단위와 화폐 기호는 앞뒤의 숫자에 붙여 쓴다.
+                        result.extend(unit::encode_unit(c)?);
+                    }
                     CharType::Space(c) => {
-                        result.push(if c == '\n' { 255 } else { 0 });
+                        result.push(if c == '\n' { unicode::NEWLINE } else { 0 });
+                    }
+                    CharType::Emphasis(c) => {
+                        if !self.emphasis {
+                            return Err("Invalid character".to_string());
+                        }
+                        result.extend(emphasis::encode_emphasis_mark(c)?);
                     }
                     CharType::MathSymbol(c) => {
                         if i > 0 && word_chars[..i].iter().any(|c| utils::is_korean_char(*c)) {
@@ -553,85 +831,78 @@ impl Encoder {
                 if !c.is_numeric() {
                     is_number = false;
                 }
-                if c.is_ascii_alphabetic() && !c.is_uppercase() {
-                    is_big_english = false;
-                }
             }
         }
 
         if self.triple_big_english
             && !(remaining_words
                 .first()
-                .is_some_and(|w| w.chars().all(|c| c.is_ascii_alphabetic())))
+                .is_some_and(|w| w.chars().all(utils::is_english_letter)))
         {
             // 28항 [붙임] 로마자가 한 글자만 대문자일 때에는 대문자 기호표 ⠠을 그 앞에 적고, 단어 전체가 대문자이거나 두 글자 이상 연속해서 대문자일 때에는 대문자 단어표
             // ⠠⠠을 그 앞에 적는다. 세 개 이상의 연속된 단어가 모두 대문자일 때에는 첫 단어
             // 앞에 대문자 구절표 ⠠⠠⠠을 적고, 마지막 단어 뒤에 대문자 종료표 ⠠⠄을 적는다.
-            result.push(32);
-            result.push(4);
+            result.extend(capital::CAPITAL_TERMINATOR);
             self.triple_big_english = false; // Reset after adding terminator
         }
         if !remaining_words.is_empty() {
-            if self.english_indicator && self.is_english {
-                if let Some(next_word) = remaining_words.first() {
-                    let ascii_letters = next_word
-                        .chars()
-                        .filter(|c| c.is_ascii_alphabetic())
-                        .collect::<Vec<_>>();
-                    let has_invalid_symbol = next_word.chars().any(|ch| {
-                        !(ch.is_ascii_alphabetic()
-                            || english_logic::is_english_symbol(ch)
-                            || symbol_shortcut::is_symbol_char(ch)
-                            || utils::is_korean_char(ch))
-                    });
-                    let is_single_letter_word = ascii_letters.len() == 1
-                        && !next_word.chars().any(|ch| ch.is_ascii_digit())
-                        && !has_invalid_symbol;
-
-                    if is_single_letter_word
-                        && english_logic::requires_single_letter_continuation(ascii_letters[0])
-                    {
-                        self.exit_english(true);
-                    } else if let Some(next_char) = next_word.chars().next() {
-                        if let Ok(next_type) = CharType::new(next_char) {
-                            match next_type {
-                                CharType::English(_) | CharType::Number(_) => {}
-                                CharType::Symbol(sym) => {
-                                    if self.english_indicator
-                                        && self.is_english
-                                        && english_logic::is_english_symbol(sym)
-                                    {
-                                        // 연속되는 영어 구절 사이에 오는 영어 문장 부호는
-                                        // 로마자 구간을 유지한다.
-                                    } else if english_logic::should_force_terminator_before_symbol(
-                                        sym,
-                                    ) {
-                                        result.push(50);
-                                        self.exit_english(false);
-                                    } else if !english_logic::should_skip_terminator_for_symbol(sym)
-                                    {
-                                        result.push(50);
-                                        self.exit_english(false);
-                                    } else {
-                                        self.exit_english(
-                                            english_logic::should_request_continuation(sym),
-                                        );
-                                    }
-                                }
-                                _ => {
+            if self.english_indicator
+                && self.is_english
+                && let Some(next_word) = remaining_words.first()
+            {
+                let ascii_letters = next_word
+                    .chars()
+                    .filter(|c| utils::is_english_letter(*c))
+                    .collect::<Vec<_>>();
+                let has_invalid_symbol = next_word.chars().any(|ch| {
+                    !(utils::is_english_letter(ch)
+                        || english_logic::is_english_symbol(ch)
+                        || symbol_shortcut::is_symbol_char(ch)
+                        || utils::is_korean_char(ch))
+                });
+                let is_single_letter_word = ascii_letters.len() == 1
+                    && !next_word.chars().any(|ch| ch.is_ascii_digit())
+                    && !has_invalid_symbol;
+
+                if is_single_letter_word
+                    && english_logic::requires_single_letter_continuation(ascii_letters[0])
+                {
+                    self.exit_english(true);
+                } else if let Some(next_char) = next_word.chars().next() {
+                    if let Ok(next_type) = CharType::new(next_char) {
+                        match next_type {
+                            CharType::English(_) | CharType::Number(_) => {}
+                            CharType::Symbol(sym) => {
+                                if self.english_indicator
+                                    && self.is_english
+                                    && english_logic::is_english_symbol(sym)
+                                {
+                                    // 연속되는 영어 구절 사이에 오는 영어 문장 부호는
+                                    // 로마자 구간을 유지한다.
+                                } else if english_logic::should_force_terminator_before_symbol(sym)
+                                    || !english_logic::should_skip_terminator_for_symbol(sym)
+                                {
                                     result.push(50);
                                     self.exit_english(false);
+                                } else {
+                                    self.exit_english(english_logic::should_request_continuation(
+                                        sym,
+                                    ));
                                 }
                             }
-                        } else {
-                            result.push(50);
-                            self.exit_english(false);
+                            _ => {
+                                result.push(50);
+                                self.exit_english(false);
+                            }
                         }
+                    } else {
+                        result.push(50);
+                        self.exit_english(false);
                     }
                 }
             }
 
-            result.push(0);
+            self.encode_gap(gap, false, result);
         } else {
             // word_shortcut을 사용한 경우가 아닐 때만 별표 확인
             let word_chars = word.chars().collect::<Vec<char>>();
@@ -640,12 +911,11 @@ impl Encoder {
             // 별표가 마지막 단어의 마지막 글자이고, 다음 단어가 없을 때 뒤에 공백 추가
             if remaining_words.is_empty() && word_len > 0 {
                 // 마지막 단어인 경우, 별표로 끝나는지 확인
-                if let Some(last_char) = word_chars.last() {
-                    if *last_char == '*' {
-                        result.push(0); // 별표 뒤에 공백 추가
-                    }
+                if word_chars.last() == Some(&'*') {
+                    result.push(0); // 별표 뒤에 공백 추가
                 }
             }
+            self.encode_gap(gap, true, result);
         }
 
         // Update state for next iteration
@@ -655,38 +925,180 @@ impl Encoder {
         Ok(())
     }
 
+    fn encode_number_token(
+        &mut self,
+        token: &number::NumberToken,
+        result: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        if self.options.eight_dot {
+            result.extend(number::encode_number_token_eight_dot(token)?);
+            return Ok(());
+        }
+        let (cells, breaks) = number::encode_number_token(token)?;
+        let offset = result.len();
+        self.line_breaks.extend(
+            breaks
+                .into_iter()
+                .map(|idx| LineBreak::Number(offset + idx)),
+        );
+        result.extend(cells);
+        Ok(())
+    }
+
+    /// 화학식 모드에서 화학식을 과학 점자로 적는다.
+    fn encode_formula(
+        &mut self,
+        tokens: &[chemistry::ChemToken],
+        result: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        let has_element = tokens
+            .iter()
+            .any(|t| matches!(t, chemistry::ChemToken::Element(_)));
+        if has_element && self.english_indicator && !self.is_english {
+            // 제31항 국어 문장 안의 로마자 앞에는 로마자표를 적는다.
+            self.enter_english(result);
+        }
+        chemistry::encode_formula(tokens, result)?;
+        self.needs_english_continuation = false;
+        Ok(())
+    }
+
+    /// 한자의 음을 한자표 사이에 적는다.
+    fn encode_hanja(&mut self, hanja: &str, result: &mut Vec<u8>) -> Result<(), String> {
+        let reading = hanja::hanja_reading(hanja)?;
+        result.extend(hanja::HANJA_OPEN);
+        self.encode(&reading, result)?;
+        result.extend(hanja::HANJA_CLOSE);
+        Ok(())
+    }
+
+    /// 단어 안의 웹 주소, 전자 우편 주소, 코드 구간을 컴퓨터 점자 구간으로 적는다.
+    fn encode_computer_span(
+        &mut self,
+        word: &str,
+        start: usize,
+        end: usize,
+        result: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        if start > 0 {
+            self.encode(&word[..start], result)?;
+        }
+        let cells = computer::encode_computer(&word[start..end], self.options.eight_dot)?;
+        if self.english_indicator && self.is_english {
+            result.push(50);
+            self.exit_english(false);
+        }
+        result.extend(computer::COMPUTER_START);
+        result.extend(cells);
+        result.extend(computer::COMPUTER_END);
+        self.needs_english_continuation = false;
+        if end < word.len() {
+            self.encode(&word[end..], result)?;
+        }
+        Ok(())
+    }
+
+    /// `$…$` 수식 구간을 수학 점자로 적는다.
+    fn encode_math(&mut self, span: &str, result: &mut Vec<u8>) -> Result<(), String> {
+        let nodes = latex::parse(span, &mut self.diagnostics)?;
+        if self.english_indicator && self.is_english {
+            result.push(50);
+            self.exit_english(false);
+        }
+        let mut math_encoder = math::MathEncoder::new(self.english_indicator);
+        math_encoder.encode(&nodes, result)?;
+        // 수식이 로마자로 끝났다면 뒤에 오는 글자에 따라 로마자 종료표를 적을 수 있도록 구간을 이어 둔다.
+        self.is_english = math_encoder.is_english();
+        self.needs_english_continuation = false;
+        Ok(())
+    }
+
     pub fn finish(&mut self, result: &mut Vec<u8>) -> Result<(), String> {
         // Handle any end-of-stream processing
         if self.triple_big_english {
             // Close triple big english if still active
-            result.push(32); // ⠠
-            result.push(4); // ⠄
+            result.extend(capital::CAPITAL_TERMINATOR);
         }
         Ok(())
     }
 }
 
 pub fn encode(text: &str) -> Result<Vec<u8>, String> {
-    // 한국어가 존재할 경우 english_indicator 가 true 가 됩니다.
-    let english_indicator = text
-        .split(' ')
-        .filter(|word| !word.is_empty())
-        .any(|word| word.chars().any(utils::is_korean_char));
+    encode_with_diagnostics(text).map(|(result, _)| result)
+}
+
+/// 점역 설정을 지정해 점역한다.
+pub fn encode_with_options(text: &str, options: &EncodeOptions) -> Result<Vec<u8>, String> {
+    encode_with_encoder(text, options).map(|(result, _)| result)
+}
+
+/// 점역 결과와 함께 지원하지 않는 LaTeX 명령처럼 오류 없이 넘어간 항목을 돌려준다.
+pub fn encode_with_diagnostics(text: &str) -> Result<(Vec<u8>, Vec<Diagnostic>), String> {
+    encode_with_encoder(text, &EncodeOptions::default())
+        .map(|(result, encoder)| (result, encoder.diagnostics))
+}
 
-    let mut encoder = Encoder::new(english_indicator);
+/// 점역 결과와 함께 입력의 단어마다 점역 결과에서 차지하는 위치를 돌려준다.
+pub fn encode_with_alignment(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<(Vec<u8>, Vec<WordAlignment>), String> {
+    encode_with_encoder(text, options).map(|(result, encoder)| (result, encoder.alignment))
+}
+
+/// 점역 결과와 함께 줄을 나눌 수 있는 위치를 돌려준다. [`wrap`]으로 줄을 나눌 때 사용한다.
+pub fn encode_with_line_breaks(text: &str) -> Result<(Vec<u8>, Vec<LineBreak>), String> {
+    encode_with_encoder(text, &EncodeOptions::default())
+        .map(|(result, encoder)| (result, encoder.line_breaks))
+}
+
+fn encode_with_encoder(text: &str, options: &EncodeOptions) -> Result<(Vec<u8>, Encoder), String> {
+    encode_text(text, options, false)
+}
+
+/// 마크다운, HTML, DOCX에서 읽은 블록의 글처럼 강조 구간 표시 문자가 들어 있는 글을 점역한다.
+pub(crate) fn encode_with_emphasis(
+    text: &str,
+    options: &EncodeOptions,
+) -> Result<(Vec<u8>, Encoder), String> {
+    encode_text(text, options, true)
+}
+
+fn encode_text(
+    text: &str,
+    options: &EncodeOptions,
+    emphasis: bool,
+) -> Result<(Vec<u8>, Encoder), String> {
+    let (text, source_map) = alignment::prepare(text, options)?;
+    let text = text.as_str();
+    let mut encoder = Encoder::with_options(false, options.clone());
+    encoder.emphasis = emphasis;
     let mut result = Vec::new();
-    encoder.encode(text, &mut result)?;
-    encoder.finish(&mut result)?;
-    
+    let join_lines = options.newlines == NewlinePolicy::Join;
+    for (paragraph, newlines) in tokenize::split_paragraphs(text, join_lines) {
+        // 한국어가 있는 문단에서만 로마자표를 쓴다. 영어로만 된 문단은 영어 점자 규칙대로 적는다.
+        let english_indicator = paragraph.chars().any(utils::is_korean_char);
+        encoder.start_paragraph(english_indicator);
+        let aligned = encoder.alignment.len();
+        encoder.encode(paragraph, &mut result)?;
+        encoder.finish(&mut result)?;
+        result.extend(std::iter::repeat_n(unicode::NEWLINE, newlines));
+        // 단어 위치를 문단 안에서 원래 입력 안으로 옮긴다.
+        let offset = paragraph.as_ptr() as usize - text.as_ptr() as usize;
+        for word in &mut encoder.alignment[aligned..] {
+            let source = word.source.start + offset..word.source.end + offset;
+            word.source = source_map.original(&source);
+        }
+    }
+
     // 제60항 별표(*)는 앞뒤를 한 칸씩 띄어 쓴다
     // 별표가 단독 단어로 포함된 텍스트의 마지막에 공백 추가
-    let words: Vec<&str> = text.split(' ').filter(|word| !word.is_empty()).collect();
-    let has_asterisk_as_word = words.iter().any(|w| *w == "*");
+    let has_asterisk_as_word = tokenize::tokenize(text).contains(&Token::Word("*"));
     if has_asterisk_as_word {
         result.push(0); // 별표가 단독 단어로 포함된 텍스트의 마지막에 공백 추가
     }
-    
-    Ok(result)
+
+    Ok((result, encoder))
 }
 
 pub fn encode_to_unicode(text: &str) -> Result<String, String> {
@@ -697,6 +1109,14 @@ pub fn encode_to_unicode(text: &str) -> Result<String, String> {
         .collect::<String>())
 }
 
+/// 점역 결과를 BRF 파일에 쓰는 점자 ASCII로 돌려준다.
+pub fn encode_to_brf(text: &str) -> Result<String, String> {
+    encode(text)?
+        .into_iter()
+        .map(unicode::encode_ascii)
+        .collect()
+}
+
 pub fn encode_to_braille_font(text: &str) -> Result<String, String> {
     let result = encode(text)?;
     Ok(result
@@ -828,6 +1248,402 @@ mod test {
         assert_eq!(encode_to_unicode("½").unwrap(), "⠼⠃⠌⠼⠁");
     }
 
+    #[test]
+    fn latex_span_with_spaces() {
+        assert_eq!(encode_to_unicode("$x + 1 = 3$").unwrap(), "⠭⠢⠼⠁⠒⠒⠼⠉");
+        assert_eq!(
+            encode_to_unicode("답은 $\\frac{1}{2}$ 이다.").unwrap(),
+            "⠊⠃⠵⠀⠼⠃⠌⠼⠁⠀⠕⠊⠲"
+        );
+        assert_eq!(
+            encode_to_unicode("$a + b$는 짝수").unwrap(),
+            "⠴⠁⠢⠃⠲⠉⠵⠀⠠⠨⠁⠠⠍"
+        );
+        // 짝이 맞지 않는 $는 수식이 아니라 화폐 기호로 적는다.
+        assert_eq!(encode_to_unicode("$5 and $10").unwrap(), "⠈⠎⠼⠑⠀⠯⠀⠈⠎⠼⠁⠚");
+    }
+
+    #[test]
+    fn enclosed_numbers() {
+        assert_eq!(encode_to_unicode("① 사과").unwrap(), "⠼⠁⠀⠇⠈⠧");
+        assert_eq!(encode_to_unicode("⑴ 목적").unwrap(), "⠦⠄⠼⠁⠠⠴⠀⠑⠭⠨⠹");
+        // 제9항 자음자가 번호로 쓰일 때에는 온표를 앞세워 받침으로 적는다.
+        assert_eq!(encode_to_unicode("㉠ 유아기").unwrap(), "⠿⠁⠀⠩⠣⠈⠕");
+        assert_eq!(encode_to_unicode("㈀ 유아기").unwrap(), "⠦⠄⠿⠁⠠⠴⠀⠩⠣⠈⠕");
+        assert_eq!(encode_to_unicode("제Ⅱ부").unwrap(), "⠨⠝⠴⠠⠠⠊⠊⠲⠘⠍");
+    }
+
+    #[test]
+    fn scripts_in_running_text() {
+        assert_eq!(encode_to_unicode("m²").unwrap(), "⠍⠘⠼⠃");
+        // 첨자 뒤에 기호가 이어져도 LaTeX 수식과 같이 기준선으로 돌아온다.
+        assert_eq!(encode_to_unicode("x²+y²").unwrap(), "⠭⠘⠼⠃⠐⠢⠽⠘⠼⠃");
+        assert_eq!(
+            encode_to_unicode("x²+y²").unwrap(),
+            encode_to_unicode("$x^2+y^2$").unwrap()
+        );
+        assert_eq!(encode_to_unicode("a₁b").unwrap(), "⠁⠰⠼⠁⠐⠃");
+    }
+
+    #[test]
+    fn chemistry_mode() {
+        let options = EncodeOptions {
+            chemistry: true,
+            ..Default::default()
+        };
+        let encode = |text: &str| {
+            encode_with_options(text, &options)
+                .unwrap()
+                .iter()
+                .map(|c| unicode::encode_unicode(*c))
+                .collect::<String>()
+        };
+        assert_eq!(encode("2H₂ + O₂ → 2H₂O"), "⠼⠃⠠⠓⠆⠀⠢⠀⠠⠕⠆⠀⠒⠒⠕⠀⠼⠃⠠⠓⠆⠠⠕");
+        assert_eq!(encode("물은 H2O이다."), "⠑⠯⠵⠀⠴⠠⠓⠆⠠⠕⠲⠕⠊⠲");
+        assert_eq!(encode("2H2 + O2 -> 2H2O"), "⠼⠃⠠⠓⠆⠀⠢⠀⠠⠕⠆⠀⠒⠒⠕⠀⠼⠃⠠⠓⠆⠠⠕");
+        assert_eq!(encode("CO"), "⠠⠉⠠⠕");
+        // 화학식 모드가 아니면 로마자와 숫자로 적는다.
+        assert_eq!(encode_to_unicode("H2O").unwrap(), "⠠⠠⠓⠼⠃⠕");
+    }
+
+    #[test]
+    fn conjoining_and_halfwidth_jamo() {
+        // macOS 파일 이름처럼 조합형 자모로 된 입력
+        assert_eq!(
+            encode_to_unicode("\u{1100}\u{1161}\u{11BC} \u{110B}\u{1161}\u{11AB}").unwrap(),
+            encode_to_unicode("강 안").unwrap()
+        );
+        assert_eq!(
+            encode_to_unicode("\u{FFA1}").unwrap(),
+            encode_to_unicode("ㄱ").unwrap()
+        );
+    }
+
+    #[test]
+    fn old_hangul() {
+        // ᄆᆞᅀᆞᆷ (마음)
+        assert_eq!(
+            encode_to_unicode("\u{1106}\u{119E}\u{1140}\u{119E}\u{11B7}").unwrap(),
+            "⠑⠸⠆⠸⠠⠸⠆⠢"
+        );
+        // 현대 음절과 섞여 쓰인 옛한글
+        assert_eq!(
+            encode_to_unicode("\u{1109}\u{119E}\u{11BC}과").unwrap(),
+            "⠠⠸⠆⠶⠈⠧"
+        );
+        assert_eq!(encode_to_unicode("ㆍ").unwrap(), "⠿⠸⠆");
+    }
+
+    #[test]
+    fn hanja_policy() {
+        let with_policy = |text: &str, hanja: HanjaPolicy| {
+            let options = EncodeOptions {
+                hanja,
+                ..Default::default()
+            };
+            encode_with_options(text, &options).map(|cells| {
+                cells
+                    .into_iter()
+                    .map(unicode::encode_unicode)
+                    .collect::<String>()
+            })
+        };
+        let text = "대한민국(大韓民國)";
+        assert!(encode_to_unicode(text).is_err());
+        assert_eq!(
+            with_policy(text, HanjaPolicy::Drop).unwrap(),
+            encode_to_unicode("대한민국").unwrap()
+        );
+        assert_eq!(
+            with_policy("國民의 義務", HanjaPolicy::Reading).unwrap(),
+            encode_to_unicode("국민의 의무").unwrap()
+        );
+        assert_eq!(
+            with_policy(text, HanjaPolicy::Indicator).unwrap(),
+            "⠊⠗⠚⠒⠑⠟⠈⠍⠁⠦⠄⠐⠆⠊⠗⠚⠒⠑⠟⠈⠍⠁⠰⠂⠠⠴"
+        );
+        assert!(with_policy("龘", HanjaPolicy::Reading).is_err());
+    }
+
+    #[test]
+    fn accented_latin_letters() {
+        assert_eq!(encode_to_unicode("café").unwrap(), "⠉⠁⠋⠘⠌⠑");
+        // 제31항 국어 문장 안의 로마자표와 로마자 종료표
+        assert_eq!(
+            encode_to_unicode("나는 café에 갔다.").unwrap(),
+            "⠉⠉⠵⠀⠴⠉⠁⠋⠘⠌⠑⠲⠝⠀⠫⠌⠊⠲"
+        );
+        assert_eq!(encode_to_unicode("Müller 씨").unwrap(), "⠴⠠⠍⠘⠒⠥⠇⠇⠻⠲⠀⠠⠠⠕");
+        assert_eq!(encode_to_unicode("ÉCOLE").unwrap(), "⠠⠠⠘⠌⠑⠉⠕⠇⠑");
+    }
+
+    #[test]
+    fn japanese_kana() {
+        // 일본어만 있는 문장은 외국 글자표 없이 적는다.
+        assert_eq!(encode_to_unicode("さくら").unwrap(), "⠱⠩⠑");
+        assert_eq!(
+            encode_to_unicode("일본어로 ありがとう는 고맙다는 뜻이다.").unwrap(),
+            "⠕⠂⠘⠷⠎⠐⠥⠀⠴⠁⠓⠐⠡⠞⠉⠲⠉⠵⠀⠈⠥⠑⠃⠊⠉⠵⠀⠠⠊⠪⠄⠕⠊⠲"
+        );
+        // 띄어 쓴 가나 단어는 한 구간으로 이어 적는다.
+        assert_eq!(
+            encode_to_unicode("こんにちは さようなら 안녕").unwrap(),
+            "⠴⠪⠴⠇⠗⠥⠀⠱⠜⠉⠅⠑⠲⠀⠣⠒⠉⠻"
+        );
+    }
+
+    #[test]
+    fn computer_braille() {
+        assert_eq!(
+            encode_to_unicode("주소는 https://braillify.kr입니다.").unwrap(),
+            "⠨⠍⠠⠥⠉⠵⠀⠸⠫⠓⠞⠞⠏⠎⠱⠌⠌⠃⠗⠁⠊⠇⠇⠊⠋⠽⠨⠅⠗⠸⠱⠕⠃⠉⠕⠊⠲"
+        );
+        assert_eq!(
+            encode_to_unicode("owjs39@gmail.com").unwrap(),
+            "⠸⠫⠕⠺⠚⠎⠒⠔⠈⠛⠍⠁⠊⠇⠨⠉⠕⠍⠸⠱"
+        );
+        assert_eq!(encode_to_unicode("a_b->c()").unwrap(), "⠸⠫⠁⠸⠃⠤⠜⠉⠷⠾⠸⠱");
+        // 역따옴표로 표시한 코드는 띄어쓰기를 포함해 한 구간으로 적는다.
+        assert_eq!(
+            encode_to_unicode("`git add -A`를 실행한다.").unwrap(),
+            "⠸⠫⠛⠊⠞⠀⠁⠙⠙⠀⠤⠠⠁⠸⠱⠐⠮⠀⠠⠕⠂⠚⠗⠶⠚⠒⠊⠲"
+        );
+        // 6점 컴퓨터 점자에서도 대문자 앞에는 대문자 기호를 적는다.
+        assert_eq!(
+            encode_to_unicode("https://Braillify.kr").unwrap(),
+            "⠸⠫⠓⠞⠞⠏⠎⠱⠌⠌⠠⠃⠗⠁⠊⠇⠇⠊⠋⠽⠨⠅⠗⠸⠱"
+        );
+        assert_eq!(encode_to_unicode("A&&B").unwrap(), "⠸⠫⠠⠁⠯⠯⠠⠃⠸⠱");
+        assert!(!encode_to_unicode("{x}").unwrap().starts_with("⠸⠫"));
+    }
+
+    #[test]
+    fn eight_dot_mode() {
+        let options = EncodeOptions {
+            eight_dot: true,
+            ..Default::default()
+        };
+        let encode = |text: &str| {
+            encode_with_options(text, &options)
+                .unwrap()
+                .into_iter()
+                .map(unicode::encode_unicode)
+                .collect::<String>()
+        };
+        assert_eq!(encode("Hello World"), "⡓⠑⠇⠇⠕⠀⡺⠕⠗⠇⠙");
+        assert_eq!(encode("ABC"), "⡁⡃⡉");
+        assert_eq!(encode("1,000원"), "⠂⠠⠴⠴⠴⠏⠒");
+        assert_eq!(encode("나는 Apple을 샀다."), "⠉⠉⠵⠀⠴⡁⠏⠏⠇⠑⠲⠮⠀⠇⠌⠊⠲");
+        // 한글은 6점 점자와 같다.
+        assert_eq!(
+            encode("안녕하세요"),
+            encode_to_unicode("안녕하세요").unwrap()
+        );
+    }
+
+    #[test]
+    fn capitals_inside_words() {
+        assert_eq!(encode_to_unicode("iPhone").unwrap(), "⠊⠠⠏⠓⠕⠝⠑");
+        assert_eq!(encode_to_unicode("YouTube").unwrap(), "⠠⠽⠳⠠⠞⠥⠃⠑");
+        assert_eq!(encode_to_unicode("McDonald").unwrap(), "⠠⠍⠉⠠⠙⠕⠝⠁⠇⠙");
+        assert_eq!(encode_to_unicode("COVID19").unwrap(), "⠠⠠⠉⠕⠧⠊⠙⠼⠁⠊");
+        assert_eq!(encode_to_unicode("PDFs").unwrap(), "⠠⠠⠏⠙⠋⠠⠄⠎");
+        assert_eq!(
+            encode_to_unicode("나는 iPhone을 샀다.").unwrap(),
+            "⠉⠉⠵⠀⠴⠊⠠⠏⠓⠕⠝⠑⠲⠮⠀⠇⠌⠊⠲"
+        );
+    }
+
+    #[test]
+    fn roman_indicator_per_paragraph() {
+        // 한국어가 없는 문단은 로마자표 없이 영어 점자로 적는다.
+        assert_eq!(
+            encode_to_unicode("안녕\nhello world").unwrap(),
+            "⠣⠒⠉⠻\n⠓⠑⠇⠇⠕⠀⠺⠕⠗⠇⠙"
+        );
+        assert_eq!(
+            encode_to_unicode("hello world\r\n나는 iPhone을 샀다.").unwrap(),
+            "⠓⠑⠇⠇⠕⠀⠺⠕⠗⠇⠙\n⠉⠉⠵⠀⠴⠊⠠⠏⠓⠕⠝⠑⠲⠮⠀⠇⠌⠊⠲"
+        );
+        // 로마자 구간과 대문자 구절표는 문단 끝에서 닫힌다.
+        assert_eq!(
+            encode_to_unicode("안녕 hello\nWORLD IS BIG").unwrap(),
+            "⠣⠒⠉⠻⠀⠴⠓⠑⠇⠇⠕\n⠠⠠⠠⠺⠕⠗⠇⠙⠀⠊⠎⠀⠃⠊⠛⠠⠄"
+        );
+    }
+
+    #[test]
+    fn emphasis_and_brf() {
+        let (cells, _) =
+            encode_with_emphasis("\u{E000}수도\u{E001}이다", &EncodeOptions::default()).unwrap();
+        assert_eq!(
+            cells
+                .iter()
+                .map(|c| unicode::encode_unicode(*c))
+                .collect::<String>(),
+            "⠠⠤⠠⠍⠊⠥⠤⠄⠕⠊"
+        );
+        // 공개된 점역 함수는 강조 구간 표시 문자를 받지 않는다.
+        assert!(encode("\u{E000}수도\u{E001}이다").is_err());
+        assert!(encode("수도\u{E001}").is_err());
+        assert_eq!(encode_to_brf("안녕").unwrap(), "<3C]");
+    }
+
+    #[test]
+    fn word_alignment() {
+        let options = EncodeOptions {
+            hanja: HanjaPolicy::Reading,
+            ..Default::default()
+        };
+        let text = "國民 상\n\n나";
+        let (cells, alignment) = encode_with_alignment(text, &options).unwrap();
+        let words: Vec<_> = alignment
+            .iter()
+            .map(|word| {
+                let braille: String = cells[word.cells.clone()]
+                    .iter()
+                    .map(|c| unicode::encode_unicode(*c))
+                    .collect();
+                (&text[word.source.clone()], braille)
+            })
+            .collect();
+        assert_eq!(
+            words,
+            [
+                ("國民", "⠈⠍⠁⠑⠟".to_string()),
+                ("상", "⠇⠶".to_string()),
+                ("나", "⠉".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn encoder_prepares_words() {
+        let encode_directly = |text: &str, hanja: HanjaPolicy| {
+            let options = EncodeOptions {
+                hanja,
+                ..Default::default()
+            };
+            let mut encoder = Encoder::with_options(false, options);
+            let mut result = Vec::new();
+            encoder.encode(text, &mut result)?;
+            encoder.finish(&mut result)?;
+            Ok::<_, String>(
+                result
+                    .into_iter()
+                    .map(unicode::encode_unicode)
+                    .collect::<String>(),
+            )
+        };
+        assert_eq!(
+            encode_directly("國民 상", HanjaPolicy::Reading).unwrap(),
+            encode_to_unicode("국민 상").unwrap()
+        );
+        assert_eq!(
+            encode_directly("가 國 나", HanjaPolicy::Drop).unwrap(),
+            encode_to_unicode("가 나").unwrap()
+        );
+        assert!(encode_directly("國民", HanjaPolicy::Error).is_err());
+        // 조합형 자모와 반각 자모도 정규화한다.
+        assert_eq!(
+            encode_directly("\u{1100}\u{1161} \u{FFA1}", HanjaPolicy::Indicator).unwrap(),
+            encode_to_unicode("가 ㄱ").unwrap()
+        );
+    }
+
+    #[test]
+    fn whitespace_policies() {
+        let with_options = |text: &str, spaces: SpacePolicy, newlines: NewlinePolicy| {
+            let options = EncodeOptions {
+                spaces,
+                newlines,
+                ..Default::default()
+            };
+            encode_with_options(text, &options)
+                .unwrap()
+                .into_iter()
+                .map(unicode::encode_unicode)
+                .collect::<String>()
+        };
+        let text = "  가   나\t다\n라";
+        assert_eq!(
+            with_options(text, SpacePolicy::Collapse, NewlinePolicy::Keep),
+            "⠫⠀⠉⠀⠊\n⠐⠣"
+        );
+        assert_eq!(
+            with_options(text, SpacePolicy::Preserve, NewlinePolicy::Keep),
+            "⠀⠀⠫⠀⠀⠀⠉⠀⠀⠊\n⠐⠣"
+        );
+        assert_eq!(
+            with_options(text, SpacePolicy::Collapse, NewlinePolicy::Join),
+            "⠫⠀⠉⠀⠊⠀⠐⠣"
+        );
+        assert_eq!(
+            with_options("가\n\n나", SpacePolicy::Collapse, NewlinePolicy::Join),
+            "⠫\n\n⠉"
+        );
+        // 줄을 바꾸지 않는 공백은 빈칸으로 적되 줄을 나눌 수 있는 곳으로 기록하지 않는다.
+        let (cells, breaks) = encode_with_line_breaks("10\u{00A0}kg 가").unwrap();
+        assert_eq!(
+            cells
+                .into_iter()
+                .map(unicode::encode_unicode)
+                .collect::<String>(),
+            encode_to_unicode("10 kg 가").unwrap()
+        );
+        assert_eq!(breaks.len(), 1);
+        // 폭 없는 문자는 적지 않는다.
+        assert_eq!(
+            encode_to_unicode("한\u{200B}글\u{FEFF}").unwrap(),
+            encode_to_unicode("한글").unwrap()
+        );
+    }
+
+    #[test]
+    fn units_and_currency() {
+        assert_eq!(encode_to_unicode("30%").unwrap(), "⠼⠉⠚⠨⠴");
+        assert_eq!(
+            encode_to_unicode("기온은 25℃이다.").unwrap(),
+            "⠈⠕⠷⠵⠀⠼⠃⠑⠘⠚⠠⠉⠕⠊⠲"
+        );
+        assert_eq!(encode_to_unicode("₩1,000").unwrap(), "⠈⠺⠼⠁⠂⠚⠚⠚");
+        assert_eq!(
+            encode_to_unicode("거리는 5㎞이다.").unwrap(),
+            "⠈⠎⠐⠕⠉⠵⠀⠼⠑⠴⠅⠍⠲⠕⠊⠲"
+        );
+        assert_eq!(
+            encode_to_unicode("넓이는 10㎡이다.").unwrap(),
+            "⠉⠞⠃⠕⠉⠵⠀⠼⠁⠚⠴⠍⠘⠼⠃⠲⠕⠊⠲"
+        );
+    }
+
+    #[test]
+    fn number_tokens() {
+        assert_eq!(encode_to_unicode("-5도").unwrap(), "⠔⠼⠑⠀⠊⠥");
+        assert_eq!(encode_to_unicode("14:30").unwrap(), "⠼⠁⠙⠐⠂⠼⠉⠚");
+        assert_eq!(encode_to_unicode("3.14").unwrap(), "⠼⠉⠲⠁⠙");
+        assert_eq!(encode_to_unicode("1,000원").unwrap(), "⠼⠁⠂⠚⠚⠚⠏⠒");
+        assert_eq!(encode_to_unicode("1~3").unwrap(), "⠼⠁⠈⠔⠼⠉");
+        assert_eq!(encode_to_unicode("2024.3.1.").unwrap(), "⠼⠃⠚⠃⠙⠲⠉⠲⠁⠲");
+    }
+
+    #[test]
+    fn latex_unsupported_command_is_diagnostic() {
+        let (result, diagnostics) = encode_with_diagnostics("$\\overline{AB}$").unwrap();
+        assert_eq!(
+            result
+                .iter()
+                .map(|c| unicode::encode_unicode(*c))
+                .collect::<String>(),
+            "⠠⠁⠠⠃"
+        );
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::UnsupportedLatexCommand("overline".to_string())]
+        );
+        assert!(encode_with_diagnostics("$x^2$").unwrap().1.is_empty());
+    }
+
     #[test]
     fn english_continuation_after_inline_number() {
         let output = encode("가 a1a").unwrap();
@@ -842,7 +1658,7 @@ mod test {
         let output = encode("(A 가").unwrap();
         let english_symbol = symbol_shortcut::encode_english_char_symbol_shortcut('(').unwrap();
         assert_eq!(output[0], 52);
-        assert!(output.len() >= 1 + english_symbol.len());
+        assert!(output.len() > english_symbol.len());
         assert_eq!(
             &output[1..1 + english_symbol.len()],
             english_symbol,
@@ -856,7 +1672,7 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("a/", "", &[], &mut skip, &mut result)
+            .encode_word("a/", "", &[], &[], &mut skip, &mut result)
             .unwrap();
         let slash = symbol_shortcut::encode_char_symbol_shortcut('/').unwrap();
         let slash_pos = result
@@ -864,13 +1680,17 @@ mod test {
             .position(|window| window == slash)
             .unwrap();
         assert!(slash_pos > 0);
-        assert_eq!(result[slash_pos - 1], 50, "forced symbol should add terminator");
+        assert_eq!(
+            result[slash_pos - 1],
+            50,
+            "forced symbol should add terminator"
+        );
 
         let mut encoder = Encoder::new(true);
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("a_b", "", &[], &mut skip, &mut result)
+            .encode_word("a_b", "", &[], &[], &mut skip, &mut result)
             .unwrap();
         let underscore = symbol_shortcut::encode_char_symbol_shortcut('_').unwrap();
         let underscore_pos = result
@@ -891,13 +1711,11 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("A ,가", "", &[], &mut skip, &mut result)
+            .encode_word("A ,가", "", &[], &[], &mut skip, &mut result)
             .unwrap();
         let comma = symbol_shortcut::encode_char_symbol_shortcut(',').unwrap();
         assert!(
-            result
-                .windows(comma.len())
-                .any(|window| window == comma),
+            result.windows(comma.len()).any(|window| window == comma),
             "comma before Korean should use Korean punctuation mapping"
         );
 
@@ -905,7 +1723,7 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("A!,가", "", &[], &mut skip, &mut result)
+            .encode_word("A!,가", "", &[], &[], &mut skip, &mut result)
             .unwrap();
     }
 
@@ -915,7 +1733,7 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("a", "", &["b"], &mut skip, &mut result)
+            .encode_word("a", "", &["b"], &[Blank::Space], &mut skip, &mut result)
             .unwrap();
         assert!(encoder.needs_english_continuation);
         assert_eq!(result.last(), Some(&0));
@@ -927,7 +1745,7 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("a", "", &["/"], &mut skip, &mut result)
+            .encode_word("a", "", &["/"], &[Blank::Space], &mut skip, &mut result)
             .unwrap();
         assert!(
             result.contains(&50),
@@ -939,7 +1757,7 @@ mod test {
         let mut result = Vec::new();
         let mut skip = 0;
         encoder
-            .encode_word("a", "", &["."], &mut skip, &mut result)
+            .encode_word("a", "", &["."], &[Blank::Space], &mut skip, &mut result)
             .unwrap();
         assert!(
             encoder.needs_english_continuation,
@@ -1040,7 +1858,7 @@ mod test {
                             input.to_string(),
                             record[3].to_string(),
                             braille_expected.clone(),
-                            record[3].to_string() == braille_expected,
+                            record[3] == braille_expected,
                         ));
                     }
                     Err(e) => {
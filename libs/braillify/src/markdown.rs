@@ -0,0 +1,163 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    document::{Block, BlockBuilder, DocumentOptions, render_blocks},
+    emphasis::{BOLD_CLOSE, BOLD_OPEN, EMPHASIS_CLOSE, EMPHASIS_OPEN},
+};
+
+fn start(builder: &mut BlockBuilder, tag: Tag) {
+    match tag {
+        Tag::Paragraph => builder.start_paragraph(),
+        Tag::TableCell => builder.start_cell(),
+        Tag::Heading { .. } => builder.start_heading(),
+        Tag::BlockQuote(_) => builder.start_quote(),
+        Tag::CodeBlock(_) => builder.start_code(),
+        Tag::List(start) => builder.start_list(start),
+        Tag::Item => builder.start_item(),
+        Tag::TableHead | Tag::TableRow => builder.start_row(),
+        Tag::Emphasis => builder.push_mark(EMPHASIS_OPEN),
+        Tag::Strong => builder.push_mark(BOLD_OPEN),
+        _ => {}
+    }
+}
+
+fn end(builder: &mut BlockBuilder, tag: TagEnd) {
+    match tag {
+        TagEnd::Paragraph => builder.flush(),
+        TagEnd::Heading(_) => builder.end_heading(),
+        TagEnd::BlockQuote(_) => builder.end_quote(),
+        TagEnd::CodeBlock => builder.end_code(),
+        TagEnd::List(_) => builder.end_list(),
+        TagEnd::Item => builder.end_item(),
+        TagEnd::TableCell => builder.end_cell(),
+        TagEnd::TableHead | TagEnd::TableRow => builder.end_row(),
+        TagEnd::Emphasis => builder.push_mark(EMPHASIS_CLOSE),
+        TagEnd::Strong => builder.push_mark(BOLD_CLOSE),
+        _ => {}
+    }
+}
+
+/// 마크다운을 문서 블록으로 바꾼다.
+///
+/// 제목, 문단, 목록, 인용문, 코드 묶음, 표는 같은 블록으로 바꾸고, 기울임(`*…*`)은
+/// 제56항 드러냄표로, 굵은 글씨(`**…**`)는 굵은 글자 표로 강조한다. 코드 표시(`` `…` ``)는
+/// 그대로 두어 컴퓨터 점자로 적고, 링크와 그림은 글과 대체 글만 남긴다. 취소선은 나타내는
+/// 점자 표가 없으므로 `~~…~~`를 글 그대로 둔다.
+pub fn parse_markdown(text: &str) -> Vec<Block> {
+    let mut builder = BlockBuilder::default();
+    for event in Parser::new_ext(text, Options::ENABLE_TABLES) {
+        match event {
+            Event::Start(tag) => start(&mut builder, tag),
+            Event::End(tag) => end(&mut builder, tag),
+            Event::Text(text) => builder.push_str(&text),
+            Event::Code(code) => builder.push_str(&format!("`{code}`")),
+            Event::SoftBreak | Event::HardBreak => builder.push_str(" "),
+            Event::Rule => builder.rule(),
+            _ => {}
+        }
+    }
+    builder.finish()
+}
+
+/// 마크다운을 점자 편집 규칙에 맞게 줄마다 나누어 점역한다.
+pub fn encode_markdown(text: &str, options: &DocumentOptions) -> Result<Vec<Vec<u8>>, String> {
+    render_blocks(&parse_markdown(text), options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph(text.to_string())
+    }
+
+    #[test]
+    fn test_parse_structure() {
+        let markdown = "# 제목\n\n첫 문단\n이어짐\n\n> 인용\n\n```\nls -a\n```\n";
+        assert_eq!(
+            parse_markdown(markdown),
+            vec![
+                Block::Heading("제목".to_string()),
+                paragraph("첫 문단 이어짐"),
+                Block::Quote("인용".to_string()),
+                Block::Code("ls -a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        let markdown = "- 사과\n  1. 부사\n  2. 홍로\n- 배\n\n3. 셋\n\n   둘째 문단\n";
+        assert_eq!(
+            parse_markdown(markdown),
+            vec![
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "사과".to_string()
+                },
+                Block::ListItem {
+                    depth: 1,
+                    marker: Some("1.".to_string()),
+                    text: "부사".to_string()
+                },
+                Block::ListItem {
+                    depth: 1,
+                    marker: Some("2.".to_string()),
+                    text: "홍로".to_string()
+                },
+                Block::ListItem {
+                    depth: 0,
+                    marker: None,
+                    text: "배".to_string()
+                },
+                Block::ListItem {
+                    depth: 0,
+                    marker: Some("3.".to_string()),
+                    text: "셋 둘째 문단".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_inline_markup() {
+        assert_eq!(
+            parse_markdown("서울은 **대한민국**의 *수도*이다. `ls`를 [보라](https://a.kr)."),
+            vec![paragraph(
+                "서울은 \u{E002}대한민국\u{E003}의 \u{E000}수도\u{E001}이다. `ls`를 보라."
+            )]
+        );
+        assert_eq!(parse_markdown("~~삭제~~"), vec![paragraph("~~삭제~~")]);
+    }
+
+    #[test]
+    fn test_parse_table() {
+        assert_eq!(
+            parse_markdown("| 이름 | 값 |\n|---|---|\n| 가 | 1 |\n"),
+            vec![
+                Block::TableRow(vec!["이름".to_string(), "값".to_string()]),
+                Block::TableRow(vec!["가".to_string(), "1".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_markdown() {
+        let lines = encode_markdown(
+            "서울은 대한민국의 **수도**이다.",
+            &DocumentOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            lines
+                .iter()
+                .map(|line| line.iter().map(|c| encode_unicode(*c)).collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["⠀⠀⠠⠎⠯⠵⠀⠊⠗⠚⠒⠑⠟⠈⠍⠁⠺⠀⠰⠤⠠⠍⠊⠥⠤⠆⠕⠊⠲"]
+        );
+        assert!(encode_markdown("~~삭제~~", &DocumentOptions::default()).is_ok());
+    }
+}
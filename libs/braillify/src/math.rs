@@ -0,0 +1,254 @@
+use phf::phf_map;
+
+use crate::{
+    english, fraction, latex::MathNode, math_symbol_shortcut, number, symbol_shortcut,
+    unicode::decode_unicode,
+};
+
+/// 통일영어점자의 그리스 문자. 그리스 문자표 ⠨ 뒤에 적는다.
+static GREEK_MAP: phf::Map<char, u8> = phf_map! {
+    'α' => decode_unicode('⠁'),
+    'β' => decode_unicode('⠃'),
+    'γ' => decode_unicode('⠛'),
+    'δ' => decode_unicode('⠙'),
+    'ε' => decode_unicode('⠑'),
+    'ζ' => decode_unicode('⠵'),
+    'η' => decode_unicode('⠱'),
+    'θ' => decode_unicode('⠹'),
+    'ι' => decode_unicode('⠊'),
+    'κ' => decode_unicode('⠅'),
+    'λ' => decode_unicode('⠇'),
+    'μ' => decode_unicode('⠍'),
+    'ν' => decode_unicode('⠝'),
+    'ξ' => decode_unicode('⠭'),
+    'π' => decode_unicode('⠏'),
+    'ρ' => decode_unicode('⠗'),
+    'σ' => decode_unicode('⠎'),
+    'τ' => decode_unicode('⠞'),
+    'υ' => decode_unicode('⠥'),
+    'φ' => decode_unicode('⠋'),
+    'χ' => decode_unicode('⠯'),
+    'ψ' => decode_unicode('⠽'),
+    'ω' => decode_unicode('⠺'),
+};
+
+const GREEK_INDICATOR: u8 = decode_unicode('⠨');
+pub(crate) const SUPERSCRIPT_INDICATOR: u8 = decode_unicode('⠘');
+const SUBSCRIPT_INDICATOR: u8 = decode_unicode('⠰');
+pub(crate) const BASELINE_INDICATOR: u8 = decode_unicode('⠐');
+const ROOT_START: u8 = decode_unicode('⠜');
+const ROOT_END: u8 = decode_unicode('⠻');
+
+#[derive(Clone, Copy, PartialEq)]
+enum Previous {
+    None,
+    Number,
+    Letter,
+    Other,
+}
+
+/// 구문 분석한 수식을 점자로 옮긴다.
+pub struct MathEncoder {
+    english_indicator: bool,
+    /// 이 수식 안에서 로마자표를 이미 적었는지 여부
+    is_english: bool,
+    previous: Previous,
+}
+
+impl MathEncoder {
+    pub fn new(english_indicator: bool) -> Self {
+        Self {
+            english_indicator,
+            is_english: false,
+            previous: Previous::None,
+        }
+    }
+
+    /// 수식이 로마자로 끝나 로마자 구간이 열려 있는지 여부
+    pub fn is_english(&self) -> bool {
+        self.is_english && self.previous == Previous::Letter
+    }
+
+    pub fn encode(&mut self, nodes: &[MathNode], result: &mut Vec<u8>) -> Result<(), String> {
+        for (i, node) in nodes.iter().enumerate() {
+            self.encode_node(node, result)?;
+            if matches!(node, MathNode::Superscript(_) | MathNode::Subscript(_))
+                && i + 1 < nodes.len()
+                && !matches!(
+                    nodes[i + 1],
+                    MathNode::Superscript(_) | MathNode::Subscript(_)
+                )
+            {
+                // 첨자 뒤에 식이 이어지면 기준선으로 돌아온다.
+                result.push(BASELINE_INDICATOR);
+                self.previous = Previous::Other;
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_node(&mut self, node: &MathNode, result: &mut Vec<u8>) -> Result<(), String> {
+        match node {
+            MathNode::Number(digits) => {
+                // 제40항 숫자는 수표 ⠼을 앞세워 적고, 제48항 소수점은 ⠲으로 적는다.
+                result.push(60);
+                for c in digits.chars() {
+                    if c == '.' {
+                        result.push(50);
+                    } else {
+                        result.push(number::encode_number(c)?);
+                    }
+                }
+                self.previous = Previous::Number;
+            }
+            MathNode::Letter(c) => {
+                if self.previous != Previous::Letter {
+                    if self.english_indicator && !self.is_english {
+                        // 제31항 국어 문장 안의 로마자 앞에는 로마자표를 적는다.
+                        result.push(52);
+                        self.is_english = true;
+                    } else if self.previous == Previous::Number {
+                        // 제35항 숫자 뒤에 로마자가 이어지면 연속표를 적어 숫자와 구별한다.
+                        result.push(48);
+                    }
+                }
+                if c.is_ascii_uppercase() {
+                    result.push(32);
+                }
+                result.push(english::encode_english(*c)?);
+                self.previous = Previous::Letter;
+            }
+            MathNode::Greek(c) => {
+                let lower = c.to_lowercase().next().unwrap_or(*c);
+                let Some(code) = GREEK_MAP.get(&lower) else {
+                    return Err("Invalid Greek character".to_string());
+                };
+                if lower != *c {
+                    result.push(32);
+                }
+                result.push(GREEK_INDICATOR);
+                result.push(*code);
+                self.previous = Previous::Other;
+            }
+            MathNode::Operator(c) => {
+                result.extend(math_symbol_shortcut::encode_char_math_symbol_shortcut(*c)?);
+                self.previous = Previous::Other;
+            }
+            MathNode::Symbol(c) => {
+                result.extend(symbol_shortcut::encode_char_symbol_shortcut(*c)?);
+                self.previous = Previous::Other;
+            }
+            MathNode::Group(nodes) => self.encode(nodes, result)?,
+            MathNode::Fraction(numerator, denominator) => {
+                if let ([MathNode::Number(n)], [MathNode::Number(d)]) =
+                    (numerator.as_slice(), denominator.as_slice())
+                    && !n.contains('.')
+                    && !d.contains('.')
+                {
+                    // 제47항 분수는 분모, 분수표, 분자 순으로 적는다.
+                    result.extend(fraction::encode_fraction(n, d)?);
+                } else {
+                    self.encode_fraction_part(denominator, result)?;
+                    result.push(12);
+                    self.previous = Previous::Other;
+                    self.encode_fraction_part(numerator, result)?;
+                }
+                self.previous = Previous::Number;
+            }
+            MathNode::Root(index, radicand) => {
+                if let Some(index) = index {
+                    result.push(SUPERSCRIPT_INDICATOR);
+                    self.encode(index, result)?;
+                }
+                result.push(ROOT_START);
+                self.previous = Previous::Other;
+                self.encode(radicand, result)?;
+                result.push(ROOT_END);
+                self.previous = Previous::Other;
+            }
+            MathNode::Superscript(nodes) | MathNode::Subscript(nodes) => {
+                result.push(if matches!(node, MathNode::Superscript(_)) {
+                    SUPERSCRIPT_INDICATOR
+                } else {
+                    SUBSCRIPT_INDICATOR
+                });
+                self.previous = Previous::Other;
+                self.encode(nodes, result)?;
+            }
+            MathNode::Text(text) => {
+                result.extend(crate::encode(text)?);
+                self.is_english = false;
+                self.previous = Previous::Other;
+            }
+        }
+        Ok(())
+    }
+
+    /// 분자나 분모가 두 개 이상의 항으로 되어 있으면 괄호로 묶는다.
+    fn encode_fraction_part(
+        &mut self,
+        nodes: &[MathNode],
+        result: &mut Vec<u8>,
+    ) -> Result<(), String> {
+        if nodes.len() > 1 {
+            result.extend(symbol_shortcut::encode_char_symbol_shortcut('(')?);
+            self.previous = Previous::Other;
+            self.encode(nodes, result)?;
+            result.extend(symbol_shortcut::encode_char_symbol_shortcut(')')?);
+        } else {
+            self.encode(nodes, result)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{latex, unicode::encode_unicode};
+
+    fn encode_span(span: &str, english_indicator: bool) -> String {
+        let mut diagnostics = Vec::new();
+        let nodes = latex::parse(span, &mut diagnostics).unwrap();
+        let mut result = Vec::new();
+        MathEncoder::new(english_indicator)
+            .encode(&nodes, &mut result)
+            .unwrap();
+        result.iter().map(|c| encode_unicode(*c)).collect()
+    }
+
+    #[test]
+    fn test_encode_numbers_and_operators() {
+        assert_eq!(encode_span("$3+4=7$", false), "⠼⠉⠢⠼⠙⠒⠒⠼⠛");
+        assert_eq!(encode_span("$3.14$", false), "⠼⠉⠲⠁⠙");
+        assert_eq!(encode_span("$6 \\div 2$", false), "⠼⠋⠌⠌⠼⠃");
+    }
+
+    #[test]
+    fn test_encode_letters() {
+        assert_eq!(encode_span("$x+y$", false), "⠭⠢⠽");
+        assert_eq!(encode_span("$x+y$", true), "⠴⠭⠢⠽");
+        assert_eq!(encode_span("$2a$", false), "⠼⠃⠰⠁");
+        assert_eq!(encode_span("$2a$", true), "⠼⠃⠴⠁");
+        assert_eq!(encode_span("$a+2b$", true), "⠴⠁⠢⠼⠃⠰⠃");
+        assert_eq!(encode_span("$A$", false), "⠠⠁");
+    }
+
+    #[test]
+    fn test_encode_fractions() {
+        assert_eq!(encode_span("$\\frac{3}{4}$", false), "⠼⠙⠌⠼⠉");
+        assert_eq!(encode_span("$3\\frac{1}{4}$", false), "⠼⠉⠼⠙⠌⠼⠁");
+        assert_eq!(encode_span("$\\frac{x}{2}$", false), "⠼⠃⠌⠭");
+        assert_eq!(encode_span("$\\frac{x+1}{2}$", false), "⠼⠃⠌⠦⠄⠭⠢⠼⠁⠠⠴");
+    }
+
+    #[test]
+    fn test_encode_scripts_roots_and_greek() {
+        assert_eq!(encode_span("$x^2$", false), "⠭⠘⠼⠃");
+        assert_eq!(encode_span("$x^2+1$", false), "⠭⠘⠼⠃⠐⠢⠼⠁");
+        assert_eq!(encode_span("$a_1$", false), "⠁⠰⠼⠁");
+        assert_eq!(encode_span("$\\sqrt{2}$", false), "⠜⠼⠃⠻");
+        assert_eq!(encode_span("$2\\pi r$", false), "⠼⠃⠨⠏⠗");
+        assert_eq!(encode_span("$\\Omega$", false), "⠠⠨⠺");
+    }
+}
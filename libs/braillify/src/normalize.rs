@@ -0,0 +1,164 @@
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::old_hangul::is_conjoining_jamo;
+
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// 현대 한글 첫소리 자모(U+1100~U+1112)에 해당하는 호환 자모
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 현대 한글 끝소리 자모(U+11A8~U+11C2)에 해당하는 호환 자모
+const JONGSEONG: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+fn is_choseong(c: u32) -> bool {
+    (L_BASE..L_BASE + L_COUNT).contains(&c)
+}
+
+fn is_jungseong(c: u32) -> bool {
+    (V_BASE..V_BASE + V_COUNT).contains(&c)
+}
+
+fn is_jongseong(c: u32) -> bool {
+    (T_BASE + 1..T_BASE + T_COUNT).contains(&c)
+}
+
+fn needs_normalization(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{FFA0}'..='\u{FFDC}')
+}
+
+/// 채움 문자를 뺀 조합형 자모
+fn is_cluster_jamo(c: char) -> bool {
+    is_conjoining_jamo(c) && !matches!(c, '\u{115F}' | '\u{1160}')
+}
+
+/// 조합형 자모(U+1100~U+11FF) 하나를 호환 자모로 바꾼다.
+///
+/// 옛한글 자모처럼 대응하는 호환 자모가 없는 글자는 그대로 둔다.
+pub(crate) fn conjoining_to_compatibility(c: char) -> char {
+    let code = c as u32;
+    if is_choseong(code) {
+        CHOSEONG[(code - L_BASE) as usize]
+    } else if is_jungseong(code) {
+        // 호환 자모의 모음(ㅏ~ㅣ)은 조합형 자모와 같은 순서이다.
+        char::from_u32(0x314F + code - V_BASE).unwrap_or(c)
+    } else if is_jongseong(code) {
+        JONGSEONG[(code - T_BASE - 1) as usize]
+    } else {
+        c
+    }
+}
+
+/// 점역하기 전에 입력의 한글 자모를 정규화한다.
+///
+/// macOS 파일 이름이나 일부 PDF에서 온 조합형 자모(NFD)는 음절로 합치고, 음절로 합쳐지지
+/// 않는 조합형 자모와 반각 자모(U+FFA0~U+FFDC)는 호환 자모로 바꾸어 한글 낱자로 점역되도록 한다.
+pub fn normalize(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(needs_normalization) {
+        return Cow::Borrowed(text);
+    }
+
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < chars.len() {
+        let c = chars[idx];
+        let code = c as u32;
+        let next = chars.get(idx + 1).map(|c| *c as u32);
+        if is_choseong(code) && next.is_some_and(is_jungseong) {
+            let l = code - L_BASE;
+            let v = next.unwrap() - V_BASE;
+            let mut s = S_BASE + (l * V_COUNT + v) * T_COUNT;
+            idx += 2;
+            if let Some(t) = chars
+                .get(idx)
+                .map(|c| *c as u32)
+                .filter(|t| is_jongseong(*t))
+            {
+                s += t - T_BASE;
+                idx += 1;
+            }
+            result.push(char::from_u32(s).unwrap());
+            continue;
+        }
+        if (S_BASE..S_BASE + S_COUNT).contains(&code)
+            && (code - S_BASE).is_multiple_of(T_COUNT)
+            && next.is_some_and(is_jongseong)
+        {
+            // 받침 없는 음절 뒤에 끝소리 자모가 따로 온 경우
+            result.push(char::from_u32(code + next.unwrap() - T_BASE).unwrap());
+            idx += 2;
+            continue;
+        }
+        match c {
+            // 채움 문자
+            '\u{115F}' | '\u{1160}' | '\u{FFA0}' => {}
+            // 옛한글 음절을 이루는 자모는 그대로 두고, 홀로 쓰인 자모만 호환 자모로 바꾼다.
+            '\u{1100}'..='\u{11FF}'
+                if (idx == 0 || !is_cluster_jamo(chars[idx - 1]))
+                    && !chars.get(idx + 1).is_some_and(|c| is_cluster_jamo(*c)) =>
+            {
+                result.push(conjoining_to_compatibility(c))
+            }
+            // 반각 자모는 호환 분해하면 조합형 자모가 된다.
+            '\u{FFA1}'..='\u{FFDC}' => result.extend(c.nfkc().map(conjoining_to_compatibility)),
+            _ => result.push(c),
+        }
+        idx += 1;
+    }
+    Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compose_conjoining_jamo() {
+        let nfd = "한글".nfd().collect::<String>();
+        assert_eq!(nfd.chars().count(), 6);
+        assert_eq!(normalize(&nfd), "한글");
+        assert_eq!(normalize("\u{1100}\u{1161} 나"), "가 나");
+        // 받침 없는 음절 + 끝소리 자모
+        assert_eq!(normalize("가\u{11AB}"), "간");
+    }
+
+    #[test]
+    fn test_lone_jamo_to_compatibility() {
+        assert_eq!(normalize("\u{1100}"), "ㄱ");
+        assert_eq!(normalize("\u{1175}"), "ㅣ");
+        assert_eq!(normalize("\u{11AF}"), "ㄹ");
+        assert_eq!(normalize("\u{FFA1}\u{FFC2}"), "ㄱㅏ");
+        assert_eq!(normalize("\u{115F}\u{1161}"), "ㅏ");
+    }
+
+    #[test]
+    fn test_old_hangul_clusters_are_kept() {
+        // ᄀᆞᆯ: 아래아가 들어간 옛한글 음절은 합치지 않고 그대로 둔다.
+        assert_eq!(
+            normalize("\u{1100}\u{119E}\u{11AF}"),
+            "\u{1100}\u{119E}\u{11AF}"
+        );
+        assert_eq!(normalize("\u{1140}\u{1175}"), "\u{1140}\u{1175}");
+    }
+
+    #[test]
+    fn test_unchanged_text_is_borrowed() {
+        assert!(matches!(normalize("안녕 abc"), Cow::Borrowed(_)));
+    }
+}
@@ -23,3 +23,320 @@ pub fn encode_number(text: char) -> Result<u8, String> {
     }
     Err("Invalid number character".to_string())
 }
+
+/// 숫자 구간을 이루는 요소
+#[derive(Debug, PartialEq)]
+pub enum NumberPart {
+    Digits(String),
+    /// 제41항 숫자 사이에 붙어 나오는 쉼표와 자릿점
+    Separator,
+    /// 제48항 소수점
+    DecimalPoint,
+    /// 제43항 [다만] 숫자 사이에 붙어 나오는 그 밖의 기호(연결표, 물결표, 빗금, 쌍점, 가운뎃점).
+    /// 뒤의 숫자에는 수표를 다시 적는다.
+    Joiner(char),
+}
+
+/// 한 번에 점역할 숫자 구간
+#[derive(Debug, PartialEq)]
+pub struct NumberToken {
+    /// 음수 부호(-)로 시작하는지 여부
+    pub negative: bool,
+    /// 제61항 작은따옴표(’)로 연도를 줄여 쓴 경우
+    pub apostrophe: bool,
+    pub parts: Vec<NumberPart>,
+    /// 구간이 차지하는 글자 수 (음수 부호 포함, 앞의 작은따옴표 제외)
+    pub len: usize,
+}
+
+fn is_joiner(c: char) -> bool {
+    matches!(c, '-' | '~' | '∼' | '/' | ':' | '·')
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+/// `chars[start..]`에서 시작하는 숫자 구간을 찾는다.
+///
+/// 숫자, 또는 숫자가 뒤따르는 음수 부호(-)나 소수점(.)에서 시작해야 한다.
+/// 숫자 사이에 붙어 나오는 쉼표, 마침표, 연결표, 물결표, 빗금, 쌍점, 가운뎃점까지 한 구간으로 묶는다.
+pub fn tokenize_number(chars: &[char], start: usize) -> Option<NumberToken> {
+    let digit_at = |idx: usize| chars.get(idx).is_some_and(|c| c.is_ascii_digit());
+    let prev = start.checked_sub(1).map(|idx| chars[idx]);
+    let after_number = prev.is_some_and(|c| c.is_ascii_alphanumeric());
+
+    let mut idx = start;
+    let mut negative = false;
+    let mut parts = Vec::new();
+    match chars.get(start) {
+        Some(c) if c.is_ascii_digit() => {}
+        Some('-') if digit_at(start + 1) && !after_number => {
+            negative = true;
+            idx += 1;
+        }
+        Some('.') if digit_at(start + 1) && !after_number && prev != Some('.') => {
+            parts.push(NumberPart::DecimalPoint);
+            idx += 1;
+        }
+        _ => return None,
+    }
+
+    loop {
+        let digits_start = idx;
+        while digit_at(idx) {
+            idx += 1;
+        }
+        parts.push(NumberPart::Digits(
+            chars[digits_start..idx].iter().collect(),
+        ));
+
+        let Some(&next) = chars.get(idx) else {
+            break;
+        };
+        if !digit_at(idx + 1) {
+            break;
+        }
+        parts.push(match next {
+            ',' => NumberPart::Separator,
+            '.' => NumberPart::DecimalPoint,
+            c if is_joiner(c) => NumberPart::Joiner(c),
+            _ => break,
+        });
+        idx += 1;
+    }
+
+    Some(NumberToken {
+        negative,
+        apostrophe: prev.is_some_and(is_apostrophe),
+        parts,
+        len: idx - start,
+    })
+}
+
+/// 제42항 줄을 나누어 적을 수 있는 가장 짧은 수의 자릿수
+const MIN_BREAKABLE_DIGITS: usize = 7;
+/// 제42항 아래 줄에 나와야 하는 최소 자릿수
+const MIN_DIGITS_AFTER_BREAK: usize = 3;
+
+/// 제42항 한 수 안에서 줄을 나눌 수 있는 위치를 찾는다.
+///
+/// `digit_offsets`는 한 수를 이루는 숫자들의 점역 결과 안 위치이다.
+/// 바로 앞 칸도 숫자인 위치만 고르고, 아래 줄에 세 자리 이상이 남도록 한다.
+fn push_number_breaks(digit_offsets: &[usize], breaks: &mut Vec<usize>) {
+    if digit_offsets.len() < MIN_BREAKABLE_DIGITS {
+        return;
+    }
+    for (idx, window) in digit_offsets.windows(2).enumerate() {
+        if window[1] == window[0] + 1 && digit_offsets.len() - (idx + 1) >= MIN_DIGITS_AFTER_BREAK {
+            breaks.push(window[1]);
+        }
+    }
+}
+
+/// 숫자 구간을 점자로 적는다.
+///
+/// 점역 결과와 함께 제42항에 따라 줄을 나눌 수 있는 위치(결과 안의 오프셋)를 돌려준다.
+/// 그 위치 앞에서 줄을 바꾸면 위 줄 끝에 연결표 ⠠을 적고, 아래 줄에는 수표를 다시 적지 않는다.
+pub fn encode_number_token(token: &NumberToken) -> Result<(Vec<u8>, Vec<usize>), String> {
+    if let [
+        NumberPart::Digits(numerator),
+        NumberPart::Joiner('/'),
+        NumberPart::Digits(denominator),
+    ] = token.parts.as_slice()
+        && numerator.len() == 1
+        && denominator.len() == 1
+        && !token.negative
+    {
+        // 제47항 분수를 표시하는 빗금(/)은 ⠸⠌으로 적고, 순서는 묵자를 따른다.
+        return Ok((
+            crate::fraction::encode_fraction_in_context(numerator, denominator)?,
+            Vec::new(),
+        ));
+    }
+
+    let mut result = Vec::new();
+    let mut breaks = Vec::new();
+    let mut digit_offsets = Vec::new();
+    if token.negative {
+        // 음수 부호는 빼기표 ⠔으로 적는다.
+        result.push(decode_unicode('⠔'));
+    }
+    // 제40항 숫자는 수표 ⠼을 앞세워 적는다.
+    result.push(decode_unicode('⠼'));
+    if token.apostrophe {
+        // 제61항 작은따옴표(')가 숫자 앞에 올 때는 수표 뒤에 ⠄을 적는다.
+        result.push(decode_unicode('⠄'));
+    }
+    for part in &token.parts {
+        match part {
+            NumberPart::Digits(digits) => {
+                for c in digits.chars() {
+                    digit_offsets.push(result.len());
+                    result.push(encode_number(c)?);
+                }
+            }
+            // 제41항, 제43항 뒤의 숫자에 수표를 적지 않는다.
+            NumberPart::Separator => result.push(decode_unicode('⠂')),
+            NumberPart::DecimalPoint => result.push(decode_unicode('⠲')),
+            NumberPart::Joiner(c) => {
+                // 제43항 [다만] 그 밖의 기호 뒤에는 수표를 다시 적는다.
+                result.extend(crate::symbol_shortcut::encode_char_symbol_shortcut(*c)?);
+                result.push(decode_unicode('⠼'));
+                push_number_breaks(&digit_offsets, &mut breaks);
+                digit_offsets.clear();
+            }
+        }
+    }
+    push_number_breaks(&digit_offsets, &mut breaks);
+    Ok((result, breaks))
+}
+
+/// 8점 점자로 숫자를 적는다. 숫자와 숫자 사이의 기호는 8점 컴퓨터 점자로 적고 수표는 적지 않는다.
+pub fn encode_number_token_eight_dot(token: &NumberToken) -> Result<Vec<u8>, String> {
+    let mut text = String::new();
+    if token.negative {
+        text.push('-');
+    }
+    if token.apostrophe {
+        text.push('\'');
+    }
+    let mut result = Vec::new();
+    for part in &token.parts {
+        match part {
+            NumberPart::Digits(digits) => text.push_str(digits),
+            NumberPart::Separator => text.push(','),
+            NumberPart::DecimalPoint => text.push('.'),
+            NumberPart::Joiner(c) if c.is_ascii() => text.push(*c),
+            NumberPart::Joiner(c) => {
+                // 컴퓨터 점자에 없는 기호(∼, ·)는 6점 점자의 기호로 적는다.
+                for c in text.drain(..) {
+                    result.push(crate::computer::encode_computer_char_eight_dot(c)?);
+                }
+                result.extend(crate::symbol_shortcut::encode_char_symbol_shortcut(*c)?);
+            }
+        }
+    }
+    for c in text.chars() {
+        result.push(crate::computer::encode_computer_char_eight_dot(c)?);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(text: &str) -> (String, usize) {
+        let chars = text.chars().collect::<Vec<_>>();
+        let token = tokenize_number(&chars, 0).unwrap();
+        let (cells, _) = encode_number_token(&token).unwrap();
+        (
+            cells.iter().map(|c| encode_unicode(*c)).collect(),
+            token.len,
+        )
+    }
+
+    #[test]
+    fn test_tokenize_number_parts() {
+        let chars = "1,234.5원".chars().collect::<Vec<_>>();
+        assert_eq!(
+            tokenize_number(&chars, 0),
+            Some(NumberToken {
+                negative: false,
+                apostrophe: false,
+                parts: vec![
+                    NumberPart::Digits("1".to_string()),
+                    NumberPart::Separator,
+                    NumberPart::Digits("234".to_string()),
+                    NumberPart::DecimalPoint,
+                    NumberPart::Digits("5".to_string()),
+                ],
+                len: 7,
+            })
+        );
+        assert_eq!(tokenize_number(&chars, 7), None);
+    }
+
+    #[test]
+    fn test_encode_number_token_eight_dot() {
+        let encode = |text: &str| {
+            let chars = text.chars().collect::<Vec<_>>();
+            let token = tokenize_number(&chars, 0).unwrap();
+            encode_number_token_eight_dot(&token)
+                .unwrap()
+                .into_iter()
+                .map(encode_unicode)
+                .collect::<String>()
+        };
+        assert_eq!(encode("1,234.5"), "⠂⠠⠆⠒⠲⠨⠢");
+        assert_eq!(encode("-3"), "⠤⠒");
+        assert_eq!(encode("1/2"), "⠂⠌⠆");
+    }
+
+    #[test]
+    fn test_tokenize_number_stops_at_trailing_symbol() {
+        assert_eq!(encode_str("3.14."), ("⠼⠉⠲⠁⠙".to_string(), 4));
+        assert_eq!(encode_str("10,"), ("⠼⠁⠚".to_string(), 2));
+        assert_eq!(encode_str("5-"), ("⠼⠑".to_string(), 1));
+    }
+
+    #[test]
+    fn test_encode_grouped_and_decimal() {
+        assert_eq!(encode_str("5,700,000").0, "⠼⠑⠂⠛⠚⠚⠂⠚⠚⠚");
+        assert_eq!(encode_str("0.48").0, "⠼⠚⠲⠙⠓");
+        assert_eq!(encode_str(".5").0, "⠼⠲⠑");
+    }
+
+    #[test]
+    fn test_encode_negative() {
+        assert_eq!(encode_str("-5").0, "⠔⠼⠑");
+        assert_eq!(encode_str("-0.5").0, "⠔⠼⠚⠲⠑");
+        let chars = "a-5".chars().collect::<Vec<_>>();
+        assert_eq!(tokenize_number(&chars, 1), None);
+    }
+
+    #[test]
+    fn test_encode_joined_numbers() {
+        assert_eq!(encode_str("02-2669-9775~6").0, "⠼⠚⠃⠤⠼⠃⠋⠋⠊⠤⠼⠊⠛⠛⠑⠈⠔⠼⠋");
+        assert_eq!(encode_str("10/1~10/9").0, "⠼⠁⠚⠸⠌⠼⠁⠈⠔⠼⠁⠚⠸⠌⠼⠊");
+        assert_eq!(encode_str("2024.3.1").0, "⠼⠃⠚⠃⠙⠲⠉⠲⠁");
+        assert_eq!(encode_str("14:30").0, "⠼⠁⠙⠐⠂⠼⠉⠚");
+        assert_eq!(encode_str("8·15").0, "⠼⠓⠐⠆⠼⠁⠑");
+        assert_eq!(encode_str("1/2").0, "⠼⠁⠸⠌⠼⠃");
+    }
+
+    #[test]
+    fn test_encode_apostrophe_year() {
+        let chars = "’88".chars().collect::<Vec<_>>();
+        let token = tokenize_number(&chars, 1).unwrap();
+        assert!(token.apostrophe);
+        assert_eq!(
+            encode_number_token(&token)
+                .unwrap()
+                .0
+                .iter()
+                .map(|c| encode_unicode(*c))
+                .collect::<String>(),
+            "⠼⠄⠓⠓"
+        );
+    }
+
+    #[test]
+    fn test_number_line_breaks() {
+        let chars = "1234567".chars().collect::<Vec<_>>();
+        let token = tokenize_number(&chars, 0).unwrap();
+        // ⠼ 뒤 두 번째 숫자부터 아래 줄에 세 자리가 남는 다섯 번째 숫자까지
+        assert_eq!(encode_number_token(&token).unwrap().1, vec![2, 3, 4, 5]);
+
+        let chars = "123456".chars().collect::<Vec<_>>();
+        let token = tokenize_number(&chars, 0).unwrap();
+        assert!(encode_number_token(&token).unwrap().1.is_empty());
+
+        // 자릿점 바로 뒤에서는 나누지 않는다.
+        let chars = "1,234,567".chars().collect::<Vec<_>>();
+        let token = tokenize_number(&chars, 0).unwrap();
+        assert_eq!(encode_number_token(&token).unwrap().1, vec![4, 5]);
+    }
+}
@@ -0,0 +1,141 @@
+use phf::phf_map;
+
+use crate::{
+    jauem::{choseong::encode_choseong, jongseong::encode_jongseong},
+    moeum::jungsong::encode_jungsong,
+    normalize::conjoining_to_compatibility,
+    unicode::decode_unicode,
+};
+
+/// 옛 글자표. 옛 글자는 옛 글자표 뒤에 가장 가까운 현대 글자의 점형을 적어 나타낸다.
+const OLD_LETTER: u8 = decode_unicode('⠸');
+
+/// 옛 첫소리 글자 (조합형 자모)
+static OLD_CHOSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    // ㅿ 반치음
+    'ᅀ' => &[OLD_LETTER, decode_unicode('⠠')],
+    // ㆁ 옛이응
+    'ᅌ' => &[OLD_LETTER, decode_unicode('⠶')],
+    // ㆆ 여린히읗
+    'ᅙ' => &[OLD_LETTER, decode_unicode('⠚')],
+    // ㅸ 순경음 비읍
+    'ᄫ' => &[OLD_LETTER, decode_unicode('⠘')],
+    // ㅹ 순경음 쌍비읍
+    'ᄬ' => &[OLD_LETTER, decode_unicode('⠠'), decode_unicode('⠘')],
+    // ㆄ 순경음 피읖
+    'ᅗ' => &[OLD_LETTER, decode_unicode('⠙')],
+    // ㅱ 순경음 미음
+    'ᄝ' => &[OLD_LETTER, decode_unicode('⠑')],
+};
+
+/// 옛 가운뎃소리 글자 (조합형 자모)
+static OLD_JUNGSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    // ㆍ 아래아
+    'ᆞ' => &[OLD_LETTER, decode_unicode('⠆')],
+    // ㆎ 아래아 + ㅣ
+    'ᆡ' => &[OLD_LETTER, decode_unicode('⠆'), decode_unicode('⠕')],
+    // 쌍아래아
+    'ᆢ' => &[OLD_LETTER, decode_unicode('⠆'), OLD_LETTER, decode_unicode('⠆')],
+};
+
+/// 옛 끝소리 글자 (조합형 자모)
+static OLD_JONGSEONG_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    'ᇫ' => &[OLD_LETTER, decode_unicode('⠄')],
+    'ᇰ' => &[OLD_LETTER, decode_unicode('⠶')],
+    'ᇹ' => &[OLD_LETTER, decode_unicode('⠴')],
+    'ᇦ' => &[OLD_LETTER, decode_unicode('⠃')],
+    'ᇴ' => &[OLD_LETTER, decode_unicode('⠲')],
+    'ᇢ' => &[OLD_LETTER, decode_unicode('⠢')],
+};
+
+/// 제8항 단독으로 쓰인 옛 자모 (호환 자모). 자음자는 받침으로 적는다.
+pub static OLD_KOREAN_PART_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    'ㅿ' => &[OLD_LETTER, decode_unicode('⠄')],
+    'ㆁ' => &[OLD_LETTER, decode_unicode('⠶')],
+    'ㆆ' => &[OLD_LETTER, decode_unicode('⠴')],
+    'ㅸ' => &[OLD_LETTER, decode_unicode('⠃')],
+    'ㅹ' => &[OLD_LETTER, decode_unicode('⠃'), decode_unicode('⠃')],
+    'ㆄ' => &[OLD_LETTER, decode_unicode('⠲')],
+    'ㅱ' => &[OLD_LETTER, decode_unicode('⠢')],
+    'ㆍ' => &[OLD_LETTER, decode_unicode('⠆')],
+    'ㆎ' => &[OLD_LETTER, decode_unicode('⠆'), decode_unicode('⠕')],
+};
+
+/// 조합형 자모인지 여부 (한글 자모, 한글 자모 확장 A·B)
+pub fn is_conjoining_jamo(c: char) -> bool {
+    matches!(c, '\u{1100}'..='\u{11FF}' | '\u{A960}'..='\u{A97F}' | '\u{D7B0}'..='\u{D7FF}')
+}
+
+/// 옛한글 음절을 이루는 조합형 자모 하나를 점자로 적는다.
+///
+/// 현대 자모는 자리(첫소리, 가운뎃소리, 끝소리)에 맞는 점형으로, 옛 자모는 옛 글자표를 앞세워 적는다.
+/// 음절 단위 약자는 옛한글 음절에 적용하지 않는다.
+pub fn encode_conjoining_jamo(c: char) -> Result<Vec<u8>, String> {
+    let invalid = || "Invalid old Hangul character".to_string();
+    let (old_map, position) = match c {
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97F}' => (&OLD_CHOSEONG_MAP, 0),
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => (&OLD_JUNGSEONG_MAP, 1),
+        _ => (&OLD_JONGSEONG_MAP, 2),
+    };
+    if let Some(code) = old_map.get(&c) {
+        return Ok(code.to_vec());
+    }
+    let modern = conjoining_to_compatibility(c);
+    if modern == c {
+        return Err(invalid());
+    }
+    match position {
+        0 => match modern {
+            // 첫소리 ㅇ은 적지 않는다.
+            'ㅇ' => Ok(Vec::new()),
+            // 제2항 된소리 글자는 된소리표를 앞세워 적는다.
+            'ㄲ' | 'ㄸ' | 'ㅃ' | 'ㅆ' | 'ㅉ' => {
+                let base = match modern {
+                    'ㄲ' => 'ㄱ',
+                    'ㄸ' => 'ㄷ',
+                    'ㅃ' => 'ㅂ',
+                    'ㅆ' => 'ㅅ',
+                    _ => 'ㅈ',
+                };
+                Ok(vec![decode_unicode('⠠'), encode_choseong(base)?])
+            }
+            _ => Ok(vec![encode_choseong(modern)?]),
+        },
+        1 => Ok(encode_jungsong(modern)?.to_vec()),
+        _ => Ok(encode_jongseong(modern)?.to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::encode_unicode;
+
+    fn encode_str(text: &str) -> String {
+        text.chars()
+            .flat_map(|c| encode_conjoining_jamo(c).unwrap())
+            .map(encode_unicode)
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_old_syllables() {
+        // ᄀᆞᆯ (ᄀ + 아래아 + ㄹ)
+        assert_eq!(encode_str("\u{1100}\u{119E}\u{11AF}"), "⠈⠸⠆⠂");
+        // ᄉᆞᆼ (ㅅ + 아래아 + ㅇ)
+        assert_eq!(encode_str("\u{1109}\u{119E}\u{11BC}"), "⠠⠸⠆⠶");
+        // ᅀᅵ (반치음 + ㅣ)
+        assert_eq!(encode_str("\u{1140}\u{1175}"), "⠸⠠⠕");
+        // ᅌᅵ (옛이응 + ㅣ)
+        assert_eq!(encode_str("\u{114C}\u{1175}"), "⠸⠶⠕");
+        // ᄭ처럼 대응하는 점형이 없는 자모
+        assert!(encode_conjoining_jamo('\u{112D}').is_err());
+    }
+
+    #[test]
+    fn test_is_conjoining_jamo() {
+        assert!(is_conjoining_jamo('\u{1100}'));
+        assert!(is_conjoining_jamo('\u{A960}'));
+        assert!(!is_conjoining_jamo('ㄱ'));
+    }
+}
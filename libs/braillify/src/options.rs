@@ -0,0 +1,53 @@
+/// 점역 설정
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EncodeOptions {
+    /// 화학식 모드. `H2O`, `SO₄²⁻`처럼 원소 기호로 된 단어를 화학식으로 보고
+    /// 아래 첨자, 이온의 전하, 반응 화살표를 과학 점자로 적는다.
+    pub chemistry: bool,
+    /// 입력에 한자가 있을 때의 처리 방법
+    pub hanja: HanjaPolicy,
+    /// 8점 점자 출력. 로마자 대문자는 7점을 더해 적고 숫자와 컴퓨터 점자 구간은
+    /// 북미 8점 컴퓨터 점자 코드를 따르며, 대문자 기호표와 수표, 영어 약자를 쓰지 않는다.
+    pub eight_dot: bool,
+    /// 연속된 공백과 탭을 적는 방법
+    pub spaces: SpacePolicy,
+    /// 문단 안의 줄바꿈을 적는 방법
+    pub newlines: NewlinePolicy,
+}
+
+/// 공백 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum SpacePolicy {
+    /// 단어 사이의 공백과 탭은 몇 개든 한 칸으로 적고, 줄의 앞뒤 공백은 적지 않는다.
+    #[default]
+    Collapse,
+    /// 공백은 개수대로, 탭은 두 칸으로 적는다. 줄 첫머리의 들여쓰기도 그대로 둔다.
+    Preserve,
+}
+
+/// 줄바꿈 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum NewlinePolicy {
+    /// 줄바꿈마다 점자에서도 줄을 바꾼다.
+    #[default]
+    Keep,
+    /// 줄바꿈 하나는 띄어쓰기로 보고, 빈 줄이 있는 곳에서만 줄을 바꾼다.
+    Join,
+}
+
+/// 한자 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum HanjaPolicy {
+    /// 한자가 있으면 오류를 낸다.
+    #[default]
+    Error,
+    /// 한자를 지운다. `대한민국(大韓民國)`처럼 괄호 안에 함께 적은 한자는 괄호까지 지운다.
+    Drop,
+    /// 한자를 한글 음으로 바꾸어 적는다.
+    Reading,
+    /// 한자의 음을 한자표 사이에 적는다.
+    Indicator,
+}
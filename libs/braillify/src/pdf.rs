@@ -0,0 +1,366 @@
+use std::fmt::Write;
+
+use crate::{
+    EncodeOptions,
+    geometry::{CellDimensions, dot_rows, format_mm, raised_dots},
+    interlinear::{InterlinearWord, interlinear_lines},
+};
+
+/// 1mm를 PDF의 단위(1/72인치)로 나타낸 길이
+const POINTS_PER_MM: f64 = 72.0 / 25.4;
+/// 원을 네 개의 베지어 곡선으로 그릴 때 조절점까지의 거리 비율
+const CIRCLE_CONTROL: f64 = 0.552_284_75;
+/// 한글을 적는 글꼴. PDF에 넣지 않고 보는 프로그램의 Adobe-Korea1 글꼴로 대신한다.
+const KOREAN_FONT: &str = "HYGoThic-Medium";
+/// 쪽보다 앞에 적는 문서 목록과 글꼴 객체의 수. 쪽마다 쪽 객체와 내용 흐름 객체를 이어 적는다.
+const SHARED_OBJECTS: usize = 6;
+
+/// 교정용 PDF 설정. 길이는 모두 mm이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfOptions {
+    /// 점을 그리는 크기와 간격. 점 번호와 묵자를 적을 수 있도록 실제 점자보다 크게 그린다.
+    pub dimensions: CellDimensions,
+    /// 쪽의 너비
+    pub page_width: f64,
+    /// 쪽의 높이
+    pub page_height: f64,
+    /// 쪽 둘레의 여백
+    pub margin: f64,
+    /// 칸 아래에 적는 점 번호의 글자 크기
+    pub number_size: f64,
+    /// 단어 아래에 적는 묵자의 글자 크기
+    pub print_size: f64,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            dimensions: CellDimensions {
+                dot_spacing: 3.5,
+                cell_spacing: 10.0,
+                line_spacing: 22.0,
+                dot_diameter: 2.0,
+            },
+            page_width: 210.0,
+            page_height: 297.0,
+            margin: 15.0,
+            number_size: 2.5,
+            print_size: 3.5,
+        }
+    }
+}
+
+impl PdfOptions {
+    /// 한 줄에 적는 칸 수
+    fn columns(&self) -> usize {
+        let width = self.page_width - 2.0 * self.margin;
+        ((width / self.dimensions.cell_spacing) as usize).max(1)
+    }
+
+    /// 한 쪽에 적는 줄 수
+    fn lines(&self) -> usize {
+        let height = self.page_height - 2.0 * self.margin;
+        ((height / self.dimensions.line_spacing) as usize).max(1)
+    }
+}
+
+/// mm를 PDF 단위로 바꾸어 적는다.
+fn pt(mm: f64) -> String {
+    format_mm(mm * POINTS_PER_MM)
+}
+
+/// 점형의 점 번호. 8점 점자의 7점과 8점도 적는다.
+fn dot_numbers(cell: u8) -> String {
+    raised_dots(cell)
+        .map(|dot| char::from(b'1' + dot as u8))
+        .collect()
+}
+
+/// 한글 글꼴의 UniKS-UCS2-H 부호계로 글을 적는다. 기본 다국어 평면 밖의 문자는 `?`로 적는다.
+fn ucs2_hex(text: &str) -> String {
+    text.chars()
+        .map(|c| match u16::try_from(c as u32) {
+            Ok(code) => format!("{code:04X}"),
+            Err(_) => "003F".to_string(),
+        })
+        .collect()
+}
+
+/// 한 쪽의 내용 흐름. 위에서부터 잰 mm 좌표를 PDF의 아래에서부터 잰 좌표로 바꾸어 적는다.
+struct Content<'a> {
+    options: &'a PdfOptions,
+    stream: String,
+}
+
+impl Content<'_> {
+    fn circle(&mut self, x: f64, y: f64, radius: f64, filled: bool) {
+        let y = self.options.page_height - y;
+        let k = radius * CIRCLE_CONTROL;
+        let point = |dx: f64, dy: f64| format!("{} {}", pt(x + dx), pt(y + dy));
+        let stream = &mut self.stream;
+        writeln!(stream, "{} m", point(radius, 0.0)).unwrap();
+        for [a, b, c] in [
+            [(radius, k), (k, radius), (0.0, radius)],
+            [(-k, radius), (-radius, k), (-radius, 0.0)],
+            [(-radius, -k), (-k, -radius), (0.0, -radius)],
+            [(k, -radius), (radius, -k), (radius, 0.0)],
+        ] {
+            writeln!(
+                stream,
+                "{} {} {} c",
+                point(a.0, a.1),
+                point(b.0, b.1),
+                point(c.0, c.1)
+            )
+            .unwrap();
+        }
+        stream.push_str(if filled { "f\n" } else { "S\n" });
+    }
+
+    fn text(&mut self, x: f64, y: f64, font: &str, size: f64, text: &str) {
+        let text = match font {
+            "F1" => format!("({text})"),
+            _ => format!("<{}>", ucs2_hex(text)),
+        };
+        writeln!(
+            self.stream,
+            "BT /{font} {} Tf {} {} Td {text} Tj ET",
+            pt(size),
+            pt(x),
+            pt(self.options.page_height - y)
+        )
+        .unwrap();
+    }
+}
+
+/// 한 쪽을 그린다. 칸마다 `rows`줄의 점 자리를 모두 그려 솟은 점은 채우고, 칸 아래에 점 번호를,
+/// 단어 아래에 묵자를 적는다.
+fn render_page(lines: &[Vec<InterlinearWord>], options: &PdfOptions, rows: usize) -> String {
+    let dimensions = &options.dimensions;
+    let radius = dimensions.dot_diameter / 2.0;
+    let mut content = Content {
+        options,
+        stream: String::from("0.5 G 0.3 w\n"),
+    };
+    for (row, words) in lines.iter().enumerate() {
+        let mut column = 0;
+        for word in words {
+            let (x, y) = dimensions.cell_origin(column, row);
+            let (x, y) = (x + options.margin, y + options.margin);
+            let numbers_y =
+                y + (rows - 1) as f64 * dimensions.dot_spacing + radius + options.number_size + 1.0;
+            content.text(
+                x - radius,
+                numbers_y + options.print_size + 1.0,
+                "F2",
+                options.print_size,
+                &word.print,
+            );
+            for (idx, cell) in word.cells.iter().enumerate() {
+                let x = x + idx as f64 * dimensions.cell_spacing;
+                for dot in 0..2 * rows {
+                    let (dx, dy) = dimensions.dot_offset(dot);
+                    let raised = cell & (1 << dot) != 0;
+                    content.circle(
+                        x + dx,
+                        y + dy,
+                        if raised { radius } else { radius / 2.0 },
+                        raised,
+                    );
+                }
+                content.text(
+                    x - radius,
+                    numbers_y,
+                    "F1",
+                    options.number_size,
+                    &dot_numbers(*cell),
+                );
+            }
+            column += word.cells.len() + 1;
+        }
+    }
+    content.stream
+}
+
+/// [`interlinear_lines`]로 나눈 줄을 쪽마다 나누어 교정용 PDF로 적는다.
+///
+/// 외부 프로그램 없이 PDF 1.4를 바로 적는다. 점 번호는 Helvetica로, 묵자는 한국어 기본 글꼴로
+/// 적으며 글꼴은 PDF에 넣지 않는다. 8점 점형이 하나라도 있으면 모든 칸을 8점 자리로 그린다.
+pub fn render_pdf(lines: &[Vec<InterlinearWord>], options: &PdfOptions) -> Vec<u8> {
+    let rows = dot_rows(lines.iter().flatten().flat_map(|word| &word.cells));
+    let pages: Vec<&[Vec<InterlinearWord>]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(options.lines()).collect()
+    };
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> >>",
+            (0..pages.len())
+                .map(|idx| format!("{} 0 R", SHARED_OBJECTS + 1 + 2 * idx))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len(),
+            pt(options.page_width),
+            pt(options.page_height)
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        format!(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /{KOREAN_FONT} /Encoding /UniKS-UCS2-H /DescendantFonts [5 0 R] >>"
+        ),
+        format!(
+            "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /{KOREAN_FONT} /CIDSystemInfo << /Registry (Adobe) /Ordering (Korea1) /Supplement 1 >> /FontDescriptor 6 0 R /DW 1000 >>"
+        ),
+        format!(
+            "<< /Type /FontDescriptor /FontName /{KOREAN_FONT} /Flags 4 /FontBBox [-6 -145 1003 880] /ItalicAngle 0 /Ascent 880 /Descent -120 /CapHeight 880 /StemV 93 >>"
+        ),
+    ];
+    for (idx, page) in pages.iter().enumerate() {
+        let stream = render_page(page, options, rows);
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /Contents {} 0 R >>",
+            SHARED_OBJECTS + 2 + 2 * idx
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{stream}endstream",
+            stream.len()
+        ));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (idx, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        writeln!(pdf, "{} 0 obj\n{object}\nendobj", idx + 1).unwrap();
+    }
+    let xref = pdf.len();
+    writeln!(pdf, "xref\n0 {}\n0000000000 65535 f ", objects.len() + 1).unwrap();
+    for offset in offsets {
+        writeln!(pdf, "{offset:010} 00000 n ").unwrap();
+    }
+    writeln!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF",
+        objects.len() + 1
+    )
+    .unwrap();
+    pdf.into_bytes()
+}
+
+/// 입력을 점역해 단어마다 점 번호와 묵자를 함께 적은 교정용 PDF로 만든다.
+pub fn encode_pdf(
+    text: &str,
+    options: &EncodeOptions,
+    pdf: &PdfOptions,
+) -> Result<Vec<u8>, String> {
+    let lines = interlinear_lines(text, options, pdf.columns())?;
+    Ok(render_pdf(&lines, pdf))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::decode_unicode;
+
+    /// 교차 참조 표가 가리키는 위치마다 그 번호의 객체가 있는지 확인한다.
+    fn check_xref(pdf: &str) {
+        let start: usize = pdf
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|rest| rest.lines().next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(pdf[start..].starts_with("xref\n"));
+        for (idx, entry) in pdf[start..].lines().skip(3).enumerate() {
+            if entry.starts_with("trailer") {
+                break;
+            }
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj\n", idx + 1)));
+        }
+    }
+
+    #[test]
+    fn test_dot_numbers() {
+        assert_eq!(dot_numbers(decode_unicode('⠇')), "123");
+        assert_eq!(dot_numbers(decode_unicode('⠶')), "2356");
+        assert_eq!(dot_numbers(decode_unicode('⢿')), "1234568");
+        assert_eq!(dot_numbers(0), "");
+    }
+
+    #[test]
+    fn test_ucs2_hex() {
+        assert_eq!(ucs2_hex("상 A"), "C0C100200041");
+        assert_eq!(ucs2_hex("😀"), "003F");
+    }
+
+    #[test]
+    fn test_encode_pdf() {
+        let pdf = encode_pdf("상", &EncodeOptions::default(), &PdfOptions::default()).unwrap();
+        let pdf = String::from_utf8(pdf).unwrap();
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        check_xref(&pdf);
+        assert!(pdf.contains("/Count 1 /MediaBox [0 0 595.276 841.89]"));
+        assert!(pdf.contains("(123) Tj"));
+        assert!(pdf.contains("(2356) Tj"));
+        assert!(pdf.contains("<C0C1> Tj"));
+        // 두 칸에 열두 점 자리를 그리고, 솟은 일곱 점은 채운다.
+        assert_eq!(pdf.matches("\nf\n").count(), 7);
+        assert_eq!(pdf.matches("\nS\n").count(), 5);
+    }
+
+    #[test]
+    fn test_render_pdf_pages() {
+        let options = PdfOptions {
+            page_height: 80.0,
+            ..Default::default()
+        };
+        assert_eq!(options.lines(), 2);
+        assert_eq!(options.columns(), 18);
+        let pdf = encode_pdf("가\n나\n다", &EncodeOptions::default(), &options).unwrap();
+        let pdf = String::from_utf8(pdf).unwrap();
+        check_xref(&pdf);
+        assert!(pdf.contains("/Kids [7 0 R 9 0 R] /Count 2"));
+        let empty = String::from_utf8(render_pdf(&[], &options)).unwrap();
+        check_xref(&empty);
+        assert!(empty.contains("/Count 1"));
+    }
+
+    #[test]
+    fn test_render_pdf_long_word() {
+        let options = PdfOptions::default();
+        let pdf = encode_pdf(
+            "https://example.com/braillify/documents/index.html",
+            &EncodeOptions::default(),
+            &options,
+        )
+        .unwrap();
+        let pdf = String::from_utf8(pdf).unwrap();
+        let page_width = options.page_width * POINTS_PER_MM;
+        let positions: Vec<f64> = pdf
+            .lines()
+            .filter_map(|line| line.split_once(" Td ")?.0.rsplit(' ').nth(1)?.parse().ok())
+            .collect();
+        assert!(positions.len() > options.columns());
+        assert!(positions.iter().all(|x| (0.0..page_width).contains(x)));
+    }
+
+    #[test]
+    fn test_render_pdf_eight_dot() {
+        let word = |cells: &[char]| InterlinearWord {
+            print: String::new(),
+            cells: cells.iter().copied().map(decode_unicode).collect(),
+        };
+        // 한 칸이라도 8점 점형이면 6점 칸도 8점 자리로 그린다.
+        let lines = [vec![word(&['⠁']), word(&['⣀'])]];
+        let pdf = String::from_utf8(render_pdf(&lines, &PdfOptions::default())).unwrap();
+        assert_eq!(pdf.matches("\nf\n").count(), 3);
+        assert_eq!(pdf.matches("\nS\n").count(), 13);
+        let lines = [vec![word(&['⠁'])], vec![word(&['⠃'])]];
+        let pdf = String::from_utf8(render_pdf(&lines, &PdfOptions::default())).unwrap();
+        assert_eq!(pdf.matches("\nS\n").count(), 9);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{fraction::normalize_digit, latex::MathNode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptKind {
+    /// 위 첨자 (¹, ², ⁺, ⁿ)
+    Super,
+    /// 아래 첨자 (₁, ₂, ₊)
+    Sub,
+}
+
+/// 첨자 글자의 종류와 그에 해당하는 기본 글자
+pub fn script_char(c: char) -> Option<(ScriptKind, char)> {
+    let kind = match c {
+        '⁰' | '¹' | '²' | '³' | '⁴'..='⁹' | '⁺' | '⁻' | '⁼' | '⁽' | '⁾' | 'ⁿ' => {
+            ScriptKind::Super
+        }
+        '₀'..='₉' | '₊' | '₋' | '₌' | '₍' | '₎' => ScriptKind::Sub,
+        _ => return None,
+    };
+    let base = match c {
+        '⁺' | '₊' => '+',
+        '⁻' | '₋' => '−',
+        '⁼' | '₌' => '=',
+        '⁽' | '₍' => '(',
+        '⁾' | '₎' => ')',
+        'ⁿ' => 'n',
+        _ => normalize_digit(c)?,
+    };
+    Some((kind, base))
+}
+
+pub fn is_script_char(c: char) -> bool {
+    script_char(c).is_some()
+}
+
+/// `chars[start..]`에서 이어지는 같은 종류의 첨자를 수식 노드 하나로 묶는다.
+///
+/// 묶은 노드와 첨자의 글자 수를 돌려준다.
+pub fn parse_script_run(chars: &[char], start: usize) -> Option<(MathNode, usize)> {
+    let (kind, _) = script_char(*chars.get(start)?)?;
+    let mut nodes = Vec::new();
+    let mut len = 0;
+    while let Some((next_kind, base)) = chars.get(start + len).and_then(|c| script_char(*c))
+        && next_kind == kind
+    {
+        match base {
+            '0'..='9' => match nodes.last_mut() {
+                Some(MathNode::Number(digits)) => digits.push(base),
+                _ => nodes.push(MathNode::Number(base.to_string())),
+            },
+            '+' | '−' | '=' => nodes.push(MathNode::Operator(base)),
+            '(' | ')' => nodes.push(MathNode::Symbol(base)),
+            _ => nodes.push(MathNode::Letter(base)),
+        }
+        len += 1;
+    }
+    let node = match kind {
+        ScriptKind::Super => MathNode::Superscript(nodes),
+        ScriptKind::Sub => MathNode::Subscript(nodes),
+    };
+    Some((node, len))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_script_char() {
+        assert_eq!(script_char('²'), Some((ScriptKind::Super, '2')));
+        assert_eq!(script_char('₂'), Some((ScriptKind::Sub, '2')));
+        assert_eq!(script_char('⁻'), Some((ScriptKind::Super, '−')));
+        assert_eq!(script_char('2'), None);
+    }
+
+    #[test]
+    fn test_parse_script_run() {
+        let chars = "m²⁰₁".chars().collect::<Vec<_>>();
+        assert_eq!(
+            parse_script_run(&chars, 1),
+            Some((
+                MathNode::Superscript(vec![MathNode::Number("20".to_string())]),
+                2
+            ))
+        );
+        let chars = "x⁻¹".chars().collect::<Vec<_>>();
+        assert_eq!(
+            parse_script_run(&chars, 1),
+            Some((
+                MathNode::Superscript(vec![
+                    MathNode::Operator('−'),
+                    MathNode::Number("1".to_string())
+                ]),
+                2
+            ))
+        );
+        assert_eq!(parse_script_run(&chars, 0), None);
+    }
+}
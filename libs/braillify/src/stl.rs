@@ -0,0 +1,367 @@
+use std::f64::consts::PI;
+
+use crate::{
+    EncodeOptions, encode_with_options,
+    geometry::{CellDimensions, dot_rows, raised_dots},
+    unicode::NEWLINE,
+};
+
+/// 이진 STL의 80바이트 머리말 앞에 적는 글
+const HEADER: &[u8] = b"braillify braille plate";
+
+/// 3D 인쇄용 점자 판 설정. 길이는 모두 mm이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StlOptions {
+    /// 점의 크기와 간격
+    pub dimensions: CellDimensions,
+    /// 점자 둘레의 판 여백
+    pub margin: f64,
+    /// 판의 두께
+    pub thickness: f64,
+    /// 판 위로 솟은 점의 높이
+    pub dot_height: f64,
+    /// 점의 둘레를 나누는 변의 수. 클수록 점이 둥글다.
+    pub segments: usize,
+}
+
+impl Default for StlOptions {
+    fn default() -> Self {
+        Self {
+            dimensions: CellDimensions::default(),
+            margin: 4.0,
+            thickness: 2.0,
+            dot_height: 0.6,
+            segments: 24,
+        }
+    }
+}
+
+impl StlOptions {
+    /// 판과 점을 만들 수 있는 치수인지 확인한다. 길이는 양수여야 하고 여백은 0일 수 있다.
+    fn validate(&self) -> Result<(), String> {
+        let dimensions = &self.dimensions;
+        let lengths = [
+            ("Dot spacing", dimensions.dot_spacing),
+            ("Cell spacing", dimensions.cell_spacing),
+            ("Line spacing", dimensions.line_spacing),
+            ("Dot diameter", dimensions.dot_diameter),
+            ("Thickness", self.thickness),
+            ("Dot height", self.dot_height),
+        ];
+        for (name, value) in lengths {
+            if !(value.is_finite() && value > 0.0) {
+                return Err(format!("{name} must be positive, got {value}"));
+            }
+        }
+        if !(self.margin.is_finite() && self.margin >= 0.0) {
+            return Err(format!("Margin must not be negative, got {}", self.margin));
+        }
+        Ok(())
+    }
+}
+
+type Point = [f64; 3];
+
+fn sub(a: Point, b: Point) -> Point {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: Point, b: Point) -> Point {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// 바깥에서 보아 반시계 방향으로 꼭짓점을 적은 삼각형들
+#[derive(Default)]
+struct Mesh {
+    triangles: Vec<[Point; 3]>,
+}
+
+impl Mesh {
+    fn triangle(&mut self, a: Point, b: Point, c: Point) {
+        self.triangles.push([a, b, c]);
+    }
+
+    fn quad(&mut self, a: Point, b: Point, c: Point, d: Point) {
+        self.triangle(a, b, c);
+        self.triangle(a, c, d);
+    }
+
+    /// `min`과 `max`를 마주 보는 꼭짓점으로 하는 상자
+    fn cuboid(&mut self, min: Point, max: Point) {
+        let p = |x: usize, y: usize, z: usize| {
+            [
+                [min[0], max[0]][x],
+                [min[1], max[1]][y],
+                [min[2], max[2]][z],
+            ]
+        };
+        self.quad(p(0, 0, 0), p(0, 1, 0), p(1, 1, 0), p(1, 0, 0));
+        self.quad(p(0, 0, 1), p(1, 0, 1), p(1, 1, 1), p(0, 1, 1));
+        self.quad(p(0, 0, 0), p(1, 0, 0), p(1, 0, 1), p(0, 0, 1));
+        self.quad(p(1, 1, 0), p(0, 1, 0), p(0, 1, 1), p(1, 1, 1));
+        self.quad(p(0, 1, 0), p(0, 0, 0), p(0, 0, 1), p(0, 1, 1));
+        self.quad(p(1, 0, 0), p(1, 1, 0), p(1, 1, 1), p(1, 0, 1));
+    }
+
+    /// 밑면이 `base` 높이에 놓인 둥근 점. 밑면 지름 `diameter`, 높이 `height`인 구의 일부로 만들고,
+    /// 판과 한 덩어리로 합쳐지도록 `depth`만큼 판 속으로 기둥을 내린다.
+    fn dome(
+        &mut self,
+        center: [f64; 2],
+        base: f64,
+        diameter: f64,
+        height: f64,
+        depth: f64,
+        segments: usize,
+    ) {
+        let radius = diameter / 2.0;
+        let sphere = (radius * radius + height * height) / (2.0 * height);
+        let sphere_center = base + height - sphere;
+        let max_angle = (radius / sphere).asin();
+        let max_angle = if height > sphere {
+            PI - max_angle
+        } else {
+            max_angle
+        };
+        let rings = (segments / 4).max(1);
+        let ring = |level: usize| -> Vec<Point> {
+            let angle = max_angle * (rings - level) as f64 / rings as f64;
+            let (r, z) = (sphere * angle.sin(), sphere_center + sphere * angle.cos());
+            (0..segments)
+                .map(|step| {
+                    let phi = 2.0 * PI * step as f64 / segments as f64;
+                    [center[0] + r * phi.cos(), center[1] + r * phi.sin(), z]
+                })
+                .collect()
+        };
+        let apex = [center[0], center[1], base + height];
+        let bottom_center = [center[0], center[1], base - depth];
+        let bottom: Vec<Point> = ring(0).iter().map(|p| [p[0], p[1], base - depth]).collect();
+        for step in 0..segments {
+            let next = (step + 1) % segments;
+            self.triangle(bottom_center, bottom[next], bottom[step]);
+        }
+        let mut lower = bottom;
+        for level in 0..rings {
+            let upper = ring(level);
+            for step in 0..segments {
+                let next = (step + 1) % segments;
+                self.quad(lower[step], lower[next], upper[next], upper[step]);
+            }
+            lower = upper;
+        }
+        for step in 0..segments {
+            self.triangle(lower[step], lower[(step + 1) % segments], apex);
+        }
+    }
+
+    /// 이진 STL로 적는다.
+    fn to_stl(&self) -> Vec<u8> {
+        let mut stl = Vec::with_capacity(84 + 50 * self.triangles.len());
+        stl.extend_from_slice(HEADER);
+        stl.resize(80, 0);
+        stl.extend_from_slice(&(self.triangles.len() as u32).to_le_bytes());
+        for [a, b, c] in &self.triangles {
+            let normal = cross(sub(*b, *a), sub(*c, *a));
+            let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+            let normal = normal.map(|v| if length > 0.0 { v / length } else { 0.0 });
+            for point in [normal, *a, *b, *c] {
+                for value in point {
+                    stl.extend_from_slice(&(value as f32).to_le_bytes());
+                }
+            }
+            stl.extend_from_slice(&0u16.to_le_bytes());
+        }
+        stl
+    }
+}
+
+/// 점역 결과의 줄을 점이 솟은 판으로 만들어 이진 STL로 적는다.
+///
+/// 판은 원점에서 시작해 x축으로 칸, y축으로 줄을 놓으며, 첫 줄이 판의 위쪽(y가 큰 쪽)에 온다.
+/// 점의 높이나 판의 두께처럼 길이가 0 이하이면 오류를 낸다.
+pub fn render_stl(lines: &[Vec<u8>], options: &StlOptions) -> Result<Vec<u8>, String> {
+    options.validate()?;
+    let dimensions = &options.dimensions;
+    let columns = lines.iter().map(Vec::len).max().unwrap_or(0);
+    let (width, height) = dimensions.size(columns, lines.len(), dot_rows(lines.iter().flatten()));
+    let (width, height) = (width + 2.0 * options.margin, height + 2.0 * options.margin);
+    let mut mesh = Mesh::default();
+    mesh.cuboid([0.0, 0.0, 0.0], [width, height, options.thickness]);
+    let depth = (options.thickness / 2.0).min(0.2);
+    for (row, line) in lines.iter().enumerate() {
+        for (column, cell) in line.iter().enumerate() {
+            let (x, y) = dimensions.cell_origin(column, row);
+            for dot in raised_dots(*cell) {
+                let (dx, dy) = dimensions.dot_offset(dot);
+                mesh.dome(
+                    [options.margin + x + dx, height - (options.margin + y + dy)],
+                    options.thickness,
+                    dimensions.dot_diameter,
+                    options.dot_height,
+                    depth,
+                    options.segments.max(3),
+                );
+            }
+        }
+    }
+    Ok(mesh.to_stl())
+}
+
+/// 짧은 글을 점역해 점자 판의 이진 STL로 만든다. 입력의 줄바꿈에서 판의 줄을 바꾼다.
+pub fn encode_stl(
+    text: &str,
+    options: &EncodeOptions,
+    stl: &StlOptions,
+) -> Result<Vec<u8>, String> {
+    let cells = encode_with_options(text, options)?;
+    let lines: Vec<Vec<u8>> = cells
+        .split(|c| *c == NEWLINE)
+        .map(|line| line.to_vec())
+        .collect();
+    render_stl(&lines, stl)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unicode::decode_unicode;
+
+    /// 이진 STL의 삼각형마다 (법선, 꼭짓점 셋)
+    fn triangles(stl: &[u8]) -> Vec<[[f32; 3]; 4]> {
+        let count = u32::from_le_bytes(stl[80..84].try_into().unwrap()) as usize;
+        assert_eq!(stl.len(), 84 + 50 * count);
+        stl[84..]
+            .chunks(50)
+            .map(|chunk| {
+                let value = |idx: usize| {
+                    f32::from_le_bytes(chunk[idx * 4..idx * 4 + 4].try_into().unwrap())
+                };
+                [0, 1, 2, 3].map(|point| [0, 1, 2].map(|axis| value(point * 3 + axis)))
+            })
+            .collect()
+    }
+
+    /// 닫힌 면으로 둘러싼 부피. 삼각형이 바깥을 보고 있어야 양수가 된다.
+    fn volume(triangles: &[[[f32; 3]; 4]]) -> f64 {
+        triangles
+            .iter()
+            .map(|[_, a, b, c]| {
+                let [a, b, c] = [a, b, c].map(|p| p.map(f64::from));
+                a[0] * (b[1] * c[2] - b[2] * c[1]) - a[1] * (b[0] * c[2] - b[2] * c[0])
+                    + a[2] * (b[0] * c[1] - b[1] * c[0])
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+
+    #[test]
+    fn test_render_stl_plate() {
+        let stl = render_stl(&[], &StlOptions::default()).unwrap();
+        assert!(stl.starts_with(HEADER));
+        let triangles = triangles(&stl);
+        assert_eq!(triangles.len(), 12);
+        // 여백만 있는 8mm x 8mm x 2mm 판
+        assert!((volume(&triangles) - 128.0).abs() < 1e-3);
+        for [normal, ..] in &triangles {
+            let length: f32 = normal.iter().map(|v| v * v).sum();
+            assert!((length - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_render_stl_dots() {
+        let options = StlOptions {
+            segments: 16,
+            ..Default::default()
+        };
+        let stl = render_stl(&[vec![decode_unicode('⠁'), decode_unicode('⠃')]], &options).unwrap();
+        let triangles = triangles(&stl);
+        // 판 12개와 점마다 바닥 16개, 기둥과 구면 4 * 32개, 꼭대기 16개
+        assert_eq!(triangles.len(), 12 + 3 * (16 + 4 * 32 + 16));
+        let max = |axis: usize| {
+            triangles
+                .iter()
+                .flat_map(|[_, points @ ..]| points.iter().map(move |p| p[axis]))
+                .fold(f32::MIN, f32::max)
+        };
+        // 두 칸 너비 10mm, 한 줄 높이 6.5mm에 여백 4mm씩
+        assert!((max(0) - 18.0).abs() < 1e-4);
+        assert!((max(1) - 14.5).abs() < 1e-4);
+        assert!((max(2) - 2.6).abs() < 1e-4);
+        // 점은 판보다 부피를 늘린다.
+        assert!(volume(&triangles) > 18.0 * 14.5 * 2.0);
+        // 첫 점은 위쪽 여백 안쪽, 왼쪽 위에 솟는다.
+        assert!(
+            triangles
+                .iter()
+                .any(|[_, points @ ..]| { points.iter().any(|p| p == &[4.75, 14.5 - 4.75, 2.6]) })
+        );
+    }
+
+    #[test]
+    fn test_render_stl_invalid_options() {
+        let lines = [vec![decode_unicode('⠁')]];
+        for options in [
+            StlOptions {
+                dot_height: 0.0,
+                ..Default::default()
+            },
+            StlOptions {
+                thickness: -1.0,
+                ..Default::default()
+            },
+            StlOptions {
+                dimensions: CellDimensions {
+                    dot_diameter: 0.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            StlOptions {
+                margin: f64::NAN,
+                ..Default::default()
+            },
+        ] {
+            assert!(render_stl(&lines, &options).is_err());
+        }
+        let options = StlOptions {
+            margin: 0.0,
+            ..Default::default()
+        };
+        assert!(render_stl(&lines, &options).is_ok());
+    }
+
+    #[test]
+    fn test_render_stl_eight_dot() {
+        let options = StlOptions {
+            margin: 0.0,
+            ..Default::default()
+        };
+        let stl = render_stl(&[vec![decode_unicode('⣀')]], &options).unwrap();
+        // 7점과 8점이 놓이는 넷째 줄까지 판이 9mm 높이로 늘어나, 점이 판 밖으로 나가지 않는다.
+        let points = triangles(&stl)
+            .into_iter()
+            .flat_map(|[_, points @ ..]| points)
+            .collect::<Vec<_>>();
+        let max_y = points.iter().map(|p| p[1]).fold(f32::MIN, f32::max);
+        let min_y = points.iter().map(|p| p[1]).fold(f32::MAX, f32::min);
+        assert!((max_y - 9.0).abs() < 1e-4);
+        assert!(min_y > -1e-4);
+    }
+
+    #[test]
+    fn test_encode_stl() {
+        let stl = encode_stl("상\n상", &EncodeOptions::default(), &StlOptions::default()).unwrap();
+        let max_y = triangles(&stl)
+            .iter()
+            .flat_map(|[_, points @ ..]| points.iter().map(|p| p[1]))
+            .fold(f32::MIN, f32::max);
+        assert!((max_y - 24.5).abs() < 1e-4);
+        assert!(encode_stl("國", &EncodeOptions::default(), &StlOptions::default()).is_err());
+    }
+}
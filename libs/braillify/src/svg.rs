@@ -0,0 +1,185 @@
+use std::fmt::Write;
+
+use crate::{
+    geometry::{CellDimensions, dot_rows, format_mm, raised_dots},
+    interlinear::InterlinearWord,
+    utils::escape_html,
+};
+
+/// SVG 그림 설정. 길이는 모두 mm이다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// 점의 크기와 간격
+    pub dimensions: CellDimensions,
+    /// 점자 둘레의 여백
+    pub margin: f64,
+    /// 점자 아래에 함께 적는 묵자의 글자 크기
+    pub print_size: f64,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            dimensions: CellDimensions::default(),
+            margin: 10.0,
+            print_size: 2.5,
+        }
+    }
+}
+
+/// 한 줄의 점자와 그 아래에 적는 묵자. 묵자는 적기 시작하는 칸과 함께 둔다.
+struct Line<'a> {
+    cells: Vec<u8>,
+    print: Vec<(usize, &'a str)>,
+}
+
+fn render(lines: &[Line], options: &SvgOptions) -> String {
+    let dimensions = &options.dimensions;
+    let columns = lines.iter().map(|line| line.cells.len()).max().unwrap_or(0);
+    let rows = dot_rows(lines.iter().flat_map(|line| &line.cells));
+    let (width, height) = dimensions.size(columns, lines.len(), rows);
+    let (width, height) = (width + 2.0 * options.margin, height + 2.0 * options.margin);
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}mm\" height=\"{h}mm\" viewBox=\"0 0 {w} {h}\">\n",
+        w = format_mm(width),
+        h = format_mm(height)
+    );
+    svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n");
+    svg.push_str("<g fill=\"black\">\n");
+    let radius = format_mm(dimensions.dot_diameter / 2.0);
+    for (row, line) in lines.iter().enumerate() {
+        for (column, cell) in line.cells.iter().enumerate() {
+            let (x, y) = dimensions.cell_origin(column, row);
+            for dot in raised_dots(*cell) {
+                let (dx, dy) = dimensions.dot_offset(dot);
+                writeln!(
+                    svg,
+                    "<circle cx=\"{}\" cy=\"{}\" r=\"{radius}\"/>",
+                    format_mm(options.margin + x + dx),
+                    format_mm(options.margin + y + dy)
+                )
+                .unwrap();
+            }
+        }
+    }
+    svg.push_str("</g>\n");
+    if lines.iter().any(|line| !line.print.is_empty()) {
+        writeln!(
+            svg,
+            "<g fill=\"gray\" font-family=\"sans-serif\" font-size=\"{}\">",
+            format_mm(options.print_size)
+        )
+        .unwrap();
+        for (row, line) in lines.iter().enumerate() {
+            for (column, text) in &line.print {
+                // 점자 칸의 아래, 다음 줄과의 사이에 적는다.
+                let (x, y) = dimensions.cell_origin(*column, row);
+                let x = x - dimensions.dot_diameter / 2.0;
+                let y =
+                    y + (rows - 1) as f64 * dimensions.dot_spacing + dimensions.dot_diameter / 2.0;
+                writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\">{}</text>",
+                    format_mm(options.margin + x),
+                    format_mm(options.margin + y + options.print_size),
+                    escape_html(text)
+                )
+                .unwrap();
+            }
+        }
+        svg.push_str("</g>\n");
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// 점역 결과의 한 면을 점의 크기와 간격을 실제 치수대로 맞춘 SVG로 그린다.
+///
+/// 화면에서 교정하거나 발포지, UV 인쇄기로 촉각 인쇄를 할 때 쓴다.
+pub fn render_svg(page: &[Vec<u8>], options: &SvgOptions) -> String {
+    let lines: Vec<Line> = page
+        .iter()
+        .map(|cells| Line {
+            cells: cells.clone(),
+            print: Vec::new(),
+        })
+        .collect();
+    render(&lines, options)
+}
+
+/// [`interlinear_lines`](crate::interlinear_lines)로 나눈 줄을 SVG로 그리고, 단어마다 점자 아래에
+/// 묵자를 흐리게 함께 적는다.
+pub fn render_svg_with_print(lines: &[Vec<InterlinearWord>], options: &SvgOptions) -> String {
+    let lines: Vec<Line> = lines
+        .iter()
+        .map(|words| {
+            let mut line = Line {
+                cells: Vec::new(),
+                print: Vec::new(),
+            };
+            for (idx, word) in words.iter().enumerate() {
+                if idx > 0 {
+                    line.cells.push(0);
+                }
+                line.print.push((line.cells.len(), word.print.as_str()));
+                line.cells.extend(&word.cells);
+            }
+            line
+        })
+        .collect();
+    render(&lines, options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{EncodeOptions, interlinear_lines, unicode::decode_unicode};
+
+    #[test]
+    fn test_render_svg() {
+        let page = vec![vec![decode_unicode('⠇'), 0, decode_unicode('⠶')], vec![]];
+        let svg = render_svg(&page, &SvgOptions::default());
+        assert!(svg.starts_with(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"36mm\" height=\"36.5mm\" viewBox=\"0 0 36 36.5\">"
+        ));
+        assert_eq!(svg.matches("<circle").count(), 7);
+        // ⠇의 1점, 2점, 3점
+        assert!(svg.contains("<circle cx=\"10.75\" cy=\"10.75\" r=\"0.75\"/>"));
+        assert!(svg.contains("<circle cx=\"10.75\" cy=\"13.25\" r=\"0.75\"/>"));
+        assert!(svg.contains("<circle cx=\"10.75\" cy=\"15.75\" r=\"0.75\"/>"));
+        // 셋째 칸 ⠶의 6점
+        assert!(svg.contains("<circle cx=\"25.25\" cy=\"15.75\" r=\"0.75\"/>"));
+        assert!(!svg.contains("<text"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_render_svg_dimensions() {
+        let options = SvgOptions {
+            dimensions: CellDimensions {
+                dot_spacing: 2.0,
+                cell_spacing: 5.0,
+                line_spacing: 8.0,
+                dot_diameter: 1.0,
+            },
+            margin: 0.0,
+            ..Default::default()
+        };
+        let svg = render_svg(&[vec![decode_unicode('⠿')]], &options);
+        assert!(svg.contains("viewBox=\"0 0 3 5\""));
+        assert!(svg.contains("<circle cx=\"2.5\" cy=\"4.5\" r=\"0.5\"/>"));
+        assert_eq!(render_svg(&[], &options).matches("<circle").count(), 0);
+        // 8점 점자의 7점과 8점은 넷째 줄에 놓이므로 그만큼 높이를 늘린다.
+        let svg = render_svg(&[vec![decode_unicode('⣀')]], &options);
+        assert!(svg.contains("viewBox=\"0 0 3 7\""));
+        assert!(svg.contains("<circle cx=\"2.5\" cy=\"6.5\" r=\"0.5\"/>"));
+    }
+
+    #[test]
+    fn test_render_svg_with_print() {
+        let lines = interlinear_lines("상 <가>", &EncodeOptions::default(), 40).unwrap();
+        let svg = render_svg_with_print(&lines, &SvgOptions::default());
+        assert!(svg.contains("<text x=\"10\" y=\"19\">상</text>"));
+        assert!(svg.contains("<text x=\"28\" y=\"19\">&lt;가&gt;</text>"));
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{computer, latex};
+
+/// 공백을 그대로 적을 때 탭 하나가 차지하는 칸 수
+pub const TAB_WIDTH: usize = 2;
+
+/// 빈칸의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blank {
+    /// 띄어쓰기. U+0020과 전각 공백처럼 줄을 바꿀 수 있는 공백 문자
+    Space,
+    /// 탭
+    Tab,
+    /// 줄을 바꾸지 않는 공백 (U+00A0, U+2007, U+202F)
+    NoBreakSpace,
+    /// 줄바꿈 (`\n`, `\r\n`, `\r`, U+2028)
+    LineBreak,
+    /// 문단 구분 (U+2029). 빈 줄 하나를 둔 줄바꿈으로 본다.
+    ParagraphBreak,
+}
+
+impl Blank {
+    /// 이 빈칸이 바꾸는 줄의 수
+    pub fn newlines(self) -> usize {
+        match self {
+            Blank::LineBreak => 1,
+            Blank::ParagraphBreak => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// 점역 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// 빈칸 없이 이어진 글자 묶음. 수식 구간과 코드 표시 구간은 안에 공백이 있어도 한 단어로 본다.
+    Word(&'a str),
+    Blank(Blank),
+}
+
+/// 공백 문자의 종류. 공백이 아니면 `None`
+pub fn classify(c: char) -> Option<Blank> {
+    match c {
+        '\t' => Some(Blank::Tab),
+        '\n' | '\r' | '\u{000B}' | '\u{000C}' | '\u{0085}' | '\u{2028}' => Some(Blank::LineBreak),
+        '\u{2029}' => Some(Blank::ParagraphBreak),
+        '\u{00A0}' | '\u{2007}' | '\u{202F}' => Some(Blank::NoBreakSpace),
+        _ if c.is_whitespace() => Some(Blank::Space),
+        _ => None,
+    }
+}
+
+/// 점자로 적을 것이 없는 폭 없는 문자 (폭 없는 공백, 폭 없는 결합자와 비결합자, 단어 결합자, BOM).
+/// 단어 안에 그대로 두고, 단어를 점역할 때 지운다.
+pub fn is_zero_width(c: char) -> bool {
+    matches!(c, '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}')
+}
+
+/// 입력을 단어와 빈칸으로 나눈다. `\r\n`은 줄바꿈 하나로 본다.
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut pos = 0;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        if rest.starts_with('$')
+            && let Some(len) = latex::math_span_len(rest)
+        {
+            pos += len;
+            continue;
+        }
+        if rest.starts_with('`')
+            && let Some(len) = computer::code_span_len(rest)
+        {
+            pos += len;
+            continue;
+        }
+        let c = rest.chars().next().unwrap();
+        let Some(blank) = classify(c) else {
+            pos += c.len_utf8();
+            continue;
+        };
+        if pos > start {
+            tokens.push(Token::Word(&text[start..pos]));
+        }
+        pos += if rest.starts_with("\r\n") {
+            2
+        } else {
+            c.len_utf8()
+        };
+        start = pos;
+        tokens.push(Token::Blank(blank));
+    }
+    if pos > start {
+        tokens.push(Token::Word(&text[start..]));
+    }
+    tokens
+}
+
+/// 줄바꿈으로 입력을 문단으로 나누고, 문단마다 그 뒤에 올 줄바꿈의 수를 함께 돌려준다.
+///
+/// `join_lines`이면 빈 줄이나 문단 구분 문자가 있는 곳에서만 문단을 나누고,
+/// 줄바꿈 하나는 문단 안의 띄어쓰기로 남겨 둔다.
+pub fn split_paragraphs(text: &str, join_lines: bool) -> Vec<(&str, usize)> {
+    let mut paragraphs = Vec::new();
+    let mut start = 0;
+    // 문단 끝 후보: 줄바꿈이 시작된 위치, 지금까지의 줄바꿈 수
+    let mut run: Option<(usize, usize)> = None;
+    for (pos, c) in text.char_indices() {
+        match classify(c) {
+            Some(blank) if blank.newlines() > 0 => {
+                // `\r\n`의 `\n`은 앞의 `\r`과 함께 세었다.
+                let newlines = if c == '\n' && text[..pos].ends_with('\r') {
+                    0
+                } else {
+                    blank.newlines()
+                };
+                let (run_start, count) = run.unwrap_or((pos, 0));
+                run = Some((run_start, count + newlines));
+            }
+            Some(_) => {}
+            None => {
+                if let Some((run_start, count)) = run.take()
+                    && (!join_lines || count >= 2)
+                {
+                    paragraphs.push((&text[start..run_start], count));
+                    start = text[run_start..pos]
+                        .rfind(|c| classify(c).is_some_and(|blank| blank.newlines() > 0))
+                        .map_or(pos, |idx| {
+                            let idx = run_start + idx;
+                            idx + text[idx..].chars().next().map_or(1, char::len_utf8)
+                        });
+                }
+            }
+        }
+    }
+    if let Some((run_start, count)) = run
+        && (!join_lines || count >= 2)
+    {
+        paragraphs.push((&text[start..run_start], count));
+        start = text.len();
+    }
+    paragraphs.push((&text[start..], 0));
+    paragraphs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn words(text: &str) -> Vec<&str> {
+        tokenize(text)
+            .into_iter()
+            .filter_map(|token| match token {
+                Token::Word(word) => Some(word),
+                Token::Blank(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_tokenize_blanks() {
+        assert_eq!(
+            tokenize("a  b\tc\u{00A0}d\r\ne"),
+            vec![
+                Token::Word("a"),
+                Token::Blank(Blank::Space),
+                Token::Blank(Blank::Space),
+                Token::Word("b"),
+                Token::Blank(Blank::Tab),
+                Token::Word("c"),
+                Token::Blank(Blank::NoBreakSpace),
+                Token::Word("d"),
+                Token::Blank(Blank::LineBreak),
+                Token::Word("e"),
+            ]
+        );
+        assert_eq!(
+            tokenize("\u{3000}가\u{2029}"),
+            vec![
+                Token::Blank(Blank::Space),
+                Token::Word("가"),
+                Token::Blank(Blank::ParagraphBreak),
+            ]
+        );
+        // 폭 없는 문자는 단어 안에 남는다.
+        assert_eq!(words("한\u{200B}글 a"), vec!["한\u{200B}글", "a"]);
+    }
+
+    #[test]
+    fn test_tokenize_keeps_spans() {
+        assert_eq!(
+            words("값은 $x + y = 1$ 이다"),
+            vec!["값은", "$x + y = 1$", "이다"]
+        );
+        assert_eq!(words("  a  b "), vec!["a", "b"]);
+        assert_eq!(words("$5 and $10"), vec!["$5", "and", "$10"]);
+        assert_eq!(words("`git add -A`를"), vec!["`git add -A`를"]);
+    }
+
+    #[test]
+    fn test_split_paragraphs() {
+        assert_eq!(
+            split_paragraphs("a\nb\r\n\r\n  c", false),
+            vec![("a", 1), ("b", 2), ("  c", 0)]
+        );
+        assert_eq!(
+            split_paragraphs("a\nb\r\n  \r\nc\n", true),
+            vec![("a\nb", 2), ("c\n", 0)]
+        );
+        assert_eq!(
+            split_paragraphs("a\u{2029}b", false),
+            vec![("a", 2), ("b", 0)]
+        );
+        assert_eq!(split_paragraphs("", false), vec![("", 0)]);
+    }
+}
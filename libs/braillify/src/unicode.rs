@@ -1,10 +1,30 @@
+/// 8점 점자의 7점. 8점 점자에서 대문자를 나타낸다.
+pub const DOT_7: u8 = 0x40;
+/// 줄바꿈. 8점을 모두 찍는 점형 자리를 줄바꿈으로 쓴다.
+pub const NEWLINE: u8 = 255;
+
+/// 점형을 유니코드 점자 문자로 바꾼다. 255(8점 모두)는 줄바꿈으로 쓰므로 8점 점자에서도 나오지 않는다.
 pub fn encode_unicode(text: u8) -> char {
-    if text == 255 {
+    if text == NEWLINE {
         return '\n';
     }
     char::from_u32(text as u32 + 0x2800).unwrap()
 }
 
+/// 6점 점형(0~63)마다의 점자 ASCII 문자 (북미 점자 ASCII). BRF 파일에 쓴다.
+const BRAILLE_ASCII: &[u8; 64] =
+    b" A1B'K2L@CIF/MSP\"E3H9O6R^DJG>NTQ,*5<-U8V.%[$+X!&;:4\\0Z7(_?W]#Y)=";
+
+/// 점형을 BRF 파일에 쓰는 점자 ASCII 문자로 바꾼다. 255는 줄바꿈이다.
+/// 7점이나 8점이 있는 점형은 점자 ASCII로 나타낼 수 없다.
+pub fn encode_ascii(cell: u8) -> Result<char, String> {
+    match cell {
+        NEWLINE => Ok('\n'),
+        0..64 => Ok(BRAILLE_ASCII[cell as usize] as char),
+        _ => Err("Eight-dot braille cannot be written as braille ASCII".to_string()),
+    }
+}
+
 pub const fn decode_unicode(text: char) -> u8 {
     if (text as u32) < 0x2800 {
         panic!("Invalid unicode character");
@@ -15,6 +35,16 @@ pub const fn decode_unicode(text: char) -> u8 {
 #[cfg(test)]
 mod test {
     use super::*;
+
+    #[test]
+    fn test_encode_ascii() {
+        assert_eq!(encode_ascii(0), Ok(' '));
+        assert_eq!(encode_ascii(decode_unicode('⠁')), Ok('A'));
+        assert_eq!(encode_ascii(decode_unicode('⠈')), Ok('@'));
+        assert_eq!(encode_ascii(decode_unicode('⠿')), Ok('='));
+        assert_eq!(encode_ascii(255), Ok('\n'));
+        assert!(encode_ascii(DOT_7).is_err());
+    }
     #[test]
     pub fn test_encode_unicode() {
         assert_eq!(encode_unicode(0), '⠀');
@@ -82,5 +112,6 @@ mod test {
         assert_eq!(encode_unicode(62), '⠾');
         assert_eq!(encode_unicode(63), '⠿');
         assert_eq!(encode_unicode(255), '\n');
+        assert_eq!(encode_unicode(DOT_7 | 1), '⡁');
     }
 }
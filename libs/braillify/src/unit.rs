@@ -0,0 +1,103 @@
+use phf::phf_map;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::unicode::decode_unicode;
+
+/// 단위와 화폐 기호. 국제 기호이므로 통일영어점자의 기호를 따르며, 앞의 숫자에 붙여 쓴다.
+static UNIT_MAP: phf::Map<char, &'static [u8]> = phf_map! {
+    '%' => &[decode_unicode('⠨'), decode_unicode('⠴')],
+    '‰' => &[decode_unicode('⠨'), decode_unicode('⠴'), decode_unicode('⠴')],
+    '°' => &[decode_unicode('⠘'), decode_unicode('⠚')],
+    '℃' => &[decode_unicode('⠘'), decode_unicode('⠚'), decode_unicode('⠠'), decode_unicode('⠉')],
+    '℉' => &[decode_unicode('⠘'), decode_unicode('⠚'), decode_unicode('⠠'), decode_unicode('⠋')],
+    '′' => &[decode_unicode('⠶')],
+    '″' => &[decode_unicode('⠶'), decode_unicode('⠶')],
+    '₩' => &[decode_unicode('⠈'), decode_unicode('⠺')],
+    '$' => &[decode_unicode('⠈'), decode_unicode('⠎')],
+    '€' => &[decode_unicode('⠈'), decode_unicode('⠑')],
+    '¥' => &[decode_unicode('⠈'), decode_unicode('⠽')],
+    '£' => &[decode_unicode('⠈'), decode_unicode('⠇')],
+    '¢' => &[decode_unicode('⠈'), decode_unicode('⠉')],
+};
+
+pub fn encode_unit(text: char) -> Result<&'static [u8], String> {
+    if let Some(code) = UNIT_MAP.get(&text) {
+        Ok(code)
+    } else {
+        Err("Invalid unit character".to_string())
+    }
+}
+
+pub fn is_unit_char(text: char) -> bool {
+    UNIT_MAP.contains_key(&text)
+}
+
+/// ㎞, ㎏처럼 한 글자로 된 단위(U+3380~U+33DF)를 로마자로 풀어 쓴다.
+///
+/// ㎡처럼 지수가 붙은 단위는 호환 분해하면 지수가 보통 숫자가 되므로 위 첨자로 되돌린다.
+/// 로마자와 지수만으로 풀리지 않는 단위(㎍ 등)는 `None`을 돌려준다.
+fn decompose_unit(c: char) -> Option<String> {
+    if !('\u{3380}'..='\u{33DF}').contains(&c) {
+        return None;
+    }
+    let has_exponent = ('\u{339F}'..='\u{33A6}').contains(&c) || c == '\u{33A8}';
+    let mut decomposed = c
+        .nfkc()
+        .map(|ch| if ch == '∕' { '/' } else { ch })
+        .collect::<String>();
+    if has_exponent && let Some(exponent) = decomposed.pop() {
+        decomposed.push(match exponent {
+            '2' => '²',
+            '3' => '³',
+            _ => return None,
+        });
+    }
+    decomposed
+        .chars()
+        .all(|ch| ch.is_ascii_alphabetic() || matches!(ch, '/' | '.' | '²' | '³'))
+        .then_some(decomposed)
+}
+
+/// 단어 안의 한 글자 단위를 로마자로 풀어 쓴다.
+pub fn decompose_units(word: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+    for c in word.chars() {
+        match decompose_unit(c) {
+            Some(decomposed) => result.push_str(&decomposed),
+            None => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_encode_unit() {
+        assert_eq!(
+            encode_unit('%').unwrap(),
+            &[decode_unicode('⠨'), decode_unicode('⠴')]
+        );
+        assert_eq!(
+            encode_unit('₩').unwrap(),
+            &[decode_unicode('⠈'), decode_unicode('⠺')]
+        );
+        assert!(is_unit_char('℃'));
+        assert!(!is_unit_char('a'));
+        assert_eq!(encode_unit('a').unwrap_err(), "Invalid unit character");
+    }
+
+    #[test]
+    pub fn test_decompose_units() {
+        assert_eq!(decompose_units("5㎞"), "5km");
+        assert_eq!(decompose_units("3㎏"), "3kg");
+        assert_eq!(decompose_units("10㎡"), "10m²");
+        assert_eq!(decompose_units("㎥"), "m³");
+        assert_eq!(decompose_units("㎧"), "m/s");
+        assert_eq!(decompose_units("㎨"), "m/s²");
+        // 그리스 문자가 들어간 단위는 풀지 않는다.
+        assert_eq!(decompose_units("㎍"), "㎍");
+    }
+}
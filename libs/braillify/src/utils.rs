@@ -28,8 +28,32 @@ pub fn has_choseong_o(ch: char) -> bool {
     false
 }
 
+/// 로마자인지 확인한다. 통일영어점자로 적을 수 있는 악센트 붙은 글자(é, ü, ñ)도 포함한다.
+pub fn is_english_letter(c: char) -> bool {
+    c.is_ascii_alphabetic() || crate::english::is_accented_letter(c)
+}
+
 pub fn is_korean_char(c: char) -> bool {
-    (c as u32 >= 0x3131 && c as u32 <= 0x3163) || (0xAC00 <= c as u32 && c as u32 <= 0xD7A3)
+    (c as u32 >= 0x3131 && c as u32 <= 0x3163)
+        || (0xAC00 <= c as u32 && c as u32 <= 0xD7A3)
+        // 옛 자모와 옛한글 음절을 이루는 조합형 자모
+        || (0x3165..=0x318E).contains(&(c as u32))
+        || crate::old_hangul::is_conjoining_jamo(c)
+}
+
+/// HTML이나 SVG의 글과 속성 값에 그대로 적을 수 없는 문자를 문자 참조로 바꾼다.
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 #[cfg(test)]
@@ -44,10 +68,18 @@ mod test {
 
     #[test]
     fn test_has_choseong_o() {
-        assert_eq!(has_choseong_o('ㅇ'), true);
-        assert_eq!(has_choseong_o('ㄱ'), false);
-        assert_eq!(has_choseong_o('아'), true);
-        assert_eq!(has_choseong_o('가'), false);
-        assert_eq!(has_choseong_o('앙'), true);
+        assert!(has_choseong_o('ㅇ'));
+        assert!(!has_choseong_o('ㄱ'));
+        assert!(has_choseong_o('아'));
+        assert!(!has_choseong_o('가'));
+        assert!(has_choseong_o('앙'));
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
     }
 }
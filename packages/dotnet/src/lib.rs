@@ -35,6 +35,10 @@ pub extern "C" fn braillify_get_last_error() -> *mut c_char {
 /// Encodes text to braille byte array.
 /// 성공 시 바이트 배열 포인터 반환, 실패 시 null 반환.
 /// Returns byte array pointer on success, null on failure.
+///
+/// # Safety
+/// `text`는 NUL로 끝나는 유효한 C 문자열이고 `out_len`은 쓰기 가능한 포인터여야 합니다.
+/// `text` must be a valid NUL-terminated C string and `out_len` must be writable.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn braillify_encode(text: *const c_char, out_len: *mut usize) -> *mut u8 {
     clear_last_error();
@@ -68,6 +72,10 @@ pub unsafe extern "C" fn braillify_encode(text: *const c_char, out_len: *mut usi
 
 /// 텍스트를 점자 유니코드 문자열로 인코딩합니다.
 /// Encodes text to braille unicode string.
+///
+/// # Safety
+/// `text`는 NUL로 끝나는 유효한 C 문자열이어야 합니다.
+/// `text` must be a valid NUL-terminated C string.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn braillify_encode_to_unicode(text: *const c_char) -> *mut c_char {
     clear_last_error();
@@ -103,6 +111,10 @@ pub unsafe extern "C" fn braillify_encode_to_unicode(text: *const c_char) -> *mu
 
 /// 텍스트를 점자 폰트 문자열로 인코딩합니다.
 /// Encodes text to braille font string.
+///
+/// # Safety
+/// `text`는 NUL로 끝나는 유효한 C 문자열이어야 합니다.
+/// `text` must be a valid NUL-terminated C string.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn braillify_encode_to_braille_font(text: *const c_char) -> *mut c_char {
     clear_last_error();
@@ -138,6 +150,10 @@ pub unsafe extern "C" fn braillify_encode_to_braille_font(text: *const c_char) -
 
 /// Rust에서 할당한 문자열을 해제합니다.
 /// Frees a string allocated by Rust.
+///
+/// # Safety
+/// `ptr`은 이 라이브러리가 반환한 문자열이어야 하며 한 번만 해제해야 합니다.
+/// `ptr` must be a string returned by this library and must be freed only once.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn braillify_free_string(ptr: *mut c_char) {
     if !ptr.is_null() {
@@ -149,6 +165,10 @@ pub unsafe extern "C" fn braillify_free_string(ptr: *mut c_char) {
 
 /// Rust에서 할당한 바이트 배열을 해제합니다.
 /// Frees a byte array allocated by Rust.
+///
+/// # Safety
+/// `ptr`과 `len`은 `braillify_encode`가 반환한 값이어야 하며 한 번만 해제해야 합니다.
+/// `ptr` and `len` must come from `braillify_encode` and must be freed only once.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn braillify_free_bytes(ptr: *mut u8, len: usize) {
     if !ptr.is_null() {